@@ -3,10 +3,10 @@ use crate::errors::CliError;
 use camino::Utf8PathBuf;
 use std::env;
 use watt_common::bail;
-use watt_pm::compile;
+use watt_pm::{compile, watch};
 
 /// Executes command
-pub fn execute() {
+pub fn execute(no_dce: bool, update: bool, watch: bool) {
     let cwd = match env::current_dir() {
         Ok(path) => match Utf8PathBuf::try_from(path.clone()) {
             Ok(path) => path,
@@ -15,5 +15,9 @@ pub fn execute() {
         Err(_) => bail!(CliError::FailedToRetrieveCwd),
     };
 
-    compile::compile(cwd);
+    if watch {
+        watch::watch(cwd, !no_dce, update);
+    } else {
+        compile::compile(cwd, !no_dce, update);
+    }
 }
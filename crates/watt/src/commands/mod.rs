@@ -1,5 +1,11 @@
+pub mod add;
 pub mod build;
 pub mod check;
+pub mod clean;
+pub mod fmt;
 pub mod init;
 pub mod new;
+pub mod remove;
 pub mod run;
+pub mod run_script;
+pub mod tree;
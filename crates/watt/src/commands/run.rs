@@ -1,21 +1,26 @@
 /// Imports
 use crate::errors::CliError;
 use camino::Utf8PathBuf;
-use std::env;
+use std::{env, process};
 use watt_common::bail;
 use watt_pm::{
     compile,
     runtime::{self, JsRuntime},
 };
 
-/// Runs code
-fn run(path: Utf8PathBuf, runtime: JsRuntime) {
+/// Runs code,
+/// exits with the runtime process exit code
+fn run(path: Utf8PathBuf, runtime: JsRuntime, no_compile: bool, no_dce: bool, update: bool) {
     // Running code
-    compile::run(path, runtime);
+    let code = compile::run(path, runtime, no_compile, !no_dce, update);
+    // Propagating exit code
+    if code != 0 {
+        process::exit(code);
+    }
 }
 
 /// Executes command
-pub fn execute(rt: Option<String>) {
+pub fn execute(rt: Option<String>, no_compile: bool, no_dce: bool, update: bool) {
     // Getting runtime from string
     let runtime = match rt {
         Some(rt) => match rt.as_str() {
@@ -35,5 +40,5 @@ pub fn execute(rt: Option<String>) {
         Err(_) => bail!(CliError::FailedToRetrieveCwd),
     };
     // Running code
-    run(cwd, runtime)
+    run(cwd, runtime, no_compile, no_dce, update)
 }
@@ -0,0 +1,24 @@
+/// Imports
+use crate::errors::CliError;
+use camino::Utf8PathBuf;
+use std::{env, process};
+use watt_common::bail;
+use watt_pm::scripts;
+
+/// Executes command,
+/// exits with the script process exit code
+pub fn execute(name: String) {
+    let cwd = match env::current_dir() {
+        Ok(path) => match Utf8PathBuf::try_from(path.clone()) {
+            Ok(path) => path,
+            Err(_) => bail!(CliError::WrongUtf8Path { path }),
+        },
+        Err(_) => bail!(CliError::FailedToRetrieveCwd),
+    };
+    // Running script
+    let code = scripts::run(&cwd, &name);
+    // Propagating exit code
+    if code != 0 {
+        process::exit(code);
+    }
+}
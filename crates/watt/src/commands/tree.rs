@@ -0,0 +1,19 @@
+/// Imports
+use crate::errors::CliError;
+use camino::Utf8PathBuf;
+use std::env;
+use watt_common::bail;
+use watt_pm::dependencies;
+
+/// Executes command
+pub fn execute(update: bool) {
+    let cwd = match env::current_dir() {
+        Ok(path) => match Utf8PathBuf::try_from(path.clone()) {
+            Ok(path) => path,
+            Err(_) => bail!(CliError::WrongUtf8Path { path }),
+        },
+        Err(_) => bail!(CliError::FailedToRetrieveCwd),
+    };
+
+    dependencies::print_tree(&cwd, update);
+}
@@ -5,7 +5,7 @@ pub(crate) mod errors;
 pub(crate) mod log;
 
 // Imports
-use crate::commands::{build, check, init, new, run};
+use crate::commands::{add, build, check, clean, fmt, init, new, remove, run, run_script, tree};
 use clap::{Parser, Subcommand};
 use watt_pm::config::PackageType;
 
@@ -23,17 +23,39 @@ struct Cli {
 enum SubCommand {
     /// Adds package from url
     Add { url: String },
-    /// Removes package by name
+    /// Removes package by url
     Remove { url: String },
     /// Runs project
     Run {
-        #[arg(value_parser = ["deno", "bun", "node"])]
+        /// Javascript runtime to run project with
+        #[arg(long = "rt", value_parser = ["deno", "bun", "node"])]
         runtime: Option<String>,
+        /// Skips compilation of the project
+        #[arg(long)]
+        no_compile: bool,
+        /// Disables dead code elimination in the generated code
+        #[arg(long)]
+        no_dce: bool,
+        /// Resolves git dependencies again, ignoring `watt.lock`
+        #[arg(long)]
+        update: bool,
     },
+    /// Runs script from the `[scripts]` table of `watt.toml`
+    RunScript { name: String },
     /// Analyzes project for compile-time errors.
     Check,
     /// Builds project
-    Build,
+    Build {
+        /// Disables dead code elimination in the generated code
+        #[arg(long)]
+        no_dce: bool,
+        /// Resolves git dependencies again, ignoring `watt.lock`
+        #[arg(long)]
+        update: bool,
+        /// Rebuilds project on each change of the sources
+        #[arg(long)]
+        watch: bool,
+    },
     /// Creates new project
     New {
         name: String,
@@ -41,8 +63,24 @@ enum SubCommand {
         #[arg(value_enum)]
         package_type: Option<PackageType>,
     },
-    /// Clears cache of packages
-    Clean,
+    /// Removes `.cache` and `target` directories
+    Clean {
+        /// Prints what would be removed without removing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Prints resolved dependencies tree
+    Tree {
+        /// Resolves git dependencies again, ignoring `watt.lock`
+        #[arg(long)]
+        update: bool,
+    },
+    /// Formats project sources
+    Fmt {
+        /// Checks formatting without rewriting files
+        #[arg(long)]
+        check: bool,
+    },
     /// Initializes new project in current folder
     Init {
         #[arg(value_enum)]
@@ -54,13 +92,25 @@ enum SubCommand {
 pub fn cli() {
     // Parsing arguments
     match Cli::parse().command {
-        SubCommand::Add { url: _ } => todo!(),
-        SubCommand::Remove { url: _ } => todo!(),
-        SubCommand::Run { runtime } => run::execute(runtime),
+        SubCommand::Add { url } => add::execute(url),
+        SubCommand::Remove { url } => remove::execute(url),
+        SubCommand::Run {
+            runtime,
+            no_compile,
+            no_dce,
+            update,
+        } => run::execute(runtime, no_compile, no_dce, update),
+        SubCommand::RunScript { name } => run_script::execute(name),
         SubCommand::Check => check::execute(),
-        SubCommand::Build => build::execute(),
+        SubCommand::Build {
+            no_dce,
+            update,
+            watch,
+        } => build::execute(no_dce, update, watch),
         SubCommand::New { name, package_type } => new::execute(name, package_type),
-        SubCommand::Clean => todo!(),
+        SubCommand::Clean { dry_run } => clean::execute(dry_run),
+        SubCommand::Tree { update } => tree::execute(update),
+        SubCommand::Fmt { check } => fmt::execute(check),
         SubCommand::Init { package_type } => init::execute(package_type),
     }
 }
@@ -1,7 +1,7 @@
 /// Imports
 use ecow::EcoString;
 use miette::NamedSource;
-use std::sync::Arc;
+use std::{borrow::Borrow, sync::Arc};
 use watt_common::address::Address;
 
 /// Dependency path
@@ -145,6 +145,8 @@ pub enum BinaryOp {
     Mul,
     /// /
     Div,
+    /// ~/
+    IntDiv,
     /// %
     Mod,
     /// ==
@@ -169,6 +171,10 @@ pub enum BinaryOp {
     BitwiseAnd,
     /// |
     BitwiseOr,
+    /// <<
+    Shl,
+    /// >>
+    Shr,
     /// <>
     Concat,
 }
@@ -327,6 +333,27 @@ pub enum Pattern {
     /// ```
     ///
     Or(Box<Pattern>, Box<Pattern>),
+    /// Represents guarded pattern
+    ///
+    /// # Example
+    /// ```watt
+    /// match a {
+    ///  Option.Some(value) if value > 0 -> value,
+    ///                     ^^^^^^^^^^^^
+    ///     case is matched only if pattern
+    ///     is matched and condition is true
+    ///  _ -> 0
+    /// }
+    /// ```
+    ///
+    /// Guarded case doesn't count as covering
+    /// its pattern in the exhaustiveness check.
+    ///
+    Guard {
+        address: Address,
+        pattern: Box<Pattern>,
+        condition: Expression,
+    },
 }
 
 /// Case
@@ -386,7 +413,7 @@ pub enum UseKind {
 pub enum ElseBranch {
     Elif {
         location: Address,
-        logical: Expression,
+        logical: Box<Expression>,
         body: Either<Block, Expression>,
     },
     Else {
@@ -395,6 +422,25 @@ pub enum ElseBranch {
     },
 }
 
+/// Optional chain access
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChainAccess {
+    /// Represents optional field access
+    ///
+    /// # Example
+    /// ```watt
+    /// a?.field
+    /// ```
+    Field(EcoString),
+    /// Represents optional method call
+    ///
+    /// # Example
+    /// ```watt
+    /// a?.method(...)
+    /// ```
+    Call(EcoString, Vec<Expression>),
+}
+
 /// Range
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Range {
@@ -527,6 +573,14 @@ pub enum Expression {
         what: Box<Expression>,
         args: Vec<Expression>,
     },
+    /// Represents optional chain
+    ///
+    /// `base?.name`, `base?.name()`
+    OptionalChain {
+        location: Address,
+        base: Box<Expression>,
+        access: ChainAccess,
+    },
     /// Represents anonymous function
     ///
     /// fn(...) {
@@ -563,6 +617,53 @@ pub enum Expression {
         location: Address,
         expr: Box<Expression>,
     },
+    /// Try-catch expression
+    ///
+    /// ```watt
+    /// try {
+    ///     ...
+    /// } catch `bind`: `typ` {
+    ///     ...
+    /// }
+    /// ```
+    ///
+    /// Type of the caught value is optional
+    /// and defaults to `string`.
+    ///
+    TryCatch {
+        location: Address,
+        body: Block,
+        bind: EcoString,
+        typ: Option<TypePath>,
+        handler: Block,
+    },
+    /// Error propagation expression
+    ///
+    /// ```watt
+    /// `value`?
+    /// ```
+    ///
+    Try {
+        location: Address,
+        value: Box<Expression>,
+    },
+    /// Await expression
+    ///
+    /// ```watt
+    /// await `value`
+    /// ```
+    ///
+    Await {
+        location: Address,
+        value: Box<Expression>,
+    },
+    /// Break expression, leaves the labeled block
+    ///
+    /// ```watt
+    /// break 'label
+    /// ```
+    ///
+    Break { location: Address, label: EcoString },
 }
 
 /// Implementation
@@ -582,11 +683,56 @@ impl Expression {
             Expression::PrefixVar { location, .. } => location.clone(),
             Expression::SuffixVar { location, .. } => location.clone(),
             Expression::Call { location, .. } => location.clone(),
+            Expression::OptionalChain { location, .. } => location.clone(),
             Expression::Function { location, .. } => location.clone(),
             Expression::Match { location, .. } => location.clone(),
             Expression::Paren { location, .. } => location.clone(),
+            Expression::TryCatch { location, .. } => location.clone(),
+            Expression::Try { location, .. } => location.clone(),
+            Expression::Await { location, .. } => location.clone(),
+            Expression::Break { location, .. } => location.clone(),
         }
     }
+
+    /// Checks that control never leaves the expression normally:
+    /// `panic`, `todo`, `break`, or `if` and `match` with diverging branches.
+    ///
+    /// # Notes
+    /// `if` without `else` and `match` without cases
+    /// are never considered diverging.
+    ///
+    pub fn diverges(&self) -> bool {
+        match self {
+            Expression::Panic { .. } | Expression::Todo { .. } | Expression::Break { .. } => true,
+            Expression::Paren { expr, .. } => expr.diverges(),
+            Expression::If {
+                body,
+                else_branches,
+                ..
+            } => {
+                body_diverges(body)
+                    && else_branches
+                        .iter()
+                        .any(|branch| matches!(branch, ElseBranch::Else { .. }))
+                    && else_branches.iter().all(|branch| match branch {
+                        ElseBranch::Elif { body, .. } => body_diverges(body),
+                        ElseBranch::Else { body, .. } => body_diverges(body),
+                    })
+            }
+            Expression::Match { cases, .. } => {
+                !cases.is_empty() && cases.iter().all(|case| body_diverges(&case.body))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Checks that block or expression body diverges
+fn body_diverges<E: Borrow<Expression>>(body: &Either<Block, E>) -> bool {
+    match body {
+        Either::Left(block) => block.diverges(),
+        Either::Right(expr) => expr.borrow().diverges(),
+    }
 }
 
 /// Either type
@@ -622,6 +768,17 @@ pub enum Statement {
         what: Expression,
         value: Expression,
     },
+    /// Parallel assignment statement
+    ///
+    /// ```watt
+    /// `a`, `b` = `b`, `a`
+    /// ```
+    ///
+    ParallelAssign {
+        location: Address,
+        what: Vec<Expression>,
+        value: Vec<Expression>,
+    },
     /// Expression
     ///
     /// represents expression
@@ -655,6 +812,30 @@ pub enum Statement {
     },
     /// Represents semi colon expression
     Semi(Expression),
+    /// Represents `throw` statement
+    ///
+    /// ```watt
+    /// throw `value`
+    /// ```
+    ///
+    Throw {
+        location: Address,
+        value: Expression,
+    },
+    /// Represents labeled block,
+    /// that could be left with `break`
+    ///
+    /// ```watt
+    /// 'label: {
+    ///     ...
+    /// }
+    /// ```
+    ///
+    Labeled {
+        location: Address,
+        label: EcoString,
+        body: Block,
+    },
 }
 
 /// Implementation
@@ -663,10 +844,24 @@ impl Statement {
         match self {
             Statement::VarDef { location, .. } => location.clone(),
             Statement::VarAssign { location, .. } => location.clone(),
+            Statement::ParallelAssign { location, .. } => location.clone(),
             Statement::Expr(expression) => expression.location(),
             Statement::Loop { location, .. } => location.clone(),
             Statement::For { location, .. } => location.clone(),
             Statement::Semi(expression) => expression.location(),
+            Statement::Throw { location, .. } => location.clone(),
+            Statement::Labeled { location, .. } => location.clone(),
+        }
+    }
+
+    /// Checks that control never reaches the statement end,
+    /// so statements after it are unreachable.
+    pub fn diverges(&self) -> bool {
+        match self {
+            Statement::Throw { .. } => true,
+            Statement::Expr(expr) | Statement::Semi(expr) => expr.diverges(),
+            Statement::VarDef { value, .. } => value.diverges(),
+            _ => false,
         }
     }
 }
@@ -678,6 +873,14 @@ pub struct Block {
     pub body: Vec<Statement>,
 }
 
+/// Implementation
+impl Block {
+    /// Checks that block contains a diverging statement
+    pub fn diverges(&self) -> bool {
+        self.body.iter().any(Statement::diverges)
+    }
+}
+
 /// Represents use declaration
 ///
 ///  ... `as ...`, `for ..., ..., n`
@@ -696,6 +899,40 @@ pub struct Field {
     pub typ: TypePath,
 }
 
+/// Declaration attribute
+///
+/// `#[name]` or `#[name("argument")]` before the declaration
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Attribute {
+    /// Represents deprecation attribute
+    ///
+    /// # Example
+    /// ```watt
+    /// #[deprecated("use `sum` instead.")]
+    /// fn add(a: int, b: int): int = a + b
+    /// ```
+    ///
+    Deprecated {
+        location: Address,
+        message: Option<EcoString>,
+    },
+}
+
+/// Implementation
+impl Attribute {
+    /// Finds deprecation in the attributes list,
+    /// returns deprecation message, if it's given
+    pub fn deprecation(attributes: &[Attribute]) -> Option<Option<EcoString>> {
+        attributes
+            .iter()
+            .map(|attribute| match attribute {
+                Attribute::Deprecated { message, .. } => message.clone(),
+            })
+            .next()
+    }
+}
+
 /// Type declaration
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypeDeclaration {
@@ -713,6 +950,7 @@ pub enum TypeDeclaration {
         publicity: Publicity,
         generics: Vec<EcoString>,
         fields: Vec<Field>,
+        attributes: Vec<Attribute>,
     },
     /// Represents enum declaration
     ///
@@ -728,6 +966,18 @@ pub enum TypeDeclaration {
         publicity: Publicity,
         generics: Vec<EcoString>,
         variants: Vec<EnumConstructor>,
+        attributes: Vec<Attribute>,
+    },
+    /// Represents type alias declaration
+    ///
+    /// `publicity` type ... = ...
+    ///
+    Alias {
+        location: Address,
+        name: EcoString,
+        publicity: Publicity,
+        target: TypePath,
+        attributes: Vec<Attribute>,
     },
 }
 
@@ -743,6 +993,27 @@ pub enum FnDeclaration {
         params: Vec<Parameter>,
         body: Either<Block, Expression>,
         typ: Option<TypePath>,
+        doc: Option<EcoString>,
+        attributes: Vec<Attribute>,
+    },
+    /// Represents const function declaration
+    ///
+    /// `publicity` const fn(..., ..., n): typ { ... }
+    ///
+    /// Body of the const function could be evaluated at
+    /// compile time, so it's restricted to the literals,
+    /// operations, `let`, `if`, `match` and const functions calls.
+    ///
+    ConstFunction {
+        location: Address,
+        publicity: Publicity,
+        name: EcoString,
+        generics: Vec<EcoString>,
+        params: Vec<Parameter>,
+        body: Either<Block, Expression>,
+        typ: Option<TypePath>,
+        doc: Option<EcoString>,
+        attributes: Vec<Attribute>,
     },
     /// Represents extern function declaration
     ///
@@ -756,6 +1027,8 @@ pub enum FnDeclaration {
         params: Vec<Parameter>,
         typ: Option<TypePath>,
         body: EcoString,
+        doc: Option<EcoString>,
+        attributes: Vec<Attribute>,
     },
 }
 
@@ -767,6 +1040,7 @@ pub struct ConstDeclaration {
     pub name: EcoString,
     pub value: Expression,
     pub typ: TypePath,
+    pub attributes: Vec<Attribute>,
 }
 
 /// Declaration
@@ -784,6 +1058,7 @@ pub enum Declaration {
 #[derive(Debug)]
 pub struct Module {
     pub source: Arc<NamedSource<String>>,
+    pub doc: Option<EcoString>,
     pub dependencies: Vec<Dependency>,
     pub declarations: Vec<Declaration>,
 }
@@ -0,0 +1,82 @@
+/// Imports
+use ecow::EcoString;
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+/// Represents code generation hints of the module.
+///
+/// Code is generated from the untyped ast, so decisions,
+/// that depend on types are made by the type checker and
+/// recorded here, keyed by the span of the expression they
+/// belong to.
+///
+/// # Fields
+///
+/// - `tries: HashMap<Range<usize>, TryKind>`
+///   Kinds of the `?` propagations.
+///
+/// - `catches: HashMap<Range<usize>, CatchKind>`
+///   Kinds of the values, caught by the `try`/`catch`.
+///
+/// - `overloads: HashMap<Range<usize>, usize>`
+///   Indices of the overloaded extern functions,
+///   selected by the calls.
+///
+/// - `overloaded_imports: HashMap<EcoString, Vec<usize>>`
+///   Indices of the public overloads of the extern functions,
+///   imported with `use ... for`, each overload is imported separately.
+///
+/// - `erased_imports: HashSet<EcoString>`
+///   Names, imported with `use ... for`, that are erased
+///   from the generated code, like type aliases.
+///
+/// - `signatures: HashMap<Range<usize>, Signature>`
+///   JSDoc types of the module functions,
+///   keyed by the span of the function declaration.
+///
+/// - `reassigned: HashSet<Range<usize>>`
+///   Spans of the variable definitions, that are reassigned,
+///   other variables are defined with `const`.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Hints {
+    pub tries: HashMap<Range<usize>, TryKind>,
+    pub catches: HashMap<Range<usize>, CatchKind>,
+    pub overloads: HashMap<Range<usize>, usize>,
+    pub overloaded_imports: HashMap<EcoString, Vec<usize>>,
+    pub erased_imports: HashSet<EcoString>,
+    pub signatures: HashMap<Range<usize>, Signature>,
+    pub reassigned: HashSet<Range<usize>>,
+}
+
+/// Represents kind of the `?` propagation
+#[derive(Debug, Clone, PartialEq)]
+pub enum TryKind {
+    /// Propagates non-`Ok` variant of the enum,
+    /// unwrapping the `field` of the `Ok` variant
+    Result { field: EcoString },
+    /// Propagates `null` of the `Optional`
+    Optional,
+}
+
+/// Represents kind of the value, caught by the `try`/`catch`
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatchKind {
+    /// Catches thrown strings
+    String,
+    /// Catches thrown instances of the struct,
+    /// that is checked by the struct `name`
+    Struct { name: EcoString },
+}
+
+/// Represents JSDoc types of the function,
+/// rendered from the inferred signature
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    /// Types of the parameters
+    pub params: Vec<EcoString>,
+    /// Return type
+    pub ret: EcoString,
+}
@@ -7,24 +7,39 @@ use std::{
 };
 
 /// Address structure
+///
+/// `span` is measured in chars, while `bytes` is
+/// the same range, measured in utf-8 bytes. They're
+/// different, when source contains non-ascii chars.
+///
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct Address {
     pub source: Arc<NamedSource<String>>,
     pub span: Range<usize>,
+    pub bytes: Range<usize>,
 }
 
 /// Address implementation
 impl Address {
-    /// New address with column
-    pub fn new(source: Arc<NamedSource<String>>, at: usize) -> Address {
+    /// New address with column and its byte offset
+    pub fn new(source: Arc<NamedSource<String>>, at: usize, byte: usize) -> Address {
         Address {
             source,
             span: at..at,
+            bytes: byte..byte,
         }
     }
-    /// New address with span
-    pub fn span(source: Arc<NamedSource<String>>, span: Range<usize>) -> Address {
-        Address { source, span }
+    /// New address with span and its byte offsets
+    pub fn span(
+        source: Arc<NamedSource<String>>,
+        span: Range<usize>,
+        bytes: Range<usize>,
+    ) -> Address {
+        Address {
+            source,
+            span,
+            bytes,
+        }
     }
 }
 
@@ -43,6 +58,10 @@ impl Add for Address {
         if self.source != rhs.source {
             panic!("address sources missmatched.")
         }
-        Address::span(self.source, self.span.start..rhs.span.end)
+        Address::span(
+            self.source,
+            self.span.start..rhs.span.end,
+            self.bytes.start..rhs.bytes.end,
+        )
     }
 }
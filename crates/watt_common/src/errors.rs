@@ -1,3 +1,6 @@
+/// Imports
+use std::cell::RefCell;
+
 /// Prints error, and then
 /// exits proccess using `std::process::exit(1)`.
 #[macro_export]
@@ -8,14 +11,42 @@ macro_rules! bail {
     }};
 }
 
-/// Prints warning
+/// Prints warning, if it's not disabled
 #[macro_export]
 macro_rules! warn {
     ($pkg:expr, $report:expr) => {{
         let report: miette::Report = $report.into();
         let report_code = report.code().unwrap().to_string();
         if !$pkg.draft.lints.disabled.contains(&report_code) {
-            eprintln!("{report:?}");
+            $crate::errors::emit_warning(report);
         }
     }};
 }
+
+thread_local! {
+    /// Rendered warnings of the current thread,
+    /// `None` if warnings aren't captured
+    static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Prints warning, or stores it,
+/// if warnings are captured
+pub fn emit_warning(report: miette::Report) {
+    let rendered = format!("{report:?}");
+    CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(warnings) => warnings.push(rendered),
+        None => eprintln!("{rendered}"),
+    })
+}
+
+/// Starts capturing warnings of the current thread,
+/// instead of printing them
+pub fn capture_warnings() {
+    CAPTURED.set(Some(Vec::new()));
+}
+
+/// Stops capturing warnings of the current
+/// thread, returns captured warnings
+pub fn captured_warnings() -> Vec<String> {
+    CAPTURED.take().unwrap_or_default()
+}
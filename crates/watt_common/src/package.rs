@@ -6,6 +6,8 @@ use camino::Utf8PathBuf;
 pub struct DraftPackageLints {
     /// Disabled lints
     pub disabled: Vec<String>,
+    /// Enabled opt-in lints
+    pub enabled: Vec<String>,
 }
 
 /// Draft package
@@ -15,4 +17,6 @@ pub struct DraftPackage {
     pub path: Utf8PathBuf,
     /// Lints config
     pub lints: DraftPackageLints,
+    /// Main module name, `None` if package is a dependency
+    pub main: Option<String>,
 }
@@ -0,0 +1,105 @@
+/// Imports
+use crate::io;
+use camino::{Utf8Path, Utf8PathBuf};
+use ecow::EcoString;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+};
+use tracing::info;
+
+/// Incremental compilation cache
+///
+/// Stores SHA-256 hashes of the modules sources
+/// in the `.cache/.watt-cache/<module-name>.hash` files,
+/// so unchanged modules could skip code generation.
+///
+pub struct ModuleCache {
+    /// Path to `.watt-cache` directory
+    path: Utf8PathBuf,
+    /// Modules source hashes, computed during this compilation
+    hashes: HashMap<EcoString, String>,
+    /// Modules, that are changed since the last compilation
+    changed: HashSet<EcoString>,
+    /// Is dead code elimination enabled
+    dce: bool,
+}
+
+/// Module cache implementation
+impl ModuleCache {
+    /// Creates new module cache inside the `.cache` directory
+    pub fn new(cache: &Utf8Path, dce: bool) -> Self {
+        Self {
+            path: cache.join(".watt-cache"),
+            hashes: HashMap::new(),
+            changed: HashSet::new(),
+            dce,
+        }
+    }
+
+    /// Computes SHA-256 hash of the source text.
+    ///
+    /// Hash is salted with the compiler version and the codegen
+    /// options, so modules are regenerated, when compiler is
+    /// updated or options are changed.
+    ///
+    fn hash(&self, source: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(source.as_bytes());
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        if !self.dce {
+            hasher.update(b"no-dce");
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Returns path to the module `.hash` file
+    fn hash_path(&self, module: &EcoString) -> Utf8PathBuf {
+        self.path.join(format!("{module}.hash"))
+    }
+
+    /// Checks that module is fresh and could be skipped.
+    ///
+    /// Module is fresh, if its source hash matches the stored one,
+    /// generated `.js` output exists and none of its dependencies
+    /// were changed. Otherwise, module is marked as changed.
+    ///
+    pub fn is_fresh<'a>(
+        &mut self,
+        module: &EcoString,
+        source: &str,
+        dependencies: impl IntoIterator<Item = &'a EcoString>,
+        generated: &Utf8Path,
+    ) -> bool {
+        // Computing hash
+        let hash = self.hash(source);
+        // Checking stored hash, output and dependencies
+        let fresh = fs::read_to_string(self.hash_path(module)).is_ok_and(|stored| stored == hash)
+            && generated.exists()
+            && !dependencies
+                .into_iter()
+                .any(|dependency| self.changed.contains(dependency));
+        // Marking module as changed
+        if !fresh {
+            self.changed.insert(module.clone());
+        }
+        self.hashes.insert(module.clone(), hash);
+        fresh
+    }
+
+    /// Stores module hash to the `.hash` file
+    pub fn store(&self, module: &EcoString) {
+        if let Some(hash) = self.hashes.get(module) {
+            // Hash path
+            let path = self.hash_path(module);
+            // Creating directory
+            if let Some(parent) = path.parent() {
+                io::mkdir_all(&parent.to_path_buf());
+            }
+            // Writing hash
+            io::write(&path, hash);
+            info!("Stored hash of {module} to {path}");
+        }
+    }
+}
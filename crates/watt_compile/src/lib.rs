@@ -3,7 +3,10 @@
 #![allow(unused_assignments)]
 
 // Modules
+pub mod cache;
 mod errors;
 pub mod io;
+pub mod lints;
 pub mod package;
 pub mod project;
+pub mod warnings;
@@ -0,0 +1,305 @@
+/// Imports
+use crate::warnings::CompileWarning;
+use ecow::EcoString;
+use std::{borrow::Borrow, collections::HashMap};
+use watt_ast::ast::{
+    Block, ChainAccess, Declaration, Either, ElseBranch, Expression, FnDeclaration, Module,
+    Parameter, Pattern, Range, Statement, TypeDeclaration,
+};
+use watt_common::{address::Address, warn};
+use watt_gen::try_escape_js;
+use watt_typeck::cx::package::PackageCx;
+
+/// Name of the opt-in lint, that enables js keyword shadow check
+pub const JS_KEYWORD_SHADOW_LINT: &str = "js-keyword-shadow";
+
+/// Collects identifiers, that are renamed by `try_escape_js`
+/// during code generation, because they're equal to js keywords.
+///
+/// Only identifiers, that are emitted into the generated code
+/// are visited: declarations names, parameters, fields,
+/// variables and pattern bindings.
+///
+pub struct JsKeywordShadow {
+    /// Found shadows
+    warnings: Vec<CompileWarning>,
+}
+
+/// Implementation
+impl JsKeywordShadow {
+    /// Collects shadows from the module
+    pub fn check(module: &Module) -> Vec<CompileWarning> {
+        let mut lint = Self {
+            warnings: Vec::new(),
+        };
+        for decl in &module.declarations {
+            lint.visit_decl(decl);
+        }
+        lint.warnings
+    }
+
+    /// Checks identifier
+    fn ident(&mut self, location: &Address, name: &EcoString) {
+        let emitted = try_escape_js(name);
+        if emitted != name.as_str() {
+            self.warnings.push(CompileWarning::JsKeywordShadow {
+                src: location.source.clone(),
+                span: location.span.clone().into(),
+                name: name.clone(),
+                emitted,
+            });
+        }
+    }
+
+    /// Checks parameters
+    fn params(&mut self, params: &[Parameter]) {
+        for param in params {
+            self.ident(&param.location, &param.name);
+        }
+    }
+
+    /// Visits declaration
+    fn visit_decl(&mut self, decl: &Declaration) {
+        match decl {
+            Declaration::Fn(FnDeclaration::Function {
+                location,
+                name,
+                params,
+                body,
+                ..
+            })
+            | Declaration::Fn(FnDeclaration::ConstFunction {
+                location,
+                name,
+                params,
+                body,
+                ..
+            }) => {
+                self.ident(location, name);
+                self.params(params);
+                self.visit_body(body);
+            }
+            Declaration::Fn(FnDeclaration::ExternFunction {
+                location,
+                name,
+                params,
+                ..
+            }) => {
+                self.ident(location, name);
+                self.params(params);
+            }
+            Declaration::Const(decl) => {
+                self.ident(&decl.location, &decl.name);
+                self.visit_expr(&decl.value);
+            }
+            Declaration::Type(TypeDeclaration::Struct {
+                location,
+                name,
+                fields,
+                ..
+            }) => {
+                self.ident(location, name);
+                for field in fields {
+                    self.ident(&field.location, &field.name);
+                }
+            }
+            Declaration::Type(TypeDeclaration::Enum {
+                location,
+                name,
+                variants,
+                ..
+            }) => {
+                self.ident(location, name);
+                for variant in variants {
+                    self.params(&variant.params);
+                }
+            }
+            // Type aliases are erased during codegen
+            Declaration::Type(TypeDeclaration::Alias { .. }) => {}
+        }
+    }
+
+    /// Visits block or expression body
+    fn visit_body<E: Borrow<Expression>>(&mut self, body: &Either<Block, E>) {
+        match body {
+            Either::Left(block) => self.visit_block(block),
+            Either::Right(expr) => self.visit_expr(expr.borrow()),
+        }
+    }
+
+    /// Visits block
+    fn visit_block(&mut self, block: &Block) {
+        for stmt in &block.body {
+            self.visit_stmt(stmt);
+        }
+    }
+
+    /// Visits statement
+    fn visit_stmt(&mut self, stmt: &Statement) {
+        match stmt {
+            Statement::Labeled { body, .. } => self.visit_block(body),
+            Statement::VarDef {
+                location,
+                name,
+                value,
+                ..
+            } => {
+                self.ident(location, name);
+                self.visit_expr(value);
+            }
+            Statement::VarAssign { what, value, .. } => {
+                self.visit_expr(what);
+                self.visit_expr(value);
+            }
+            Statement::ParallelAssign { what, value, .. } => {
+                for expr in what.iter().chain(value) {
+                    self.visit_expr(expr);
+                }
+            }
+            Statement::Expr(expr) | Statement::Semi(expr) => self.visit_expr(expr),
+            Statement::Throw { value, .. } => self.visit_expr(value),
+            Statement::Loop { logical, body, .. } => {
+                self.visit_expr(logical);
+                self.visit_body(body);
+            }
+            Statement::For { range, body, .. } => {
+                let (from, to) = match range.as_ref() {
+                    Range::ExcludeLast { from, to, .. } => (from, to),
+                    Range::IncludeLast { from, to, .. } => (from, to),
+                };
+                self.visit_expr(from);
+                self.visit_expr(to);
+                self.visit_body(body);
+            }
+        }
+    }
+
+    /// Visits pattern bindings
+    fn visit_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Unwrap { fields, .. } => {
+                for (location, name) in fields {
+                    self.ident(location, name);
+                }
+            }
+            Pattern::BindTo(location, name) => self.ident(location, name),
+            Pattern::Or(pat1, pat2) => {
+                self.visit_pattern(pat1);
+                self.visit_pattern(pat2);
+            }
+            Pattern::Guard {
+                pattern, condition, ..
+            } => {
+                self.visit_pattern(pattern);
+                self.visit_expr(condition);
+            }
+            _ => {}
+        }
+    }
+
+    /// Visits expression
+    fn visit_expr(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Bin { left, right, .. } => {
+                self.visit_expr(left);
+                self.visit_expr(right);
+            }
+            Expression::As { value, .. }
+            | Expression::Unary { value, .. }
+            | Expression::Try { value, .. }
+            | Expression::Await { value, .. } => self.visit_expr(value),
+            Expression::Paren { expr, .. } => self.visit_expr(expr),
+            Expression::SuffixVar { container, .. } => self.visit_expr(container),
+            Expression::If {
+                logical,
+                body,
+                else_branches,
+                ..
+            } => {
+                self.visit_expr(logical);
+                self.visit_body(body);
+                for branch in else_branches {
+                    match branch {
+                        ElseBranch::Elif { logical, body, .. } => {
+                            self.visit_expr(logical);
+                            self.visit_body(body);
+                        }
+                        ElseBranch::Else { body, .. } => self.visit_body(body),
+                    }
+                }
+            }
+            Expression::Call { what, args, .. } => {
+                self.visit_expr(what);
+                for arg in args {
+                    self.visit_expr(arg);
+                }
+            }
+            Expression::OptionalChain { base, access, .. } => {
+                self.visit_expr(base);
+                if let ChainAccess::Call(_, args) = access {
+                    for arg in args {
+                        self.visit_expr(arg);
+                    }
+                }
+            }
+            Expression::Function { params, body, .. } => {
+                self.params(params);
+                self.visit_body(body);
+            }
+            Expression::Match { value, cases, .. } => {
+                self.visit_expr(value);
+                for case in cases {
+                    self.visit_pattern(&case.pattern);
+                    self.visit_body(&case.body);
+                }
+            }
+            Expression::TryCatch {
+                location,
+                body,
+                bind,
+                handler,
+                ..
+            } => {
+                self.visit_block(body);
+                self.ident(location, bind);
+                self.visit_block(handler);
+            }
+            Expression::PrefixVar { .. }
+            | Expression::Int { .. }
+            | Expression::Float { .. }
+            | Expression::String { .. }
+            | Expression::Bool { .. }
+            | Expression::Todo { .. }
+            | Expression::Panic { .. }
+            | Expression::Break { .. } => {}
+        }
+    }
+}
+
+/// Reports identifiers, that are renamed in the generated
+/// code, because they're equal to js keywords.
+///
+/// # Notes
+/// Check is opt-in and does nothing,
+/// unless `js-keyword-shadow` lint is enabled.
+///
+pub fn check_js_keyword_shadow(pkg: &PackageCx, modules: &HashMap<EcoString, Module>) {
+    if !pkg
+        .draft
+        .lints
+        .enabled
+        .iter()
+        .any(|lint| lint == JS_KEYWORD_SHADOW_LINT)
+    {
+        return;
+    }
+
+    // Reporting shadows, sorted by module name
+    let mut names: Vec<&EcoString> = modules.keys().collect();
+    names.sort();
+    for name in names {
+        for warning in JsKeywordShadow::check(&modules[name]) {
+            warn!(pkg, warning);
+        }
+    }
+}
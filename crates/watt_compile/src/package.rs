@@ -1,9 +1,12 @@
 /// Imports
 use crate::{
+    cache::ModuleCache,
     errors::CompileError,
     io::{self, WattFile},
+    lints,
 };
 use camino::{Utf8Path, Utf8PathBuf};
+use console::style;
 use ecow::EcoString;
 use id_arena::Id;
 use miette::NamedSource;
@@ -16,11 +19,12 @@ use std::{
 use tracing::{error, info};
 use watt_ast::ast::{self};
 use watt_common::{bail, package::DraftPackage};
-use watt_gen::gen_module;
+use watt_gen::{dce::ReachabilitySet, gen_module};
 use watt_lex::lexer::Lexer;
 use watt_lint::lint::LintCx;
 use watt_parse::parser::Parser;
 use watt_typeck::{
+    check::dead,
     cx::{module::ModuleCx, package::PackageCx, root::RootCx},
     typ::{cx::TyCx, typ::Module},
 };
@@ -218,12 +222,18 @@ impl<'cx> PackageCompiler<'cx> {
             analyzed_modules.push(analyzed_module);
         }
 
+        // Checking for dead code
+        dead::check_dead_code(&self.package, loaded_modules);
+
+        // Checking for js keyword shadows
+        lints::check_js_keyword_shadow(&self.package, loaded_modules);
+
         analyzed_modules
     }
 
     /// Compiles package
     /// returns analyzed modules
-    pub fn compile(&mut self) -> CompiledPackage {
+    pub fn compile(&mut self, cache: &mut ModuleCache, dce: bool) -> CompiledPackage {
         info!("Compiling package: {}", self.package.draft.path);
 
         // Collecting sources
@@ -245,28 +255,37 @@ impl<'cx> PackageCompiler<'cx> {
 
         // Performing codegen
         info!("Performing codegen...");
-        let mut generated_modules = HashMap::new();
+        let mut completed_modules = HashMap::new();
         for id in &analyzed_modules {
             // Retrieving module
             let module = self.package.root.module(*id);
+            let loaded = loaded_modules.get(&module.name).unwrap();
+
+            // Target path
+            let mut target_path = self.outcome.clone();
+            target_path.push(Utf8Path::new(&format!("{}.js", &module.name)));
+            completed_modules.insert(module.name.clone(), target_path.clone());
+
+            // Skipping module, if it's not changed
+            if cache.is_fresh(
+                &module.name,
+                loaded.source.inner(),
+                loaded.dependencies.iter().map(|d| &d.path.module),
+                &target_path,
+            ) {
+                println!("{} {} (cached)", style("[-]").bold().dim(), module.name);
+                continue;
+            }
 
             // Performing code generation
             info!("Performing codegen for {}", module.name);
-            let generated = gen_module(&module.name, loaded_modules.get(&module.name).unwrap())
+            let reachable = dce.then(|| ReachabilitySet::new(loaded));
+            let generated = gen_module(&module.name, loaded, &module.hints, reachable.as_ref())
                 .to_file_string()
                 .unwrap();
-            generated_modules.insert(module.name.clone(), generated);
-        }
 
-        // Writing outcome
-        info!("Writing outcome...");
-        let mut completed_modules = HashMap::new();
-        for module in generated_modules {
-            // Target path
-            let mut target_path = self.outcome.clone();
-            target_path.push(Utf8Path::new(&format!("{}.js", &module.0)));
-            
-            completed_modules.insert(module.0, target_path.clone());
+            // Writing outcome
+            info!("Writing outcome of {}", module.name);
             // Creating directory
             if let Some(path) = target_path.parent() {
                 // Catching error
@@ -275,7 +294,9 @@ impl<'cx> PackageCompiler<'cx> {
                 }
             }
             // Creating file
-            io::write(&target_path, &module.1);
+            io::write(&target_path, &generated);
+            // Storing module hash
+            cache.store(&module.name);
         }
 
         // Returning analyzed modules
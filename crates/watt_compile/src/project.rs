@@ -1,5 +1,6 @@
 /// Imports
 use crate::{
+    cache::ModuleCache,
     io,
     package::{CompiledPackage, PackageCompiler},
 };
@@ -29,13 +30,27 @@ pub struct ProjectCompiler<'out> {
     pub packages: Vec<DraftPackage>,
     /// Outcome
     pub outcome: &'out Utf8PathBuf,
+    /// Cache
+    pub cache: &'out Utf8PathBuf,
+    /// Is dead code elimination enabled
+    pub dce: bool,
 }
 
 /// Project compiler implementation
 impl<'out> ProjectCompiler<'out> {
     /// Creates new project compiler
-    pub fn new(packages: Vec<DraftPackage>, outcome: &'out Utf8PathBuf) -> Self {
-        Self { packages, outcome }
+    pub fn new(
+        packages: Vec<DraftPackage>,
+        outcome: &'out Utf8PathBuf,
+        cache: &'out Utf8PathBuf,
+        dce: bool,
+    ) -> Self {
+        Self {
+            packages,
+            outcome,
+            cache,
+            dce,
+        }
     }
 
     /// Writes `prelude.js`
@@ -58,12 +73,14 @@ impl<'out> ProjectCompiler<'out> {
         let mut rcx = RootCx::default();
         // Types context
         let mut tcx = TyCx::default();
+        // Modules cache
+        let mut cache = ModuleCache::new(self.cache, self.dce);
         // Compiling packages
         let mut compiled_packages = Vec::new();
         for package in &self.packages {
             compiled_packages.push(
                 PackageCompiler::new(package.clone(), self.outcome.clone(), &mut rcx, &mut tcx)
-                    .compile(),
+                    .compile(&mut cache, self.dce),
             );
         }
         // Writing prelude
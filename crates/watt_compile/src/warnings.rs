@@ -0,0 +1,24 @@
+/// Imports
+use ecow::EcoString;
+use miette::{Diagnostic, NamedSource, SourceSpan};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Compile warning
+#[derive(Debug, Error, Diagnostic)]
+pub enum CompileWarning {
+    #[error("identifier `{name}` is a js keyword and will be emitted as `{emitted}`.")]
+    #[diagnostic(
+        code(compile::warn::js_keyword_shadow),
+        severity(warning),
+        help("consider renaming it, so generated code is easier to debug.")
+    )]
+    JsKeywordShadow {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this identifier will be renamed.")]
+        span: SourceSpan,
+        name: EcoString,
+        emitted: String,
+    },
+}
@@ -0,0 +1,35 @@
+// Modules
+pub mod printer;
+pub mod trivia;
+
+/// Imports
+use crate::{printer::Printer, trivia::Trivia};
+use miette::NamedSource;
+use std::sync::Arc;
+use watt_lex::lexer::Lexer;
+use watt_parse::parser::Parser;
+
+/// Formats module source code.
+///
+/// Code is re-rendered from the ast with the canonical
+/// indentation and spacing, one statement per line.
+/// Comments are kept before the node, that follows them.
+///
+/// # Notes
+/// Formatting is idempotent: formatting
+/// of the formatted code doesn't change it.
+///
+pub fn format(name: &str, code: String) -> String {
+    // Creating named source for miette
+    let code_chars: Vec<char> = code.chars().collect();
+    let named_source = Arc::new(NamedSource::<String>::new(name, code.clone()));
+    // Lexing
+    let lexer = Lexer::new(&code_chars, &named_source);
+    let tokens = lexer.lex();
+    // Parsing
+    let trivia = Trivia::new(&code, &tokens);
+    let mut parser = Parser::new(tokens, &named_source);
+    let ast = parser.parse();
+    // Printing
+    Printer::new(trivia).module(&ast)
+}
@@ -0,0 +1,969 @@
+/// Imports
+use crate::trivia::Trivia;
+use ecow::EcoString;
+use std::borrow::Borrow;
+use watt_ast::ast::{
+    Attribute, BinaryOp, Block, Case, ChainAccess, ConstDeclaration, Declaration, Dependency,
+    Either, ElseBranch, EnumConstructor, Expression, Field, FnDeclaration, Module, Parameter,
+    Pattern, Publicity, Range, Statement, TypeDeclaration, TypePath, UnaryOp, UseKind,
+};
+
+/// Indentation of the single nesting level
+const INDENT: &str = "    ";
+
+/// Binary operator text
+fn binary_op(op: &BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::Add => "+",
+        BinaryOp::Sub => "-",
+        BinaryOp::Mul => "*",
+        BinaryOp::Div => "/",
+        BinaryOp::IntDiv => "~/",
+        BinaryOp::Mod => "%",
+        BinaryOp::Eq => "==",
+        BinaryOp::NotEq => "!=",
+        BinaryOp::Gt => ">",
+        BinaryOp::Ge => ">=",
+        BinaryOp::Lt => "<",
+        BinaryOp::Le => "<=",
+        BinaryOp::And => "&&",
+        BinaryOp::Or => "||",
+        BinaryOp::Xor => "^",
+        BinaryOp::BitwiseAnd => "&",
+        BinaryOp::BitwiseOr => "|",
+        BinaryOp::Shl => "<<",
+        BinaryOp::Shr => ">>",
+        BinaryOp::Concat => "<>",
+    }
+}
+
+/// Binary operator precedence, the same as in the parser
+fn precedence(op: &BinaryOp) -> u8 {
+    match op {
+        BinaryOp::Or => 0,
+        BinaryOp::And => 1,
+        BinaryOp::Eq | BinaryOp::NotEq => 2,
+        BinaryOp::Gt | BinaryOp::Ge | BinaryOp::Lt | BinaryOp::Le => 3,
+        BinaryOp::Shl | BinaryOp::Shr => 4,
+        BinaryOp::Add | BinaryOp::Sub | BinaryOp::Concat => 5,
+        BinaryOp::Mul
+        | BinaryOp::Div
+        | BinaryOp::IntDiv
+        | BinaryOp::Mod
+        | BinaryOp::Xor
+        | BinaryOp::BitwiseAnd
+        | BinaryOp::BitwiseOr => 6,
+    }
+}
+
+/// Renders string literal.
+///
+/// Strings with new lines are rendered as multiline
+/// backtick strings, others are rendered in double quotes.
+///
+fn string_literal(value: &str) -> String {
+    let quote = if value.contains('\n') { '`' } else { '"' };
+    let mut result = String::from(quote);
+    for ch in value.chars() {
+        match ch {
+            '\\' => result.push_str("\\\\"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\n' => result.push('\n'),
+            ch if ch == quote => {
+                result.push('\\');
+                result.push(ch);
+            }
+            ch if ch.is_control() => result.push_str(&format!("\\u{{{:04x}}}", ch as u32)),
+            ch => result.push(ch),
+        }
+    }
+    result.push(quote);
+    result
+}
+
+/// Start of the expression in the source code
+///
+/// # Notes
+/// Addresses of the punctuation tokens point right after
+/// the token, and string addresses skip opening quote.
+///
+fn expr_start(expr: &Expression) -> usize {
+    match expr {
+        Expression::Paren { location, .. }
+        | Expression::Unary { location, .. }
+        | Expression::String { location, .. } => location.bytes.start.saturating_sub(1),
+        Expression::Bin { left, .. } => expr_start(left),
+        Expression::As { value, .. } => expr_start(value),
+        Expression::SuffixVar { container, .. } => expr_start(container),
+        _ => expr.location().bytes.start,
+    }
+}
+
+/// Start of the statement in the source code
+fn stmt_start(stmt: &Statement) -> usize {
+    match stmt {
+        Statement::VarAssign { what, .. } => expr_start(what),
+        Statement::ParallelAssign { what, .. } => expr_start(&what[0]),
+        Statement::Expr(expr) | Statement::Semi(expr) => expr_start(expr),
+        _ => stmt.location().bytes.start,
+    }
+}
+
+/// Renders ast back to the source code
+pub struct Printer<'s> {
+    /// Source trivia
+    trivia: Trivia<'s>,
+    /// Output
+    out: String,
+    /// Current nesting level
+    indent: usize,
+}
+
+/// Implementation
+impl<'s> Printer<'s> {
+    /// Creates new printer
+    pub fn new(trivia: Trivia<'s>) -> Self {
+        Self {
+            trivia,
+            out: String::new(),
+            indent: 0,
+        }
+    }
+
+    /// Writes text
+    fn write(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    /// Writes current indentation
+    fn write_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    /// Writes comments, that start before `offset`,
+    /// each one on its own line.
+    ///
+    /// Blank lines between the comments are kept, blank line
+    /// before the first comment is kept, only if `blank` is set.
+    /// Returns end of the last written comment.
+    ///
+    fn comments_before(&mut self, offset: usize, blank: bool) -> Option<usize> {
+        let mut last: Option<usize> = None;
+        for comment in self.trivia.take_before(offset) {
+            let from = match last {
+                Some(end) => end,
+                None if blank => self.trivia.token_end_before(comment.start),
+                None => comment.start,
+            };
+            if self.trivia.blank_between(from, comment.start) {
+                self.write("\n");
+            }
+            self.write_indent();
+            self.write(&comment.text);
+            self.write("\n");
+            last = Some(comment.end);
+        }
+        last
+    }
+
+    /// Writes line comment, that trails the printed node on
+    /// the same line, if it starts before the next node at `offset`
+    fn trailing_comment(&mut self, offset: usize) {
+        if let Some(comment) = self.trivia.take_trailing(offset) {
+            self.write(" ");
+            self.write(&comment.text);
+        }
+    }
+
+    /// Starts line-level node, located at `offset`
+    ///
+    /// Writes the comments, that precede the node, and keeps
+    /// blank lines between them, unless the node is `first`
+    /// in its parent. If `separate` is set, node is always
+    /// separated with a blank line from the previous one.
+    ///
+    fn node_start(&mut self, offset: usize, first: bool, separate: bool) {
+        if separate {
+            self.write("\n");
+        }
+        let blank = !first && !separate;
+        let from = match self.comments_before(offset, blank) {
+            Some(end) => end,
+            None if blank => self.trivia.token_end_before(offset),
+            None => offset,
+        };
+        if self.trivia.blank_between(from, offset) {
+            self.write("\n");
+        }
+        self.write_indent();
+    }
+
+    /// Prints module
+    pub fn module(mut self, module: &Module) -> String {
+        let starts: Vec<usize> = module
+            .dependencies
+            .iter()
+            .map(|dependency| dependency.location.bytes.start)
+            .chain(module.declarations.iter().map(|decl| self.decl_start(decl)))
+            .collect();
+        let next = |i: usize| starts.get(i + 1).copied().unwrap_or(usize::MAX);
+        for (i, dependency) in module.dependencies.iter().enumerate() {
+            self.node_start(starts[i], i == 0, false);
+            self.dependency(dependency);
+            self.trailing_comment(next(i));
+            self.write("\n");
+        }
+        for (i, declaration) in module.declarations.iter().enumerate() {
+            let idx = module.dependencies.len() + i;
+            let separate = i > 0 || !module.dependencies.is_empty();
+            self.node_start(starts[idx], !separate, separate);
+            self.declaration(declaration);
+            self.trailing_comment(next(idx));
+            self.write("\n");
+        }
+        let items = !module.dependencies.is_empty() || !module.declarations.is_empty();
+        self.comments_before(usize::MAX, items);
+        self.out
+    }
+
+    /// Prints dependency
+    fn dependency(&mut self, dependency: &Dependency) {
+        self.write("use ");
+        self.write(&dependency.path.module);
+        match &dependency.kind {
+            UseKind::AsName(name) => {
+                self.write(" as ");
+                self.write(name);
+            }
+            UseKind::ForNames(names) => {
+                self.write(" for ");
+                self.write(&names.join(", "));
+            }
+        }
+    }
+
+    /// Start of the declaration, including
+    /// attributes and publicity
+    fn decl_start(&self, declaration: &Declaration) -> usize {
+        let (keyword, attributes) = self.decl_keyword(declaration);
+        match attributes.first() {
+            // Attributes start with `#` punctuation token
+            Some(Attribute::Deprecated { location, .. }) => location.bytes.start.saturating_sub(1),
+            None => keyword,
+        }
+    }
+
+    /// Start of the declaration keyword, including
+    /// publicity, and attributes of the declaration
+    fn decl_keyword<'d>(&self, declaration: &'d Declaration) -> (usize, &'d [Attribute]) {
+        let (start, publicity, attributes) = match declaration {
+            Declaration::Fn(FnDeclaration::Function {
+                location,
+                publicity,
+                attributes,
+                ..
+            })
+            | Declaration::Fn(FnDeclaration::ConstFunction {
+                location,
+                publicity,
+                attributes,
+                ..
+            })
+            | Declaration::Fn(FnDeclaration::ExternFunction {
+                location,
+                publicity,
+                attributes,
+                ..
+            })
+            | Declaration::Type(TypeDeclaration::Struct {
+                location,
+                publicity,
+                attributes,
+                ..
+            })
+            | Declaration::Type(TypeDeclaration::Enum {
+                location,
+                publicity,
+                attributes,
+                ..
+            })
+            | Declaration::Type(TypeDeclaration::Alias {
+                location,
+                publicity,
+                attributes,
+                ..
+            }) => (location.bytes.start, publicity, attributes),
+            // Constant location points to its name
+            Declaration::Const(ConstDeclaration {
+                location,
+                publicity,
+                attributes,
+                ..
+            }) => (
+                self.trivia.token_before(location.bytes.start),
+                publicity,
+                attributes,
+            ),
+        };
+        match publicity {
+            Publicity::Public => (self.trivia.token_before(start), attributes),
+            Publicity::Private => (start, attributes),
+        }
+    }
+
+    /// Prints declaration
+    fn declaration(&mut self, declaration: &Declaration) {
+        // Attributes, and comments between attributes and the declaration
+        let (keyword, attributes) = self.decl_keyword(declaration);
+        for attribute in attributes {
+            match attribute {
+                Attribute::Deprecated { message, .. } => {
+                    self.write("#[deprecated");
+                    if let Some(message) = message {
+                        self.write("(");
+                        self.write(&string_literal(message));
+                        self.write(")");
+                    }
+                    self.write("]\n");
+                }
+            }
+        }
+        if !attributes.is_empty() {
+            self.comments_before(keyword, false);
+            self.write_indent();
+        }
+
+        match declaration {
+            Declaration::Fn(FnDeclaration::Function {
+                publicity,
+                name,
+                generics,
+                params,
+                body,
+                typ,
+                ..
+            }) => {
+                self.publicity(publicity);
+                self.fn_signature(name, generics, params, typ);
+                self.body(body);
+            }
+            Declaration::Fn(FnDeclaration::ConstFunction {
+                publicity,
+                name,
+                generics,
+                params,
+                body,
+                typ,
+                ..
+            }) => {
+                self.publicity(publicity);
+                self.write("const ");
+                self.fn_signature(name, generics, params, typ);
+                self.body(body);
+            }
+            Declaration::Fn(FnDeclaration::ExternFunction {
+                publicity,
+                name,
+                generics,
+                params,
+                typ,
+                body,
+                ..
+            }) => {
+                self.publicity(publicity);
+                self.write("extern ");
+                self.fn_signature(name, generics, params, typ);
+                self.write(" = ");
+                self.write(&string_literal(body));
+            }
+            Declaration::Const(ConstDeclaration {
+                publicity,
+                name,
+                typ,
+                value,
+                ..
+            }) => {
+                self.publicity(publicity);
+                self.write("const ");
+                self.write(name);
+                self.write(": ");
+                self.typ(typ);
+                self.write(" = ");
+                self.expr(value);
+            }
+            Declaration::Type(TypeDeclaration::Struct {
+                location,
+                name,
+                publicity,
+                generics,
+                fields,
+                ..
+            }) => {
+                self.publicity(publicity);
+                self.write("type ");
+                self.write(name);
+                self.generics(generics);
+                self.write(" ");
+                self.fields(location.bytes.end, fields);
+            }
+            Declaration::Type(TypeDeclaration::Enum {
+                location,
+                name,
+                publicity,
+                generics,
+                variants,
+                ..
+            }) => {
+                self.publicity(publicity);
+                self.write("enum ");
+                self.write(name);
+                self.generics(generics);
+                self.write(" ");
+                let end = variants
+                    .last()
+                    .map(|variant| variant.location.bytes.end)
+                    .unwrap_or(location.bytes.end);
+                self.variants(end, variants);
+            }
+            Declaration::Type(TypeDeclaration::Alias {
+                name,
+                publicity,
+                target,
+                ..
+            }) => {
+                self.publicity(publicity);
+                self.write("type ");
+                self.write(name);
+                self.write(" = ");
+                self.typ(target);
+            }
+        }
+    }
+
+    /// Prints publicity
+    fn publicity(&mut self, publicity: &Publicity) {
+        if *publicity == Publicity::Public {
+            self.write("pub ");
+        }
+    }
+
+    /// Prints function signature `fn $name[$generics]($params): $typ`
+    fn fn_signature(
+        &mut self,
+        name: &EcoString,
+        generics: &[EcoString],
+        params: &[Parameter],
+        typ: &Option<TypePath>,
+    ) {
+        self.write("fn ");
+        self.write(name);
+        self.generics(generics);
+        self.params(params);
+        if let Some(typ) = typ {
+            self.write(": ");
+            self.typ(typ);
+        }
+    }
+
+    /// Prints generic parameters `[A, B, ...n]`, if given
+    fn generics(&mut self, generics: &[EcoString]) {
+        if !generics.is_empty() {
+            self.write("[");
+            self.write(&generics.join(", "));
+            self.write("]");
+        }
+    }
+
+    /// Prints parameters `(a: t1, b: t2, ...n)`
+    fn params(&mut self, params: &[Parameter]) {
+        self.write("(");
+        for (i, param) in params.iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            self.write(&param.name);
+            self.write(": ");
+            self.typ(&param.typ);
+        }
+        self.write(")");
+    }
+
+    /// Prints struct fields
+    fn fields(&mut self, end: usize, fields: &[Field]) {
+        if fields.is_empty() {
+            self.write("{}");
+            return;
+        }
+        self.write("{\n");
+        self.indent += 1;
+        for (i, field) in fields.iter().enumerate() {
+            let start = field.location.bytes.start;
+            self.node_start(start, i == 0, false);
+            self.write(&field.name);
+            self.write(": ");
+            self.typ(&field.typ);
+            if i + 1 < fields.len() {
+                self.write(",");
+            }
+            let next = fields
+                .get(i + 1)
+                .map_or(end, |field| field.location.bytes.start);
+            self.trailing_comment(next);
+            self.write("\n");
+        }
+        self.comments_before(end, true);
+        self.indent -= 1;
+        self.write_indent();
+        self.write("}");
+    }
+
+    /// Prints enum variants
+    fn variants(&mut self, end: usize, variants: &[EnumConstructor]) {
+        if variants.is_empty() {
+            self.write("{}");
+            return;
+        }
+        self.write("{\n");
+        self.indent += 1;
+        for (i, variant) in variants.iter().enumerate() {
+            let start = variant.location.bytes.start;
+            self.node_start(start, i == 0, false);
+            self.write(&variant.name);
+            if !variant.params.is_empty() {
+                self.params(&variant.params);
+            }
+            if i + 1 < variants.len() {
+                self.write(",");
+            }
+            let next = variants
+                .get(i + 1)
+                .map_or(end, |variant| variant.location.bytes.start);
+            self.trailing_comment(next);
+            self.write("\n");
+        }
+        self.comments_before(end, true);
+        self.indent -= 1;
+        self.write_indent();
+        self.write("}");
+    }
+
+    /// Prints type annotation
+    fn typ(&mut self, typ: &TypePath) {
+        match typ {
+            TypePath::Local { name, generics, .. } => {
+                self.write(name);
+                self.generic_args(generics);
+            }
+            TypePath::Module {
+                module,
+                name,
+                generics,
+                ..
+            } => {
+                self.write(module);
+                self.write(".");
+                self.write(name);
+                self.generic_args(generics);
+            }
+            TypePath::Function { params, ret, .. } => {
+                self.write("fn(");
+                for (i, param) in params.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.typ(param);
+                }
+                self.write(")");
+                if let Some(ret) = ret {
+                    self.write(": ");
+                    self.typ(ret);
+                }
+            }
+            TypePath::Unit { .. } => self.write("()"),
+        }
+    }
+
+    /// Prints generic arguments `[t1, t2, ...n]`, if given
+    fn generic_args(&mut self, generics: &[TypePath]) {
+        if !generics.is_empty() {
+            self.write("[");
+            for (i, generic) in generics.iter().enumerate() {
+                if i > 0 {
+                    self.write(", ");
+                }
+                self.typ(generic);
+            }
+            self.write("]");
+        }
+    }
+
+    /// Prints block or expression body,
+    /// expression body is prefixed with `=`
+    fn body<E: Borrow<Expression>>(&mut self, body: &Either<Block, E>) {
+        match body {
+            Either::Left(block) => {
+                self.write(" ");
+                self.block(block);
+            }
+            Either::Right(expr) => {
+                self.write(" = ");
+                self.expr(expr.borrow());
+            }
+        }
+    }
+
+    /// Prints block
+    fn block(&mut self, block: &Block) {
+        let end = block.location.bytes.end;
+        if block.body.is_empty() && !self.trivia.has_before(end) {
+            self.write("{}");
+            return;
+        }
+        self.write("{\n");
+        self.indent += 1;
+        for (i, stmt) in block.body.iter().enumerate() {
+            let start = stmt_start(stmt);
+            self.node_start(start, i == 0, false);
+            self.statement(stmt);
+            self.trailing_comment(block.body.get(i + 1).map_or(end, stmt_start));
+            self.write("\n");
+        }
+        self.comments_before(end, !block.body.is_empty());
+        self.indent -= 1;
+        self.write_indent();
+        self.write("}");
+    }
+
+    /// Prints statement
+    fn statement(&mut self, stmt: &Statement) {
+        match stmt {
+            Statement::VarDef {
+                name, value, typ, ..
+            } => {
+                self.write("let ");
+                self.write(name);
+                if let Some(typ) = typ {
+                    self.write(": ");
+                    self.typ(typ);
+                }
+                self.write(" = ");
+                self.expr(value);
+                self.write(";");
+            }
+            Statement::VarAssign { what, value, .. } => {
+                self.expr(what);
+                // Compound assignment `a += b` is desugared into
+                // `a = a + b` by the parser, so it's restored back
+                let op = self.trivia.token_after(what.location().bytes.end);
+                match value {
+                    Expression::Bin { right, .. } if op != "=" && op.ends_with('=') => {
+                        self.write(" ");
+                        self.write(op);
+                        self.write(" ");
+                        self.expr(right);
+                    }
+                    _ => {
+                        self.write(" = ");
+                        self.expr(value);
+                    }
+                }
+                self.write(";");
+            }
+            Statement::ParallelAssign { what, value, .. } => {
+                self.exprs(what);
+                self.write(" = ");
+                self.exprs(value);
+                self.write(";");
+            }
+            Statement::Expr(expr) => self.expr(expr),
+            Statement::Semi(expr) => {
+                self.expr(expr);
+                self.write(";");
+            }
+            Statement::Loop { logical, body, .. } => {
+                self.write("loop ");
+                self.expr(logical);
+                self.body(body);
+            }
+            Statement::For {
+                name, range, body, ..
+            } => {
+                self.write("for ");
+                self.write(name);
+                self.write(" in ");
+                match range.as_ref() {
+                    Range::ExcludeLast { from, to, .. } => {
+                        self.expr(from);
+                        self.write("..");
+                        self.expr(to);
+                    }
+                    Range::IncludeLast { from, to, .. } => {
+                        self.expr(from);
+                        self.write("..=");
+                        self.expr(to);
+                    }
+                }
+                self.body(body);
+            }
+            Statement::Throw { value, .. } => {
+                self.write("throw ");
+                self.expr(value);
+                self.write(";");
+            }
+            Statement::Labeled { label, body, .. } => {
+                self.write("'");
+                self.write(label);
+                self.write(": ");
+                self.block(body);
+            }
+        }
+    }
+
+    /// Prints operand of the binary expression,
+    /// wraps it into parens, if it's needed
+    fn operand(&mut self, expr: &Expression, parent: u8, right: bool) {
+        match expr {
+            Expression::Bin { op, .. }
+                if precedence(op) < parent || (right && precedence(op) == parent) =>
+            {
+                self.write("(");
+                self.expr(expr);
+                self.write(")");
+            }
+            _ => self.expr(expr),
+        }
+    }
+
+    /// Prints arguments `(a, b, ...n)`
+    fn args(&mut self, args: &[Expression]) {
+        self.write("(");
+        self.exprs(args);
+        self.write(")");
+    }
+
+    /// Prints comma separated expressions `a, b, ...n`
+    fn exprs(&mut self, exprs: &[Expression]) {
+        for (i, expr) in exprs.iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            self.expr(expr);
+        }
+    }
+
+    /// Prints expression
+    fn expr(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Int { value, .. }
+            | Expression::Float { value, .. }
+            | Expression::Bool { value, .. } => self.write(value),
+            Expression::String { value, .. } => self.write(&string_literal(value)),
+            Expression::Todo { text, .. } => {
+                self.write("todo");
+                if let Some(text) = text {
+                    self.write(" as ");
+                    self.write(&string_literal(text));
+                }
+            }
+            Expression::Panic { text, .. } => {
+                self.write("panic");
+                if let Some(text) = text {
+                    self.write(" as ");
+                    self.write(&string_literal(text));
+                }
+            }
+            Expression::Break { label, .. } => {
+                self.write("break '");
+                self.write(label);
+            }
+            Expression::Bin {
+                left, right, op, ..
+            } => {
+                self.operand(left, precedence(op), false);
+                self.write(" ");
+                self.write(binary_op(op));
+                self.write(" ");
+                self.operand(right, precedence(op), true);
+            }
+            Expression::As { value, typ, .. } => {
+                self.expr(value);
+                self.write(" as ");
+                self.typ(typ);
+            }
+            Expression::Unary { value, op, .. } => {
+                self.write(match op {
+                    UnaryOp::Neg => "-",
+                    UnaryOp::Bang => "!",
+                });
+                self.expr(value);
+            }
+            Expression::If {
+                logical,
+                body,
+                else_branches,
+                ..
+            } => {
+                self.write("if ");
+                self.expr(logical);
+                self.body(body);
+                for branch in else_branches {
+                    match branch {
+                        ElseBranch::Elif { logical, body, .. } => {
+                            self.write(" elif ");
+                            self.expr(logical);
+                            self.body(body);
+                        }
+                        ElseBranch::Else { body, .. } => {
+                            self.write(" else");
+                            self.body(body);
+                        }
+                    }
+                }
+            }
+            Expression::PrefixVar { name, .. } => self.write(name),
+            Expression::SuffixVar {
+                container, name, ..
+            } => {
+                self.expr(container);
+                self.write(".");
+                self.write(name);
+            }
+            Expression::Call { what, args, .. } => {
+                self.expr(what);
+                self.args(args);
+            }
+            Expression::OptionalChain { base, access, .. } => {
+                self.expr(base);
+                self.write("?.");
+                match access {
+                    ChainAccess::Field(name) => self.write(name),
+                    ChainAccess::Call(name, args) => {
+                        self.write(name);
+                        self.args(args);
+                    }
+                }
+            }
+            Expression::Function {
+                params, body, typ, ..
+            } => {
+                self.write("fn");
+                self.params(params);
+                if let Some(typ) = typ {
+                    self.write(": ");
+                    self.typ(typ);
+                }
+                self.body(body);
+            }
+            Expression::Match {
+                location,
+                value,
+                cases,
+            } => {
+                self.write("match ");
+                self.expr(value);
+                self.write(" ");
+                self.cases(location.bytes.end, cases);
+            }
+            Expression::Paren { expr, .. } => {
+                self.write("(");
+                self.expr(expr);
+                self.write(")");
+            }
+            Expression::TryCatch {
+                body,
+                bind,
+                typ,
+                handler,
+                ..
+            } => {
+                self.write("try ");
+                self.block(body);
+                self.write(" catch ");
+                self.write(bind);
+                if let Some(typ) = typ {
+                    self.write(": ");
+                    self.typ(typ);
+                }
+                self.write(" ");
+                self.block(handler);
+            }
+            Expression::Try { value, .. } => {
+                self.expr(value);
+                self.write("?");
+            }
+            Expression::Await { value, .. } => {
+                self.write("await ");
+                self.expr(value);
+            }
+        }
+    }
+
+    /// Prints match cases
+    fn cases(&mut self, end: usize, cases: &[Case]) {
+        if cases.is_empty() && !self.trivia.has_before(end) {
+            self.write("{}");
+            return;
+        }
+        self.write("{\n");
+        self.indent += 1;
+        for (i, case) in cases.iter().enumerate() {
+            let start = case.address.bytes.start;
+            self.node_start(start, i == 0, false);
+            self.pattern(&case.pattern);
+            self.write(" -> ");
+            match &case.body {
+                Either::Left(block) => self.block(block),
+                Either::Right(expr) => self.expr(expr),
+            }
+            self.trailing_comment(
+                cases
+                    .get(i + 1)
+                    .map_or(end, |case| case.address.bytes.start),
+            );
+            self.write("\n");
+        }
+        self.comments_before(end, !cases.is_empty());
+        self.indent -= 1;
+        self.write_indent();
+        self.write("}");
+    }
+
+    /// Prints pattern
+    fn pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Unwrap { en, fields, .. } => {
+                self.expr(en);
+                self.write("(");
+                for (i, (_, name)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.write(name);
+                }
+                self.write(")");
+            }
+            Pattern::Variant(_, en) => self.expr(en),
+            Pattern::Int(_, value)
+            | Pattern::Float(_, value)
+            | Pattern::Bool(_, value)
+            | Pattern::BindTo(_, value) => self.write(value),
+            Pattern::String(_, value) => self.write(&string_literal(value)),
+            Pattern::Wildcard => self.write("_"),
+            Pattern::Or(pat1, pat2) => {
+                self.pattern(pat1);
+                self.write(" | ");
+                self.pattern(pat2);
+            }
+            Pattern::Guard {
+                pattern, condition, ..
+            } => {
+                self.pattern(pattern);
+                self.write(" if ");
+                self.expr(condition);
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+/// Imports
+use ecow::EcoString;
+use std::{collections::VecDeque, ops::Range};
+use watt_lex::tokens::{Token, TokenKind};
+
+/// Comment, found in the source code
+#[derive(Debug, Clone)]
+pub struct Comment {
+    /// Byte offset of the comment start
+    pub start: usize,
+    /// Byte offset of the comment end
+    pub end: usize,
+    /// Comment text, including `//`, `///`, `//!` or `/* */`
+    pub text: EcoString,
+}
+
+/// Source code parts, that are dropped by the parser:
+/// comments and whitespaces between tokens.
+///
+/// # Notes
+/// Punctuation tokens are created with empty address
+/// right after the token, so their byte ranges are
+/// restored from the token value.
+///
+pub struct Trivia<'s> {
+    /// Source code
+    source: &'s str,
+    /// Byte ranges of the tokens in the source order,
+    /// doc comments are excluded.
+    tokens: Vec<Range<usize>>,
+    /// Not yet emitted comments in the source order
+    comments: VecDeque<Comment>,
+}
+
+/// Implementation
+impl<'s> Trivia<'s> {
+    /// Collects trivia from the source code and its tokens
+    pub fn new(source: &'s str, tokens: &[Token]) -> Self {
+        let mut trivia = Self {
+            source,
+            tokens: Vec::new(),
+            comments: VecDeque::new(),
+        };
+        let mut end = 0;
+        for token in tokens {
+            let range = Self::token_range(token);
+            trivia.scan_gap(end..range.start);
+            if let Some(prefix) = match token.tk_type {
+                TokenKind::DocComment => Some("///"),
+                TokenKind::ModuleDoc => Some("//!"),
+                _ => None,
+            } {
+                trivia.comments.push_back(Comment {
+                    start: range.start,
+                    end: range.end,
+                    text: if token.value.is_empty() {
+                        prefix.into()
+                    } else {
+                        format!("{prefix} {}", token.value).into()
+                    },
+                });
+            } else {
+                trivia.tokens.push(range.clone());
+            }
+            end = range.end;
+        }
+        trivia.scan_gap(end..source.len());
+        trivia
+    }
+
+    /// Byte range of the token in the source code
+    fn token_range(token: &Token) -> Range<usize> {
+        let bytes = token.address.bytes.clone();
+        if bytes.is_empty() {
+            bytes.end.saturating_sub(token.value.len())..bytes.end
+        } else {
+            bytes
+        }
+    }
+
+    /// Scans comments in the gap between two tokens
+    fn scan_gap(&mut self, gap: Range<usize>) {
+        let text = match self.source.get(gap.clone()) {
+            Some(text) => text,
+            None => return,
+        };
+        let bytes = text.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let rest = &text[i..];
+            // `// comment`
+            if rest.starts_with("//") {
+                let end = rest.find('\n').unwrap_or(rest.len());
+                self.comments.push_back(Comment {
+                    start: gap.start + i,
+                    end: gap.start + i + end,
+                    text: rest[..end].trim_end().into(),
+                });
+                i += end;
+            }
+            // `/* comment */`
+            else if rest.starts_with("/*") {
+                let end = Self::block_comment_end(rest);
+                self.comments.push_back(Comment {
+                    start: gap.start + i,
+                    end: gap.start + i + end,
+                    text: rest[..end].into(),
+                });
+                i += end;
+            } else {
+                i += rest.chars().next().map(char::len_utf8).unwrap_or(1);
+            }
+        }
+    }
+
+    /// Finds end of the block comment at the start of `text`,
+    /// nested block comments are skipped
+    fn block_comment_end(text: &str) -> usize {
+        let bytes = text.as_bytes();
+        let mut depth = 0;
+        let mut i = 0;
+        while i + 1 < bytes.len() {
+            match &bytes[i..i + 2] {
+                b"/*" => {
+                    depth += 1;
+                    i += 2;
+                }
+                b"*/" => {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        return i;
+                    }
+                }
+                _ => i += 1,
+            }
+        }
+        text.len()
+    }
+
+    /// Takes comments, that start before `offset`
+    pub fn take_before(&mut self, offset: usize) -> Vec<Comment> {
+        let mut taken = Vec::new();
+        while let Some(comment) = self.comments.front() {
+            if comment.start >= offset {
+                break;
+            }
+            taken.extend(self.comments.pop_front());
+        }
+        taken
+    }
+
+    /// Takes line comment, that starts before `offset` on the
+    /// same line with the preceding token, so it trails the token
+    pub fn take_trailing(&mut self, offset: usize) -> Option<Comment> {
+        let comment = self.comments.front()?;
+        if comment.start >= offset || !comment.text.starts_with("//") {
+            return None;
+        }
+        let from = self.token_end_before(comment.start);
+        match self.source.get(from..comment.start) {
+            Some(gap) if from > 0 && !gap.contains('\n') => self.comments.pop_front(),
+            _ => None,
+        }
+    }
+
+    /// Checks that there are comments, that start before `offset`
+    pub fn has_before(&self, offset: usize) -> bool {
+        self.comments
+            .front()
+            .is_some_and(|comment| comment.start < offset)
+    }
+
+    /// End of the token, that precedes `offset`
+    pub fn token_end_before(&self, offset: usize) -> usize {
+        let idx = self.tokens.partition_point(|range| range.end <= offset);
+        match idx {
+            0 => 0,
+            _ => self.tokens[idx - 1].end,
+        }
+    }
+
+    /// Checks that source between `from` and `to` contains a blank line
+    pub fn blank_between(&self, from: usize, to: usize) -> bool {
+        match self.source.get(from..to) {
+            Some(gap) => {
+                let lines: Vec<&str> = gap.split('\n').collect();
+                lines.len() > 2
+                    && lines[1..lines.len() - 1]
+                        .iter()
+                        .any(|line| line.trim().is_empty())
+            }
+            None => false,
+        }
+    }
+
+    /// Start of the token, that precedes `offset`
+    pub fn token_before(&self, offset: usize) -> usize {
+        let idx = self.tokens.partition_point(|range| range.start < offset);
+        match idx {
+            0 => offset,
+            _ => self.tokens[idx - 1].start,
+        }
+    }
+
+    /// Source text of the token, that follows `offset`
+    pub fn token_after(&self, offset: usize) -> &'s str {
+        let idx = self.tokens.partition_point(|range| range.start < offset);
+        match self.tokens.get(idx) {
+            Some(range) => &self.source[range.clone()],
+            None => "",
+        }
+    }
+}
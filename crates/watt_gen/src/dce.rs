@@ -0,0 +1,257 @@
+/// Imports
+use ecow::EcoString;
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+};
+use watt_ast::ast::{
+    Block, ChainAccess, Declaration, Either, ElseBranch, Expression, FnDeclaration, Module,
+    Pattern, Publicity, Range, Statement,
+};
+
+/// Collects names referenced from the code.
+///
+/// Every reference to the name counts, not only calls.
+/// So, functions passed as values are considered reachable.
+///
+#[derive(Default)]
+struct NamesCollector {
+    /// Referenced names
+    names: HashSet<EcoString>,
+}
+
+/// Implementation
+impl NamesCollector {
+    /// Collects names from the block or expression body
+    fn collect_body<E: Borrow<Expression>>(&mut self, body: &Either<Block, E>) {
+        match body {
+            Either::Left(block) => self.collect_block(block),
+            Either::Right(expr) => self.collect_expr(expr.borrow()),
+        }
+    }
+
+    /// Collects names from the block
+    fn collect_block(&mut self, block: &Block) {
+        for stmt in &block.body {
+            self.collect_stmt(stmt);
+        }
+    }
+
+    /// Collects names from the statement
+    fn collect_stmt(&mut self, stmt: &Statement) {
+        match stmt {
+            Statement::Labeled { body, .. } => self.collect_block(body),
+            Statement::VarDef { value, .. } | Statement::Throw { value, .. } => {
+                self.collect_expr(value)
+            }
+            Statement::VarAssign { what, value, .. } => {
+                self.collect_expr(what);
+                self.collect_expr(value);
+            }
+            Statement::ParallelAssign { what, value, .. } => {
+                for expr in what.iter().chain(value) {
+                    self.collect_expr(expr);
+                }
+            }
+            Statement::Expr(expr) | Statement::Semi(expr) => self.collect_expr(expr),
+            Statement::Loop { logical, body, .. } => {
+                self.collect_expr(logical);
+                self.collect_body(body);
+            }
+            Statement::For { range, body, .. } => {
+                let (from, to) = match range.as_ref() {
+                    Range::ExcludeLast { from, to, .. } => (from, to),
+                    Range::IncludeLast { from, to, .. } => (from, to),
+                };
+                self.collect_expr(from);
+                self.collect_expr(to);
+                self.collect_body(body);
+            }
+        }
+    }
+
+    /// Collects names from the pattern guards
+    fn collect_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Or(pat1, pat2) => {
+                self.collect_pattern(pat1);
+                self.collect_pattern(pat2);
+            }
+            Pattern::Guard {
+                pattern, condition, ..
+            } => {
+                self.collect_pattern(pattern);
+                self.collect_expr(condition);
+            }
+            _ => {}
+        }
+    }
+
+    /// Collects names from the expression
+    fn collect_expr(&mut self, expr: &Expression) {
+        match expr {
+            Expression::PrefixVar { name, .. } => {
+                self.names.insert(name.clone());
+            }
+            Expression::SuffixVar { container, .. } => self.collect_expr(container),
+            Expression::Bin { left, right, .. } => {
+                self.collect_expr(left);
+                self.collect_expr(right);
+            }
+            Expression::As { value, .. }
+            | Expression::Unary { value, .. }
+            | Expression::Try { value, .. }
+            | Expression::Await { value, .. } => self.collect_expr(value),
+            Expression::Paren { expr, .. } => self.collect_expr(expr),
+            Expression::If {
+                logical,
+                body,
+                else_branches,
+                ..
+            } => {
+                self.collect_expr(logical);
+                self.collect_body(body);
+                for branch in else_branches {
+                    match branch {
+                        ElseBranch::Elif { logical, body, .. } => {
+                            self.collect_expr(logical);
+                            self.collect_body(body);
+                        }
+                        ElseBranch::Else { body, .. } => self.collect_body(body),
+                    }
+                }
+            }
+            Expression::Call { what, args, .. } => {
+                self.collect_expr(what);
+                for arg in args {
+                    self.collect_expr(arg);
+                }
+            }
+            Expression::OptionalChain { base, access, .. } => {
+                self.collect_expr(base);
+                if let ChainAccess::Call(_, args) = access {
+                    for arg in args {
+                        self.collect_expr(arg);
+                    }
+                }
+            }
+            Expression::Function { body, .. } => self.collect_body(body),
+            Expression::TryCatch { body, handler, .. } => {
+                self.collect_block(body);
+                self.collect_block(handler);
+            }
+            Expression::Match { value, cases, .. } => {
+                self.collect_expr(value);
+                for case in cases {
+                    self.collect_pattern(&case.pattern);
+                    self.collect_body(&case.body);
+                }
+            }
+            Expression::Int { .. }
+            | Expression::Float { .. }
+            | Expression::String { .. }
+            | Expression::Bool { .. }
+            | Expression::Todo { .. }
+            | Expression::Panic { .. }
+            | Expression::Break { .. } => {}
+        }
+    }
+}
+
+/// Set of the module functions, that are reachable
+/// from the module exported code.
+///
+/// Reachability analysis starts from the public functions,
+/// `main` function and constants, and transitively includes
+/// functions, referenced by the reached code.
+///
+/// # Notes
+/// Local variables, that shadow function names are
+/// considered as references, so analysis is conservative.
+///
+pub struct ReachabilitySet {
+    /// Reached names
+    reached: HashSet<EcoString>,
+}
+
+/// Implementation
+impl ReachabilitySet {
+    /// Computes reachability set of the module
+    pub fn new(module: &Module) -> Self {
+        // Building call graph and collecting roots
+        let mut graph: HashMap<&EcoString, HashSet<EcoString>> = HashMap::new();
+        let mut roots = NamesCollector::default();
+        for decl in &module.declarations {
+            match decl {
+                Declaration::Fn(FnDeclaration::Function {
+                    name,
+                    publicity,
+                    body,
+                    ..
+                })
+                | Declaration::Fn(FnDeclaration::ConstFunction {
+                    name,
+                    publicity,
+                    body,
+                    ..
+                }) => {
+                    let mut collector = NamesCollector::default();
+                    collector.collect_body(body);
+                    graph.entry(name).or_default().extend(collector.names);
+                    if *publicity == Publicity::Public || name == "main" {
+                        roots.names.insert(name.clone());
+                    }
+                }
+                Declaration::Fn(FnDeclaration::ExternFunction {
+                    name, publicity, ..
+                }) => {
+                    graph.entry(name).or_default();
+                    if *publicity == Publicity::Public {
+                        roots.names.insert(name.clone());
+                    }
+                }
+                Declaration::Const(decl) => roots.collect_expr(&decl.value),
+                Declaration::Type(_) => {}
+            }
+        }
+
+        // Reachability analysis
+        let mut reached = HashSet::new();
+        let mut stack: Vec<EcoString> = roots.names.into_iter().collect();
+        while let Some(name) = stack.pop() {
+            if !reached.insert(name.clone()) {
+                continue;
+            }
+            if let Some(refs) = graph.get(&name) {
+                stack.extend(refs.iter().filter(|r| !reached.contains(*r)).cloned());
+            }
+        }
+
+        Self { reached }
+    }
+
+    /// Checks that declaration should be generated
+    ///
+    /// # Notes
+    /// Only functions could be eliminated,
+    /// other declarations are always generated.
+    ///
+    pub fn is_live(&self, decl: &Declaration) -> bool {
+        match decl {
+            Declaration::Fn(FnDeclaration::Function { name, .. })
+            | Declaration::Fn(FnDeclaration::ConstFunction { name, .. })
+            | Declaration::Fn(FnDeclaration::ExternFunction { name, .. }) => {
+                self.reached.contains(name)
+            }
+            Declaration::Const(_) | Declaration::Type(_) => true,
+        }
+    }
+}
+
+/// Removes statements, that follow the first
+/// diverging statement, since they're unreachable.
+pub fn truncate_unreachable(body: &mut Vec<Statement>) {
+    if let Some(idx) = body.iter().position(Statement::diverges) {
+        body.truncate(idx + 1);
+    }
+}
@@ -0,0 +1,133 @@
+/// Imports
+use std::borrow::Borrow;
+use watt_ast::ast::{Block, ChainAccess, Either, ElseBranch, Expression, Range, Statement};
+
+/// Checks that block or expression body contains `?` propagation
+pub fn body_propagates<E: Borrow<Expression>>(body: &Either<Block, E>) -> bool {
+    body_contains(body, is_try)
+}
+
+/// Checks that block or expression body contains `await`
+pub fn body_awaits<E: Borrow<Expression>>(body: &Either<Block, E>) -> bool {
+    body_contains(body, is_await)
+}
+
+/// Checks that expression contains `await`
+pub fn expr_awaits(expr: &Expression) -> bool {
+    expr_contains(expr, is_await)
+}
+
+/// Checks that expression is `?` propagation
+fn is_try(expr: &Expression) -> bool {
+    matches!(expr, Expression::Try { .. })
+}
+
+/// Checks that expression is `await`
+fn is_await(expr: &Expression) -> bool {
+    matches!(expr, Expression::Await { .. })
+}
+
+/// Checks that block or expression body contains expression, matching `pred`
+fn body_contains<E: Borrow<Expression>>(
+    body: &Either<Block, E>,
+    pred: fn(&Expression) -> bool,
+) -> bool {
+    match body {
+        Either::Left(block) => block_contains(block, pred),
+        Either::Right(expr) => expr_contains(expr.borrow(), pred),
+    }
+}
+
+/// Checks that block contains expression, matching `pred`
+fn block_contains(block: &Block, pred: fn(&Expression) -> bool) -> bool {
+    block.body.iter().any(|stmt| stmt_contains(stmt, pred))
+}
+
+/// Checks that statement contains expression, matching `pred`
+fn stmt_contains(stmt: &Statement, pred: fn(&Expression) -> bool) -> bool {
+    match stmt {
+        Statement::Labeled { body, .. } => block_contains(body, pred),
+        Statement::VarDef { value, .. } | Statement::Throw { value, .. } => {
+            expr_contains(value, pred)
+        }
+        Statement::VarAssign { what, value, .. } => {
+            expr_contains(what, pred) || expr_contains(value, pred)
+        }
+        Statement::ParallelAssign { what, value, .. } => what
+            .iter()
+            .chain(value)
+            .any(|expr| expr_contains(expr, pred)),
+        Statement::Expr(expr) | Statement::Semi(expr) => expr_contains(expr, pred),
+        Statement::Loop { logical, body, .. } => {
+            expr_contains(logical, pred) || body_contains(body, pred)
+        }
+        Statement::For { range, body, .. } => {
+            let (from, to) = match range.as_ref() {
+                Range::ExcludeLast { from, to, .. } => (from, to),
+                Range::IncludeLast { from, to, .. } => (from, to),
+            };
+            expr_contains(from, pred) || expr_contains(to, pred) || body_contains(body, pred)
+        }
+    }
+}
+
+/// Checks that expression contains expression, matching `pred`
+///
+/// # Notes
+/// Anonymous functions aren't visited, because `?` and `await`
+/// inside them affect the anonymous function itself, and `break`
+/// could not leave it.
+///
+fn expr_contains(expr: &Expression, pred: fn(&Expression) -> bool) -> bool {
+    if pred(expr) {
+        return true;
+    }
+    match expr {
+        Expression::Bin { left, right, .. } => {
+            expr_contains(left, pred) || expr_contains(right, pred)
+        }
+        Expression::As { value, .. }
+        | Expression::Unary { value, .. }
+        | Expression::Try { value, .. }
+        | Expression::Await { value, .. } => expr_contains(value, pred),
+        Expression::Paren { expr, .. } => expr_contains(expr, pred),
+        Expression::SuffixVar { container, .. } => expr_contains(container, pred),
+        Expression::Call { what, args, .. } => {
+            expr_contains(what, pred) || args.iter().any(|arg| expr_contains(arg, pred))
+        }
+        Expression::OptionalChain { base, access, .. } => {
+            expr_contains(base, pred)
+                || matches!(access, ChainAccess::Call(_, args) if args.iter().any(|arg| expr_contains(arg, pred)))
+        }
+        Expression::If {
+            logical,
+            body,
+            else_branches,
+            ..
+        } => {
+            expr_contains(logical, pred)
+                || body_contains(body, pred)
+                || else_branches.iter().any(|branch| match branch {
+                    ElseBranch::Elif { logical, body, .. } => {
+                        expr_contains(logical, pred) || body_contains(body, pred)
+                    }
+                    ElseBranch::Else { body, .. } => body_contains(body, pred),
+                })
+        }
+        Expression::Match { value, cases, .. } => {
+            expr_contains(value, pred) || cases.iter().any(|case| body_contains(&case.body, pred))
+        }
+        Expression::TryCatch { body, handler, .. } => {
+            block_contains(body, pred) || block_contains(handler, pred)
+        }
+        Expression::Function { .. }
+        | Expression::PrefixVar { .. }
+        | Expression::Int { .. }
+        | Expression::Float { .. }
+        | Expression::String { .. }
+        | Expression::Bool { .. }
+        | Expression::Todo { .. }
+        | Expression::Panic { .. }
+        | Expression::Break { .. } => false,
+    }
+}
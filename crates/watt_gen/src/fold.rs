@@ -0,0 +1,340 @@
+/// Imports
+use ecow::EcoString;
+use std::{borrow::Borrow, cell::Cell, collections::HashMap};
+use watt_ast::ast::{
+    BinaryOp, Block, ConstDeclaration, Declaration, Either, ElseBranch, Expression, FnDeclaration,
+    Parameter, Statement, UnaryOp,
+};
+
+/// Max safe integer of js numbers, `2^53 - 1`
+const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+
+/// Max count of the const function calls, evaluated
+/// while folding a single constant, deeper or longer
+/// evaluations are left to runtime
+const MAX_CALLS: usize = 10_000;
+
+/// Constant value
+#[derive(Clone)]
+enum Const {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    String(EcoString),
+}
+
+/// Const function of the module
+struct ConstFn<'a> {
+    params: &'a [Parameter],
+    body: &'a Either<Block, Expression>,
+}
+
+/// Environment of the constant evaluation
+struct Env<'a> {
+    /// Folded module constants
+    consts: &'a HashMap<EcoString, Const>,
+    /// Const functions of the module
+    fns: &'a HashMap<EcoString, ConstFn<'a>>,
+    /// Parameters and `let` bindings of the evaluated const function
+    locals: HashMap<EcoString, Const>,
+    /// Count of the evaluated calls
+    calls: &'a Cell<usize>,
+}
+
+/// Implementation
+impl<'a> Env<'a> {
+    /// Creates environment with the same
+    /// constants and functions, and given locals
+    fn with_locals(&self, locals: HashMap<EcoString, Const>) -> Self {
+        Self {
+            consts: self.consts,
+            fns: self.fns,
+            locals,
+            calls: self.calls,
+        }
+    }
+}
+
+/// Evaluates constant expression, variables are looked up in `env`,
+/// returns `None` if expression isn't constant
+/// or couldn't be folded without changing js semantics
+fn eval(expr: &Expression, env: &Env) -> Option<Const> {
+    match expr {
+        Expression::PrefixVar { name, .. } => env
+            .locals
+            .get(name)
+            .or_else(|| env.consts.get(name))
+            .cloned(),
+        Expression::Int { value, .. } => value
+            .parse::<i64>()
+            .ok()
+            .filter(|int| int.abs() <= MAX_SAFE_INTEGER)
+            .map(Const::Int),
+        Expression::Float { value, .. } => value
+            .parse::<f64>()
+            .ok()
+            .filter(|float| float.is_finite())
+            .map(Const::Float),
+        Expression::Bool { value, .. } => Some(Const::Bool(value == "true")),
+        Expression::String { value, .. } => Some(Const::String(value.clone())),
+        Expression::Paren { expr, .. } => eval(expr, env),
+        Expression::Unary { op, value, .. } => match (op, eval(value, env)?) {
+            (UnaryOp::Neg, Const::Int(int)) => Some(Const::Int(-int)),
+            (UnaryOp::Neg, Const::Float(float)) => Some(Const::Float(-float)),
+            (UnaryOp::Bang, Const::Bool(bool)) => Some(Const::Bool(!bool)),
+            _ => None,
+        },
+        Expression::Bin {
+            op, left, right, ..
+        } => eval_bin(op, eval(left, env)?, eval(right, env)?),
+        Expression::Call { what, args, .. } => eval_call(what, args, env),
+        Expression::If {
+            logical,
+            body,
+            else_branches,
+            ..
+        } => {
+            if let Const::Bool(true) = eval(logical, env)? {
+                return eval_body(body, env);
+            }
+            for branch in else_branches {
+                match branch {
+                    ElseBranch::Elif { logical, body, .. } => {
+                        if let Const::Bool(true) = eval(logical, env)? {
+                            return eval_body(body, env);
+                        }
+                    }
+                    ElseBranch::Else { body, .. } => return eval_body(body, env),
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Evaluates call of the module const function,
+/// arguments are bound to the function parameters
+fn eval_call(what: &Expression, args: &[Expression], env: &Env) -> Option<Const> {
+    let Expression::PrefixVar { name, .. } = what else {
+        return None;
+    };
+    if env.locals.contains_key(name) || env.calls.get() >= MAX_CALLS {
+        return None;
+    }
+    let function = env.fns.get(name)?;
+    if function.params.len() != args.len() {
+        return None;
+    }
+    env.calls.set(env.calls.get() + 1);
+    let locals = function
+        .params
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| Some((param.name.clone(), eval(arg, env)?)))
+        .collect::<Option<HashMap<_, _>>>()?;
+    eval_body(function.body, &env.with_locals(locals))
+}
+
+/// Evaluates block or expression body
+fn eval_body<E: Borrow<Expression>>(body: &Either<Block, E>, env: &Env) -> Option<Const> {
+    match body {
+        Either::Left(block) => eval_block(block, env),
+        Either::Right(expr) => eval(expr.borrow(), env),
+    }
+}
+
+/// Evaluates block, that consists of `let` bindings
+/// and the resulting expression
+fn eval_block(block: &Block, env: &Env) -> Option<Const> {
+    let mut env = env.with_locals(env.locals.clone());
+    let (last, bindings) = block.body.split_last()?;
+    for stmt in bindings {
+        match stmt {
+            Statement::VarDef { name, value, .. } => {
+                let value = eval(value, &env)?;
+                env.locals.insert(name.clone(), value);
+            }
+            _ => return None,
+        }
+    }
+    match last {
+        Statement::Expr(expr) => eval(expr, &env),
+        _ => None,
+    }
+}
+
+/// Evaluates binary operation over constants
+///
+/// # Notes
+/// Division and modulo are left to runtime, so division
+/// by zero behaves the same. Bitwise operations are left to
+/// runtime too, because js performs them on 32-bit integers.
+///
+fn eval_bin(op: &BinaryOp, left: Const, right: Const) -> Option<Const> {
+    match (left, right) {
+        (Const::Int(a), Const::Int(b)) => match op {
+            BinaryOp::Add => a.checked_add(b).and_then(safe_int),
+            BinaryOp::Sub => a.checked_sub(b).and_then(safe_int),
+            BinaryOp::Mul => a.checked_mul(b).and_then(safe_int),
+            _ => compare(op, a.cmp(&b)),
+        },
+        (Const::Float(a), Const::Float(b)) => eval_float(op, a, b),
+        (Const::Int(a), Const::Float(b)) => eval_float(op, a as f64, b),
+        (Const::Float(a), Const::Int(b)) => eval_float(op, a, b as f64),
+        (Const::Bool(a), Const::Bool(b)) => match op {
+            BinaryOp::And => Some(Const::Bool(a && b)),
+            BinaryOp::Or => Some(Const::Bool(a || b)),
+            BinaryOp::Eq => Some(Const::Bool(a == b)),
+            BinaryOp::NotEq => Some(Const::Bool(a != b)),
+            _ => None,
+        },
+        (Const::String(a), Const::String(b)) => match op {
+            BinaryOp::Concat => {
+                let mut string = a;
+                string.push_str(&b);
+                Some(Const::String(string))
+            }
+            BinaryOp::Eq => Some(Const::Bool(a == b)),
+            BinaryOp::NotEq => Some(Const::Bool(a != b)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Evaluates binary operation over float constants
+fn eval_float(op: &BinaryOp, a: f64, b: f64) -> Option<Const> {
+    let float = match op {
+        BinaryOp::Add => a + b,
+        BinaryOp::Sub => a - b,
+        BinaryOp::Mul => a * b,
+        _ => return compare(op, a.partial_cmp(&b)?),
+    };
+    if float.is_finite() {
+        Some(Const::Float(float))
+    } else {
+        None
+    }
+}
+
+/// Evaluates comparison by the ordering of operands
+fn compare(op: &BinaryOp, ordering: std::cmp::Ordering) -> Option<Const> {
+    match op {
+        BinaryOp::Eq => Some(Const::Bool(ordering.is_eq())),
+        BinaryOp::NotEq => Some(Const::Bool(ordering.is_ne())),
+        BinaryOp::Gt => Some(Const::Bool(ordering.is_gt())),
+        BinaryOp::Ge => Some(Const::Bool(ordering.is_ge())),
+        BinaryOp::Lt => Some(Const::Bool(ordering.is_lt())),
+        BinaryOp::Le => Some(Const::Bool(ordering.is_le())),
+        _ => None,
+    }
+}
+
+/// Checks that int is exactly representable by js number
+fn safe_int(int: i64) -> Option<Const> {
+    if int.abs() <= MAX_SAFE_INTEGER {
+        Some(Const::Int(int))
+    } else {
+        None
+    }
+}
+
+/// Folds constant binary or unary expression into the literal,
+/// returns `None` if expression couldn't be folded.
+///
+/// # Example
+/// `2 + 3 * 4` is folded into `14`,
+/// `"a" <> "b"` is folded into `"ab"`.
+///
+pub fn fold(expr: &Expression) -> Option<Expression> {
+    // Only operations are folded, literals are kept as is
+    if !matches!(expr, Expression::Bin { .. } | Expression::Unary { .. }) {
+        return None;
+    }
+    let env = Env {
+        consts: &HashMap::new(),
+        fns: &HashMap::new(),
+        locals: HashMap::new(),
+        calls: &Cell::new(0),
+    };
+    let value = eval(expr, &env)?;
+    Some(literal(expr, value))
+}
+
+/// Converts constant value into the literal
+/// expression with location of `expr`
+fn literal(expr: &Expression, value: Const) -> Expression {
+    let location = expr.location();
+    match value {
+        Const::Int(int) => Expression::Int {
+            location,
+            value: int.to_string().into(),
+        },
+        Const::Float(float) => Expression::Float {
+            location,
+            value: format!("{float:?}").into(),
+        },
+        Const::Bool(bool) => Expression::Bool {
+            location,
+            value: bool.to_string().into(),
+        },
+        Const::String(value) => Expression::String { location, value },
+    }
+}
+
+/// Folded values of the module constants,
+/// constants, that couldn't be folded, are omitted
+pub struct ConstValues {
+    values: HashMap<EcoString, Const>,
+}
+
+/// Implementation
+impl ConstValues {
+    /// Folds values of the module constants in the declaration order,
+    /// so constant could use values of the previously declared ones.
+    /// Calls of the module const functions are evaluated too.
+    pub fn new(declarations: &[Declaration]) -> Self {
+        let fns = declarations
+            .iter()
+            .filter_map(|decl| match decl {
+                Declaration::Fn(FnDeclaration::ConstFunction {
+                    name, params, body, ..
+                }) => Some((name.clone(), ConstFn { params, body })),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+        let consts = declarations.iter().filter_map(|decl| match decl {
+            Declaration::Const(decl) => Some(decl),
+            _ => None,
+        });
+        let mut values = HashMap::new();
+        for ConstDeclaration { name, value, .. } in consts {
+            let env = Env {
+                consts: &values,
+                fns: &fns,
+                locals: HashMap::new(),
+                calls: &Cell::new(0),
+            };
+            if let Some(folded) = eval(value, &env) {
+                values.insert(name.clone(), folded);
+            }
+        }
+        Self { values }
+    }
+
+    /// Folds constant declaration value into the literal,
+    /// value is kept as is, if it couldn't be folded.
+    ///
+    /// # Example
+    /// `const TWO = ONE + 1` is folded into `const TWO = 2`,
+    /// if `ONE` is folded. `const NINE = square(3)` is folded
+    /// into `const NINE = 9`, if `square` is const function.
+    ///
+    pub fn fold_const(&self, mut decl: ConstDeclaration) -> ConstDeclaration {
+        if let Some(value) = self.values.get(&decl.name) {
+            decl.value = literal(&decl.value, value.clone());
+        }
+        decl
+    }
+}
@@ -0,0 +1,262 @@
+/// Imports
+use ecow::EcoString;
+use std::{borrow::Borrow, collections::HashSet};
+use watt_ast::{
+    ast::{
+        BinaryOp, Block, ChainAccess, ConstDeclaration, Declaration, Either, ElseBranch,
+        Expression, FnDeclaration, Pattern, Range, Statement, TypeDeclaration,
+    },
+    hints::{Hints, TryKind},
+};
+
+/// Prelude symbols, that could be imported by the module,
+/// in the order of the generated import
+const PRELUDE_SYMBOLS: [&str; 17] = [
+    "$$match",
+    "$$equals",
+    "$$todo",
+    "$$panic",
+    "$$range",
+    "$$EqPattern",
+    "$$UnwrapPattern",
+    "$$WildcardPattern",
+    "$$BindPattern",
+    "$$VariantPattern",
+    "$$GuardPattern",
+    "$$Propagate",
+    "$$try",
+    "$$try_optional",
+    "$$Thrown",
+    "$$Break",
+    "$$break",
+];
+
+/// Collects prelude symbols referenced by the generated module code.
+///
+/// Symbols are collected from the ast, by the same rules,
+/// that are used during code generation. So, expression
+/// could require a symbol, even if it's folded later.
+///
+struct PreludeCollector<'a> {
+    /// Used symbols
+    used: HashSet<&'static str>,
+    /// Code generation hints
+    hints: &'a Hints,
+}
+
+/// Implementation
+impl PreludeCollector<'_> {
+    /// Marks symbol as used
+    fn use_symbol(&mut self, symbol: &'static str) {
+        self.used.insert(symbol);
+    }
+
+    /// Collects symbols from the declaration
+    fn collect_decl(&mut self, decl: &Declaration) {
+        match decl {
+            Declaration::Fn(FnDeclaration::Function { body, .. })
+            | Declaration::Fn(FnDeclaration::ConstFunction { body, .. }) => self.collect_body(body),
+            Declaration::Const(ConstDeclaration { value, .. }) => self.collect_expr(value),
+            Declaration::Fn(FnDeclaration::ExternFunction { .. }) | Declaration::Type(_) => {}
+        }
+    }
+
+    /// Collects symbols from the block or expression body
+    fn collect_body<E: Borrow<Expression>>(&mut self, body: &Either<Block, E>) {
+        match body {
+            Either::Left(block) => self.collect_block(block),
+            Either::Right(expr) => self.collect_expr(expr.borrow()),
+        }
+    }
+
+    /// Collects symbols from the block
+    fn collect_block(&mut self, block: &Block) {
+        for stmt in &block.body {
+            self.collect_stmt(stmt);
+        }
+    }
+
+    /// Collects symbols from the statement
+    fn collect_stmt(&mut self, stmt: &Statement) {
+        match stmt {
+            Statement::Labeled { body, .. } => {
+                self.use_symbol("$$Break");
+                self.collect_block(body);
+            }
+            Statement::VarDef { value, .. } => self.collect_expr(value),
+            Statement::Throw { value, .. } => {
+                self.use_symbol("$$Thrown");
+                self.collect_expr(value);
+            }
+            Statement::VarAssign { what, value, .. } => {
+                self.collect_expr(what);
+                self.collect_expr(value);
+            }
+            Statement::ParallelAssign { what, value, .. } => {
+                for expr in what.iter().chain(value) {
+                    self.collect_expr(expr);
+                }
+            }
+            Statement::Expr(expr) | Statement::Semi(expr) => self.collect_expr(expr),
+            Statement::Loop { logical, body, .. } => {
+                self.collect_expr(logical);
+                self.collect_body(body);
+            }
+            Statement::For { range, body, .. } => {
+                self.use_symbol("$$range");
+                let (from, to) = match range.as_ref() {
+                    Range::ExcludeLast { from, to, .. } => (from, to),
+                    Range::IncludeLast { from, to, .. } => (from, to),
+                };
+                self.collect_expr(from);
+                self.collect_expr(to);
+                self.collect_body(body);
+            }
+        }
+    }
+
+    /// Collects symbols from the pattern
+    fn collect_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Int(..) | Pattern::Float(..) | Pattern::Bool(..) | Pattern::String(..) => {
+                self.use_symbol("$$EqPattern")
+            }
+            Pattern::Unwrap { .. } => self.use_symbol("$$UnwrapPattern"),
+            Pattern::Wildcard => self.use_symbol("$$WildcardPattern"),
+            Pattern::BindTo(..) => self.use_symbol("$$BindPattern"),
+            Pattern::Variant(..) => self.use_symbol("$$VariantPattern"),
+            Pattern::Or(pat1, pat2) => {
+                self.collect_pattern(pat1);
+                self.collect_pattern(pat2);
+            }
+            Pattern::Guard {
+                pattern, condition, ..
+            } => {
+                self.use_symbol("$$GuardPattern");
+                self.collect_pattern(pattern);
+                self.collect_expr(condition);
+            }
+        }
+    }
+
+    /// Collects symbols from the expression
+    fn collect_expr(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Bin {
+                left, right, op, ..
+            } => {
+                if matches!(op, BinaryOp::Eq | BinaryOp::NotEq) {
+                    self.use_symbol("$$equals");
+                }
+                self.collect_expr(left);
+                self.collect_expr(right);
+            }
+            // `?` is generated as `$$try` or `$$try_optional`,
+            // and the enclosing function catches `$$Propagate`
+            Expression::Try { location, value } => {
+                match self.hints.tries.get(&location.span) {
+                    Some(TryKind::Optional) => self.use_symbol("$$try_optional"),
+                    _ => self.use_symbol("$$try"),
+                }
+                self.use_symbol("$$Propagate");
+                self.collect_expr(value);
+            }
+            Expression::As { value, .. }
+            | Expression::Unary { value, .. }
+            | Expression::Await { value, .. } => self.collect_expr(value),
+            Expression::Paren { expr, .. } => self.collect_expr(expr),
+            Expression::SuffixVar { container, .. } => self.collect_expr(container),
+            Expression::Call { what, args, .. } => {
+                self.collect_expr(what);
+                for arg in args {
+                    self.collect_expr(arg);
+                }
+            }
+            Expression::OptionalChain { base, access, .. } => {
+                self.collect_expr(base);
+                if let ChainAccess::Call(_, args) = access {
+                    for arg in args {
+                        self.collect_expr(arg);
+                    }
+                }
+            }
+            Expression::If {
+                logical,
+                body,
+                else_branches,
+                ..
+            } => {
+                self.collect_expr(logical);
+                self.collect_body(body);
+                for branch in else_branches {
+                    match branch {
+                        ElseBranch::Elif { logical, body, .. } => {
+                            self.collect_expr(logical);
+                            self.collect_body(body);
+                        }
+                        ElseBranch::Else { body, .. } => self.collect_body(body),
+                    }
+                }
+            }
+            Expression::Match { value, cases, .. } => {
+                self.use_symbol("$$match");
+                self.collect_expr(value);
+                for case in cases {
+                    self.collect_pattern(&case.pattern);
+                    self.collect_body(&case.body);
+                }
+            }
+            Expression::Function { body, .. } => self.collect_body(body),
+            Expression::TryCatch { body, handler, .. } => {
+                self.use_symbol("$$Thrown");
+                self.collect_block(body);
+                self.collect_block(handler);
+            }
+            Expression::Todo { .. } => self.use_symbol("$$todo"),
+            Expression::Panic { .. } => self.use_symbol("$$panic"),
+            Expression::Break { .. } => self.use_symbol("$$break"),
+            Expression::PrefixVar { .. }
+            | Expression::Int { .. }
+            | Expression::Float { .. }
+            | Expression::String { .. }
+            | Expression::Bool { .. } => {}
+        }
+    }
+}
+
+/// Collects prelude symbols, that should be imported
+/// by the module with given generated declarations
+pub fn prelude_imports<'a>(
+    declarations: impl IntoIterator<Item = &'a Declaration>,
+    hints: &Hints,
+) -> Vec<&'static str> {
+    let mut collector = PreludeCollector {
+        used: HashSet::new(),
+        hints,
+    };
+    for decl in declarations {
+        collector.collect_decl(decl);
+    }
+    PRELUDE_SYMBOLS
+        .into_iter()
+        .filter(|symbol| collector.used.contains(symbol))
+        .collect()
+}
+
+/// Collects names, declared by the module declarations
+/// in the generated code, type aliases are erased,
+/// so they don't declare anything
+pub fn declared_names(declarations: &[Declaration]) -> HashSet<EcoString> {
+    declarations
+        .iter()
+        .filter_map(|decl| match decl {
+            Declaration::Fn(FnDeclaration::Function { name, .. })
+            | Declaration::Fn(FnDeclaration::ConstFunction { name, .. })
+            | Declaration::Fn(FnDeclaration::ExternFunction { name, .. })
+            | Declaration::Const(ConstDeclaration { name, .. })
+            | Declaration::Type(TypeDeclaration::Struct { name, .. })
+            | Declaration::Type(TypeDeclaration::Enum { name, .. }) => Some(name.clone()),
+            Declaration::Type(TypeDeclaration::Alias { .. }) => None,
+        })
+        .collect()
+}
@@ -1,10 +1,22 @@
+// Modules
+pub mod dce;
+pub mod effects;
+pub mod fold;
+pub mod imports;
+
 /// Imports
+use crate::{dce::ReachabilitySet, fold::ConstValues};
 use ecow::EcoString;
 use genco::{lang::js, quote, tokens::quoted};
+use std::collections::{HashMap, HashSet};
 use tracing::instrument;
-use watt_ast::ast::{
-    BinaryOp, Block, ConstDeclaration, Declaration, Either, ElseBranch, Expression, FnDeclaration,
-    Module, Pattern, Range, Statement, TypeDeclaration, UnaryOp, UseKind,
+use watt_ast::{
+    ast::{
+        Attribute, BinaryOp, Block, ChainAccess, ConstDeclaration, Declaration, Dependency, Either,
+        ElseBranch, Expression, FnDeclaration, Module, Parameter, Pattern, Publicity, Range,
+        Statement, TypeDeclaration, UnaryOp, UseKind,
+    },
+    hints::{CatchKind, Hints, Signature, TryKind},
 };
 
 /// Replaces js identifiers equal
@@ -80,25 +92,38 @@ pub fn try_escape_js(identifier: &str) -> String {
     }
 }
 
+/// Generates `async` modifier of the function,
+/// which body contains `await`
+fn gen_async(awaits: bool) -> &'static str {
+    if awaits { "async " } else { "" }
+}
+
+/// Generates `await` of the immediately invoked function
+/// or the match, which body contains `await`
+fn gen_await(awaits: bool) -> &'static str {
+    if awaits { "await " } else { "" }
+}
+
 /// Generates pattern code
-fn gen_pattern(pattern: Pattern, body: Either<Block, Expression>) -> js::Tokens {
+fn gen_pattern(pattern: Pattern, body: Either<Block, Expression>, hints: &Hints) -> js::Tokens {
+    let async_ = gen_async(effects::body_awaits(&body));
     quote! {
         $(match pattern {
             // Int, float, bool patterns
             Pattern::Int(_, val) | Pattern::Float(_, val) | Pattern::Bool(_, val)  => {
-                new $("$$")EqPattern($(val.as_str()), function() {
+                new $("$$")EqPattern($(val.as_str()), $(async_)function() {
                     $(match body {
-                        Either::Left(block) => $(gen_block_expr(block)),
-                        Either::Right(expr) => return $(gen_expression(expr))
+                        Either::Left(block) => $(gen_block_expr(block, hints)),
+                        Either::Right(expr) => return $(gen_expression(expr, hints))
                     })
                 })
             },
             // String pattern
             Pattern::String(_, val) => {
-                new $("$$")EqPattern($(quoted(val.as_str())), function() {
+                new $("$$")EqPattern($(quoted(val.as_str())), $(async_)function() {
                     $(match body {
-                        Either::Left(block) => $(gen_block_expr(block)),
-                        Either::Right(expr) => return $(gen_expression(expr))
+                        Either::Left(block) => $(gen_block_expr(block, hints)),
+                        Either::Right(expr) => return $(gen_expression(expr, hints))
                     })
                 })
             }
@@ -110,31 +135,31 @@ fn gen_pattern(pattern: Pattern, body: Either<Block, Expression>) -> js::Tokens
                         _ => $(quoted("unreachable"))
                     }),
                     [$(for field in fields.clone() join (, ) => $(quoted(try_escape_js(&field.1))))],
-                    function($("$$fields")) {
+                    $(async_)function($("$$fields")) {
                         $(for field in fields => let $(try_escape_js(&field.1)) = $("$$fields").$(try_escape_js(&field.1));$['\r'])
                         $(match body {
-                            Either::Left(block) => $(gen_block_expr(block)),
-                            Either::Right(expr) => return $(gen_expression(expr))
+                            Either::Left(block) => $(gen_block_expr(block, hints)),
+                            Either::Right(expr) => return $(gen_expression(expr, hints))
                         })
                     }
                 )
             },
             // Wildcard pattern
             Pattern::Wildcard => {
-                new $("$$")WildcardPattern(function() {
+                new $("$$")WildcardPattern($(async_)function() {
                     $(match body {
-                        Either::Left(block) => $(gen_block_expr(block)),
-                        Either::Right(expr) => return $(gen_expression(expr))
+                        Either::Left(block) => $(gen_block_expr(block, hints)),
+                        Either::Right(expr) => return $(gen_expression(expr, hints))
                     })
                 })
             }
             // BindTo(var) pattern
             Pattern::BindTo(_, var) => {
-                new $("$$")BindPattern(function($("$$it")) {
+                new $("$$")BindPattern($(async_)function($("$$it")) {
                     $(try_escape_js(var.as_str())) = $("$$it")
                     $(match body {
-                        Either::Left(block) => $(gen_block_expr(block)),
-                        Either::Right(expr) => return $(gen_expression(expr))
+                        Either::Left(block) => $(gen_block_expr(block, hints)),
+                        Either::Right(expr) => return $(gen_expression(expr, hints))
                     })
                 })
             }
@@ -145,38 +170,76 @@ fn gen_pattern(pattern: Pattern, body: Either<Block, Expression>) -> js::Tokens
                         Expression::SuffixVar { name, .. } => $(quoted(try_escape_js(name.as_str()))),
                         _ => $(quoted("unreachable"))
                     }),
-                    function() {
+                    $(async_)function() {
                         $(match body {
-                            Either::Left(block) => $(gen_block_expr(block)),
-                            Either::Right(expr) => return $(gen_expression(expr))
+                            Either::Left(block) => $(gen_block_expr(block, hints)),
+                            Either::Right(expr) => return $(gen_expression(expr, hints))
                         })
                     }
                 )
             }
             // Or(pat1, pat2) pattern
             Pattern::Or(pat1, pat2) => {
-                new $("$$")OrPattern($(gen_pattern(*pat1, body.clone())), $(gen_pattern(*pat2, body)))
+                new $("$$")OrPattern($(gen_pattern(*pat1, body.clone(), hints)), $(gen_pattern(*pat2, body, hints)))
+            }
+            // Guard(pat, condition) pattern, the guard is generated
+            // as the same pattern returning condition, to
+            // get access to the pattern bindings
+            Pattern::Guard { pattern, condition, .. } => {
+                new $("$$")GuardPattern(
+                    $(gen_pattern(*pattern.clone(), body, hints)),
+                    $(gen_pattern(*pattern, Either::Right(condition), hints))
+                )
             }
         })
     }
 }
 
 /// Generates range code
-fn gen_range(range: Range) -> js::Tokens {
+fn gen_range(range: Range, hints: &Hints) -> js::Tokens {
     match range {
         Range::ExcludeLast { from, to, .. } => {
-            quote!($("$$range")($(gen_expression(from)), $(gen_expression(to)), 0))
+            quote!($("$$range")($(gen_expression(from, hints)), $(gen_expression(to, hints)), 0))
         }
         Range::IncludeLast { from, to, .. } => {
-            quote!($("$$range")($(gen_expression(from)), $(gen_expression(to)), 1))
+            quote!($("$$range")($(gen_expression(from, hints)), $(gen_expression(to, hints)), 1))
         }
     }
 }
 
+/// Generates float code
+///
+/// Non-finite floats aren't valid js number literals,
+/// so they are generated as `Infinity`, `-Infinity` and `NaN`.
+///
+fn gen_float(value: &str) -> String {
+    match value.parse::<f64>() {
+        Ok(float) if float.is_nan() => "NaN".to_string(),
+        Ok(float) if float.is_infinite() && float > 0.0 => "Infinity".to_string(),
+        Ok(float) if float.is_infinite() => "-Infinity".to_string(),
+        _ => value.to_string(),
+    }
+}
+
 /// Generates expression code
-pub fn gen_expression(expr: Expression) -> js::Tokens {
+///
+/// # Notes
+/// Constant operations over literals are folded
+/// into the single literal before generation.
+///
+pub fn gen_expression(expr: Expression, hints: &Hints) -> js::Tokens {
+    // Folding constant expression
+    if let Some(folded) = fold::fold(&expr) {
+        return gen_expression(folded, hints);
+    }
+    // Immediately invoked functions and matches are awaited,
+    // if they contain `await`
+    let awaits = matches!(
+        expr,
+        Expression::If { .. } | Expression::Match { .. } | Expression::TryCatch { .. }
+    ) && effects::expr_awaits(&expr);
     match expr {
-        Expression::Float { location: _, value } => quote! ( $(value.to_string()) ),
+        Expression::Float { location: _, value } => quote! ( $(gen_float(&value)) ),
         Expression::Int { location: _, value } => quote! ( $(value.to_string()) ),
         Expression::String { location: _, value } => quote! ( $(quoted(value.as_str())) ),
         Expression::Bool { location: _, value } => quote! ( $(value.as_str()) ),
@@ -187,58 +250,121 @@ pub fn gen_expression(expr: Expression) -> js::Tokens {
             op,
         } => match op {
             // With string values
-            BinaryOp::Concat => quote!( $(gen_expression(*left)) + $(gen_expression(*right)) ),
+            BinaryOp::Concat => {
+                quote!( $(gen_expression(*left, hints)) + $(gen_expression(*right, hints)) )
+            }
             // With number values
-            BinaryOp::Add => quote!( $(gen_expression(*left)) + $(gen_expression(*right)) ),
-            BinaryOp::Sub => quote!( $(gen_expression(*left)) - $(gen_expression(*right)) ),
-            BinaryOp::Mul => quote!( $(gen_expression(*left)) * $(gen_expression(*right)) ),
-            BinaryOp::Div => quote!( $(gen_expression(*left)) / $(gen_expression(*right)) ),
-            BinaryOp::Xor => quote!( $(gen_expression(*left)) ^ $(gen_expression(*right)) ),
+            BinaryOp::Add => {
+                quote!( $(gen_expression(*left, hints)) + $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Sub => {
+                quote!( $(gen_expression(*left, hints)) - $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Mul => {
+                quote!( $(gen_expression(*left, hints)) * $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Div => {
+                quote!( $(gen_expression(*left, hints)) / $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::IntDiv => {
+                quote!( Math.floor($(gen_expression(*left, hints)) / $(gen_expression(*right, hints))) )
+            }
+            BinaryOp::Xor => {
+                quote!( $(gen_expression(*left, hints)) ^ $(gen_expression(*right, hints)) )
+            }
             BinaryOp::BitwiseAnd => {
-                quote!( $(gen_expression(*left)) & $(gen_expression(*right)) )
-            }
-            BinaryOp::BitwiseOr => quote!( $(gen_expression(*left)) | $(gen_expression(*right)) ),
-            BinaryOp::Mod => quote!( $(gen_expression(*left)) % $(gen_expression(*right)) ),
-            BinaryOp::Gt => quote!( $(gen_expression(*left)) > $(gen_expression(*right)) ),
-            BinaryOp::Lt => quote!( $(gen_expression(*left)) < $(gen_expression(*right)) ),
-            BinaryOp::Ge => quote!( $(gen_expression(*left)) >= $(gen_expression(*right)) ),
-            BinaryOp::Le => quote!( $(gen_expression(*left)) <= $(gen_expression(*right)) ),
+                quote!( $(gen_expression(*left, hints)) & $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::BitwiseOr => {
+                quote!( $(gen_expression(*left, hints)) | $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Mod => {
+                quote!( $(gen_expression(*left, hints)) % $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Shl => {
+                quote!( $(gen_expression(*left, hints)) << $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Shr => {
+                quote!( $(gen_expression(*left, hints)) >> $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Gt => {
+                quote!( $(gen_expression(*left, hints)) > $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Lt => {
+                quote!( $(gen_expression(*left, hints)) < $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Ge => {
+                quote!( $(gen_expression(*left, hints)) >= $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::Le => {
+                quote!( $(gen_expression(*left, hints)) <= $(gen_expression(*right, hints)) )
+            }
             // With bool
-            BinaryOp::Or => quote!( $(gen_expression(*left)) || $(gen_expression(*right)) ),
-            BinaryOp::And => quote!( $(gen_expression(*left)) && $(gen_expression(*right)) ),
+            BinaryOp::Or => {
+                quote!( $(gen_expression(*left, hints)) || $(gen_expression(*right, hints)) )
+            }
+            BinaryOp::And => {
+                quote!( $(gen_expression(*left, hints)) && $(gen_expression(*right, hints)) )
+            }
             BinaryOp::Eq => {
-                quote!( $("$$equals")($(gen_expression(*left)), $(gen_expression(*right))) )
+                quote!( $("$$equals")($(gen_expression(*left, hints)), $(gen_expression(*right, hints))) )
             }
             BinaryOp::NotEq => {
-                quote!( !$("$$equals")($(gen_expression(*left)), $(gen_expression(*right))) )
+                quote!( !$("$$equals")($(gen_expression(*left, hints)), $(gen_expression(*right, hints))) )
             }
         },
-        Expression::As { value, .. } => gen_expression(*value),
+        Expression::As { value, .. } => gen_expression(*value, hints),
         Expression::Unary { value, op, .. } => match op {
-            UnaryOp::Neg => quote!( -$(gen_expression(*value)) ),
-            UnaryOp::Bang => quote!( !$(gen_expression(*value)) ),
+            UnaryOp::Neg => quote!( -$(gen_expression(*value, hints)) ),
+            UnaryOp::Bang => quote!( !$(gen_expression(*value, hints)) ),
         },
         Expression::PrefixVar { name, .. } => quote!($(try_escape_js(&name))),
         Expression::SuffixVar {
             location: _,
             container,
             name,
-        } => quote!($(gen_expression(*container)).$(try_escape_js(&name))),
+        } => quote!($(gen_expression(*container, hints)).$(try_escape_js(&name))),
         Expression::Call {
-            location: _,
+            location,
             what,
             args,
-        } => quote! {
-            $(gen_expression(*what))($(for arg in args join (, ) => $(gen_expression(arg))))
+        } => {
+            // Overload, selected by the type checker, is called directly
+            let what = match (*what, hints.overloads.get(&location.span)) {
+                (Expression::PrefixVar { name, .. }, Some(idx)) => {
+                    quote!($(gen_overload_name(&name, *idx)))
+                }
+                (
+                    Expression::SuffixVar {
+                        container, name, ..
+                    },
+                    Some(idx),
+                ) => {
+                    quote!($(gen_expression(*container, hints)).$(gen_overload_name(&name, *idx)))
+                }
+                (what, _) => gen_expression(what, hints),
+            };
+            quote! {
+                $what($(for arg in args join (, ) => $(gen_expression(arg, hints))))
+            }
+        }
+        Expression::OptionalChain { base, access, .. } => match access {
+            ChainAccess::Field(name) => {
+                quote!($(gen_expression(*base, hints))?.$(try_escape_js(&name)))
+            }
+            ChainAccess::Call(name, args) => quote! {
+                $(gen_expression(*base, hints))?.$(try_escape_js(&name))($(for arg in args join (, ) => $(gen_expression(arg, hints))))
+            },
         },
         Expression::Function { params, body, .. } => {
             // function ($param, $param, n...)
+            let async_ = gen_async(effects::body_awaits(&body));
             quote! {
-                function ($(for param in params join (, ) => $(try_escape_js(&param.name)))) {
-                    $(match body {
-                        Either::Left(block) => $(gen_block_expr(block)),
-                        Either::Right(expr) => return $(gen_expression(*expr))
-                    })
+                $(async_)function ($(for param in params join (, ) => $(try_escape_js(&param.name)))) {
+                    $(gen_fn_body(match body {
+                        Either::Left(block) => Either::Left(block),
+                        Either::Right(expr) => Either::Right(*expr),
+                    }, hints))
                 }
             }
         }
@@ -248,10 +374,10 @@ pub fn gen_expression(expr: Expression) -> js::Tokens {
             cases,
         } => {
             quote! {
-                $("$$match")($(gen_expression(*value)), [
+                $(gen_await(awaits))$("$$match")($(gen_expression(*value, hints)), [
                     $['\r']
                     $(for case in cases join (,$['\r']) {
-                        $(gen_pattern(case.pattern, case.body))
+                        $(gen_pattern(case.pattern, case.body, hints))
                     })
                     $['\r']
                 ])
@@ -264,20 +390,20 @@ pub fn gen_expression(expr: Expression) -> js::Tokens {
             ..
         } => {
             quote! {
-                (() => {
-                   if ($(gen_expression(*logical))) {
+                $(gen_await(awaits))($(gen_async(awaits))() => {
+                   if ($(gen_expression(*logical, hints))) {
                        $(match body {
-                           Either::Left(block) => $(gen_block_expr(block)),
-                           Either::Right(expr) => return $(gen_expression(*expr))
+                           Either::Left(block) => $(gen_block_expr(block, hints)),
+                           Either::Right(expr) => return $(gen_expression(*expr, hints))
                        })
                    }
                    $(for branch in else_branches {
                        $(match branch {
                            ElseBranch::Elif { logical, body, .. } => {
-                               else if ($(gen_expression(logical))) {
+                               else if ($(gen_expression(*logical, hints))) {
                                    $(match body {
-                                       Either::Left(block) => $(gen_block_expr(block)),
-                                       Either::Right(expr) => return $(gen_expression(expr))
+                                       Either::Left(block) => $(gen_block_expr(block, hints)),
+                                       Either::Right(expr) => return $(gen_expression(expr, hints))
                                    })
                                }
                                $['\r']
@@ -285,8 +411,8 @@ pub fn gen_expression(expr: Expression) -> js::Tokens {
                            ElseBranch::Else { body, .. } => {
                                else {
                                    $(match body {
-                                       Either::Left(block) => $(gen_block_expr(block)),
-                                       Either::Right(expr) => return $(gen_expression(expr))
+                                       Either::Left(block) => $(gen_block_expr(block, hints)),
+                                       Either::Right(expr) => return $(gen_expression(expr, hints))
                                    })
                                }
                                $['\r']
@@ -297,26 +423,67 @@ pub fn gen_expression(expr: Expression) -> js::Tokens {
             }
         }
         Expression::Panic { text, .. } => match text {
-            Some(text) => quote!($("$$")panic($(text.as_str()))),
+            Some(text) => quote!($("$$")panic($(quoted(text.as_str())))),
             None => quote!($("$$")panic()),
         },
         Expression::Todo { text, .. } => match text {
-            Some(text) => quote!($("$$")todo($(text.as_str()))),
+            Some(text) => quote!($("$$")todo($(quoted(text.as_str())))),
             None => quote!($("$$")todo()),
         },
-        Expression::Paren { expr, .. } => quote!(($(gen_expression(*expr)))),
+        Expression::Break { label, .. } => quote!($("$$")break($(quoted(label.as_str())))),
+        Expression::Paren { expr, .. } => quote!(($(gen_expression(*expr, hints)))),
+        Expression::TryCatch {
+            location,
+            body,
+            bind,
+            handler,
+            ..
+        } => {
+            // only values of the caught type, thrown with `throw`,
+            // are caught, anything else is rethrown
+            let mismatch = match hints.catches.get(&location.span) {
+                Some(CatchKind::String) => quote!(typeof $("$e").value !== "string"),
+                Some(CatchKind::Struct { name }) => {
+                    quote!($("$e").value?.$("$type") !== $(quoted(name.as_str())))
+                }
+                None => unreachable!(),
+            };
+            quote! {
+                $(gen_await(awaits))($(gen_async(awaits))() => {
+                    try {
+                        $(gen_block_expr(body, hints))
+                    } catch ($("$e")) {
+                        if (!($("$e") instanceof $("$$")Thrown) || $mismatch) {
+                            throw $("$e");
+                        }
+                        let $(try_escape_js(&bind)) = $("$e").value;
+                        $(gen_block_expr(handler, hints))
+                    }
+                })()
+            }
+        }
+        Expression::Try { location, value } => match hints.tries.get(&location.span) {
+            Some(TryKind::Result { field }) => quote! {
+                $("$$")try($(gen_expression(*value, hints)), $(quoted(try_escape_js(field))))
+            },
+            Some(TryKind::Optional) => {
+                quote!($("$$")try_optional($(gen_expression(*value, hints))))
+            }
+            None => unreachable!(),
+        },
+        Expression::Await { value, .. } => quote!(await $(gen_expression(*value, hints))),
     }
 }
 
 /// Generates statement code
-pub fn gen_statement(stmt: Statement) -> js::Tokens {
+pub fn gen_statement(stmt: Statement, hints: &Hints) -> js::Tokens {
     match stmt {
         // Loop statement
         Statement::Loop { logical, body, .. } => quote! {
-            while ($(gen_expression(logical))) {
+            while ($(gen_expression(logical, hints))) {
                 $(match body {
-                    Either::Left(block) => $(gen_block(block)),
-                    Either::Right(expr) => $(gen_expression(expr));
+                    Either::Left(block) => $(gen_block(block, hints)),
+                    Either::Right(expr) => $(gen_expression(expr, hints));
                 })
             }
         },
@@ -324,52 +491,242 @@ pub fn gen_statement(stmt: Statement) -> js::Tokens {
         Statement::For {
             name, range, body, ..
         } => quote! {
-            for (const $(name.as_str()) of $(gen_range(*range))) {
+            for (const $(name.as_str()) of $(gen_range(*range, hints))) {
                 $(match body {
-                    Either::Left(block) => $(gen_block(block)),
-                    Either::Right(expr) => $(gen_expression(expr));
+                    Either::Left(block) => $(gen_block(block, hints)),
+                    Either::Right(expr) => $(gen_expression(expr, hints));
                 })
             }
         },
         // Variable definition statement
         Statement::VarDef { name, value, .. } => quote! {
-            let $(try_escape_js(&name)) = $(gen_expression(value))
+            let $(try_escape_js(&name)) = $(gen_expression(value, hints))
         },
         // Variable assignment statement
         Statement::VarAssign { what, value, .. } => quote! {
-            $(gen_expression(what)) = $(gen_expression(value))
+            $(gen_expression(what, hints)) = $(gen_expression(value, hints))
+        },
+        // Parallel assignment statement, values are
+        // saved into temporaries before the assignment
+        Statement::ParallelAssign { what, value, .. } => quote! {
+            {
+                $(for (i, value) in value.into_iter().enumerate() join ($['\r']) =>
+                    const $(format!("$${i}")) = $(gen_expression(value, hints)))
+                $['\r']
+                $(for (i, what) in what.into_iter().enumerate() join ($['\r']) =>
+                    $(gen_expression(what, hints)) = $(format!("$${i}")))
+            }
         },
         // Expression statement
-        Statement::Expr(expr) => quote!($(gen_expression(expr))),
+        Statement::Expr(expr) => quote!($(gen_expression(expr, hints))),
         // Semicolon expression statement
-        Statement::Semi(expr) => quote!($(gen_expression(expr));),
+        Statement::Semi(expr) => quote!($(gen_expression(expr, hints));),
+        // Throw statement, value is wrapped with `$$Thrown`,
+        // to be distinguished by the `catch` from the other errors
+        Statement::Throw { value, .. } => {
+            quote!(throw new $("$$")Thrown($(gen_expression(value, hints))))
+        }
+        // Labeled block statement, `break` throws
+        // `$$Break`, that is caught by its block
+        Statement::Labeled { label, body, .. } => quote! {
+            try {
+                $(gen_block(body, hints))
+            } catch (e) {
+                if (!(e instanceof $("$$")Break) || e.label !== $(quoted(label.as_str()))) {
+                    throw e;
+                }
+            }
+        },
+    }
+}
+
+/// Generates function body code
+///
+/// # Notes
+/// If body contains `?` propagation, it's wrapped with
+/// `try`, so propagated value is returned from the function.
+///
+fn gen_fn_body(body: Either<Block, Expression>, hints: &Hints) -> js::Tokens {
+    let propagates = effects::body_propagates(&body);
+    let body = match body {
+        Either::Left(block) => gen_block_expr(block, hints),
+        Either::Right(expr) => quote!(return $(gen_expression(expr, hints))),
+    };
+    if propagates {
+        quote! {
+            try {
+                $body
+            } catch (e) {
+                if (e instanceof $("$$")Propagate) {
+                    return e.value;
+                }
+                throw e;
+            }
+        }
+    } else {
+        body
+    }
+}
+
+/// Generates `export` for the public declaration code,
+/// private declarations are kept module-local
+fn gen_exported(publicity: &Publicity, tokens: js::Tokens) -> js::Tokens {
+    match publicity {
+        Publicity::Public => quote!(export $tokens),
+        Publicity::Private => tokens,
+    }
+}
+
+/// Generates `@deprecated` JSDoc tag, if declaration is deprecated
+fn gen_deprecated_tag(attributes: &[Attribute]) -> Option<String> {
+    Attribute::deprecation(attributes).map(|message| match message {
+        Some(message) => format!("@deprecated {}", message.replace("*/", "*\\/")),
+        None => "@deprecated".to_string(),
+    })
+}
+
+/// Generates single line JSDoc comment with `@deprecated` tag
+/// of the type or constant declaration, if it's deprecated
+fn gen_deprecated_jsdoc(attributes: &[Attribute]) -> js::Tokens {
+    match gen_deprecated_tag(attributes) {
+        Some(tag) => quote!($(format!("/** {tag} */"))$['\r']),
+        None => quote!(),
     }
 }
 
+/// Generates JSDoc comment of the public function
+///
+/// Comment contains function doc comment, deprecation, generics,
+/// parameters and return type. Types are taken from the `signature`,
+/// inferred by the type checker, so unannotated return types and
+/// type aliases are rendered as the actual types.
+/// Private functions aren't visible outside of the module,
+/// so nothing is generated for them.
+///
+fn gen_jsdoc(
+    publicity: &Publicity,
+    doc: &Option<EcoString>,
+    attributes: &[Attribute],
+    generics: &[EcoString],
+    params: &[Parameter],
+    signature: Option<&Signature>,
+) -> js::Tokens {
+    if let Publicity::Private = publicity {
+        return quote!();
+    }
+    // Doc comment lines
+    let mut lines: Vec<String> = match doc {
+        Some(doc) => gen_doc_lines(doc),
+        None => Vec::new(),
+    };
+    // Tags
+    lines.extend(gen_deprecated_tag(attributes).map(|tag| format!(" * {tag}")));
+    lines.extend(
+        generics
+            .iter()
+            .map(|generic| format!(" * @template {generic}")),
+    );
+    lines.extend(params.iter().enumerate().map(|(idx, param)| {
+        format!(
+            " * @param {{{}}} {}",
+            signature
+                .and_then(|signature| signature.params.get(idx))
+                .map_or("*", |typ| typ.as_str()),
+            try_escape_js(&param.name)
+        )
+    }));
+    lines.extend(signature.map(|signature| format!(" * @returns {{{}}}", signature.ret)));
+    quote! {
+        $("/**")
+        $(for line in lines join ($['\r']) => $line)
+        $(" */")$['\r']
+    }
+}
+
+/// Splits doc comment into JSDoc comment lines,
+/// `*/` would close the comment, so it's escaped
+fn gen_doc_lines(doc: &EcoString) -> Vec<String> {
+    doc.replace("*/", "*\\/")
+        .lines()
+        .map(|line| format!(" * {line}").trim_end().to_string())
+        .collect()
+}
+
 /// Generates function declaration code
-pub fn gen_fn_declaration(decl: FnDeclaration) -> js::Tokens {
+///
+/// # Notes
+/// `main` function is always exported,
+/// because it's imported by `index.js`.
+/// Public functions are documented with JSDoc.
+/// Functions, that contain `await` are generated as `async`.
+///
+pub fn gen_fn_declaration(decl: FnDeclaration, hints: &Hints) -> js::Tokens {
     match decl {
         FnDeclaration::Function {
-            name, params, body, ..
+            location,
+            name,
+            publicity,
+            generics,
+            params,
+            body,
+            doc,
+            attributes,
+            ..
+        }
+        | FnDeclaration::ConstFunction {
+            location,
+            name,
+            publicity,
+            generics,
+            params,
+            body,
+            doc,
+            attributes,
+            ..
         } => {
+            // JSDoc
+            let signature = hints.signatures.get(&location.span);
+            let jsdoc = gen_jsdoc(&publicity, &doc, &attributes, &generics, &params, signature);
             // function $name($param, $param, n...)
-            quote! {
-                export function $(try_escape_js(&name))($(for param in params join (, ) => $(try_escape_js(&param.name)))) {
-                    $(match body {
-                        Either::Left(block) => $(gen_block_expr(block)),
-                        Either::Right(expr) => return $(gen_expression(expr))
-                    })
-                }
-            }
+            let publicity = if name == "main" {
+                Publicity::Public
+            } else {
+                publicity
+            };
+            let async_ = gen_async(effects::body_awaits(&body));
+            let function = gen_exported(
+                &publicity,
+                quote! {
+                    $(async_)function $(try_escape_js(&name))($(for param in params join (, ) => $(try_escape_js(&param.name)))) {
+                        $(gen_fn_body(body, hints))
+                    }
+                },
+            );
+            quote!($jsdoc$function)
         }
         FnDeclaration::ExternFunction {
-            name, params, body, ..
+            location,
+            name,
+            publicity,
+            generics,
+            params,
+            body,
+            doc,
+            attributes,
+            ..
         } => {
-            quote! {
-                export function $(try_escape_js(&name))($(for param in params join (, ) => $(try_escape_js(&param.name)))) {
-                    $(body.to_string())
-                }
-            }
+            // JSDoc
+            let signature = hints.signatures.get(&location.span);
+            let jsdoc = gen_jsdoc(&publicity, &doc, &attributes, &generics, &params, signature);
+            let function = gen_exported(
+                &publicity,
+                quote! {
+                    function $(try_escape_js(&name))($(for param in params join (, ) => $(try_escape_js(&param.name)))) {
+                        $(body.to_string())
+                    }
+                },
+            );
+            quote!($jsdoc$function)
         }
     }
 }
@@ -377,7 +734,13 @@ pub fn gen_fn_declaration(decl: FnDeclaration) -> js::Tokens {
 /// Generates type declaration code
 pub fn gen_type_declaration(decl: TypeDeclaration) -> js::Tokens {
     match decl {
-        TypeDeclaration::Struct { name, fields, .. } => {
+        TypeDeclaration::Struct {
+            name,
+            publicity,
+            fields,
+            attributes,
+            ..
+        } => {
             // constructor($field, $field, n...)
             // with meta type field as `type_name`
             let generated_constructor = quote! {
@@ -394,15 +757,25 @@ pub fn gen_type_declaration(decl: TypeDeclaration) -> js::Tokens {
             // Class of `Type` named as $type_name
             // and class fabric named as `type_name`
             quote! {
-                export class $("$")$(try_escape_js(&name)) {
-                    $generated_constructor
-                }
-                export function $(try_escape_js(&name))($(for field in &fields join (, ) => $(try_escape_js(&field.name)))) {
-                    return new $("$")$(try_escape_js(&name))($(for field in &fields join (, ) => $(try_escape_js(&field.name))));
-                }
+                $(gen_exported(&publicity, quote! {
+                    class $("$")$(try_escape_js(&name)) {
+                        $generated_constructor
+                    }
+                }))
+                $(gen_deprecated_jsdoc(&attributes))$(gen_exported(&publicity, quote! {
+                    function $(try_escape_js(&name))($(for field in &fields join (, ) => $(try_escape_js(&field.name)))) {
+                        return new $("$")$(try_escape_js(&name))($(for field in &fields join (, ) => $(try_escape_js(&field.name))));
+                    }
+                }))
             }
         }
-        TypeDeclaration::Enum { name, variants, .. } => {
+        TypeDeclaration::Enum {
+            name,
+            publicity,
+            variants,
+            attributes,
+            ..
+        } => {
             // ($variant_name): ($param, $param, n...): ({
             //    $meta: "Enum"
             //    $enum: $name
@@ -420,56 +793,97 @@ pub fn gen_type_declaration(decl: TypeDeclaration) -> js::Tokens {
             ));
 
             // constr $name = {}
-            quote! {
-                export const $(try_escape_js(&name)) = {
-                    $variants
-                };
-            }
+            let enum_ = gen_exported(
+                &publicity,
+                quote! {
+                    const $(try_escape_js(&name)) = {
+                        $variants
+                    };
+                },
+            );
+            quote!($(gen_deprecated_jsdoc(&attributes))$enum_)
         }
+        // Type aliases are erased during codegen
+        TypeDeclaration::Alias { .. } => quote!(),
     }
 }
 
 /// Generates const declaration code
-pub fn gen_const_declaration(decl: ConstDeclaration) -> js::Tokens {
-    quote! {
-        export const $(try_escape_js(&decl.name)) = $(gen_expression(decl.value));
-    }
+pub fn gen_const_declaration(decl: ConstDeclaration, hints: &Hints) -> js::Tokens {
+    let jsdoc = gen_deprecated_jsdoc(&decl.attributes);
+    let const_ = gen_exported(
+        &decl.publicity,
+        quote! {
+            const $(try_escape_js(&decl.name)) = $(gen_expression(decl.value, hints));
+        },
+    );
+    quote!($jsdoc$const_)
 }
 
 /// Generates declaration code
-pub fn gen_declaration(decl: Declaration) -> js::Tokens {
+pub fn gen_declaration(decl: Declaration, hints: &Hints) -> js::Tokens {
     match decl {
-        Declaration::Fn(decl) => gen_fn_declaration(decl),
-        Declaration::Const(decl) => gen_const_declaration(decl),
+        Declaration::Fn(decl) => gen_fn_declaration(decl, hints),
+        Declaration::Const(decl) => gen_const_declaration(decl, hints),
         Declaration::Type(decl) => gen_type_declaration(decl),
     }
 }
 
-/// Generates block code
-pub fn gen_block(block: Block) -> js::Tokens {
+/// Generates block code, unreachable statements are omitted
+pub fn gen_block(mut block: Block, hints: &Hints) -> js::Tokens {
+    dce::truncate_unreachable(&mut block.body);
     quote! {
-        $(for stmt in block.body join ($['\r']) => $(gen_statement(stmt)))
+        $(for stmt in block.body join ($['\r']) => $(gen_block_statement(stmt, hints)))
     }
 }
 
-/// Generates block code with last statement as return
-pub fn gen_block_expr(mut block: Block) -> js::Tokens {
+/// Generates block code with last statement as return,
+/// unreachable statements are omitted
+pub fn gen_block_expr(mut block: Block, hints: &Hints) -> js::Tokens {
+    dce::truncate_unreachable(&mut block.body);
     let last = match block.body.pop() {
         Some(last) => last,
         None => return quote!(),
     };
     quote! {
-        $(for stmt in block.body join ($['\r']) => $(gen_statement(stmt)))
+        $(for stmt in block.body join ($['\r']) => $(gen_block_statement(stmt, hints)))
         $(match last {
-            Statement::Expr(last) => return $(gen_expression(last)),
-            it => $(gen_statement(it))
+            Statement::Expr(last) => return $(gen_expression(last, hints)),
+            it => $(gen_block_statement(it, hints))
         })
     }
 }
 
+/// Generates statement code of the block,
+/// variables, that are never reassigned, are defined with `const`
+fn gen_block_statement(stmt: Statement, hints: &Hints) -> js::Tokens {
+    match stmt {
+        Statement::VarDef {
+            location,
+            name,
+            value,
+            ..
+        } if !hints.reassigned.contains(&location.span) => quote! {
+            const $(try_escape_js(&name)) = $(gen_expression(value, hints))
+        },
+        it => gen_statement(it, hints),
+    }
+}
+
 /// Generates module code
-#[instrument(skip(module))]
-pub fn gen_module(name: &EcoString, module: &Module) -> js::Tokens {
+///
+/// If `reachable` set is provided, private functions,
+/// that are unreachable from the module exports are omitted.
+/// Type-directed code is generated by the `hints`, recorded
+/// by the type checker.
+///
+#[instrument(skip(module, hints, reachable))]
+pub fn gen_module(
+    name: &EcoString,
+    module: &Module,
+    hints: &Hints,
+    reachable: Option<&ReachabilitySet>,
+) -> js::Tokens {
     // Segments amount for dependencies
     let name_segments_amount = name.split("/").count();
     // Dependencies prefix
@@ -477,35 +891,137 @@ pub fn gen_module(name: &EcoString, module: &Module) -> js::Tokens {
         1 => String::from("./"),
         _ => "../".repeat(name_segments_amount - 1),
     };
+    // Live declarations
+    let live: Vec<&Declaration> = module
+        .declarations
+        .iter()
+        .filter(|decl| reachable.is_none_or(|set| set.is_live(decl)))
+        .collect();
+    // Overloaded extern functions are renamed by
+    // their indices in the declaration order
+    let overloads = overloaded_externs(&module.declarations);
+    // Folded values of the constants
+    let consts = ConstValues::new(&module.declarations);
+    // Declarations, type aliases are erased
+    let declarations: Vec<js::Tokens> = live
+        .iter()
+        .filter(|decl| !matches!(decl, Declaration::Type(TypeDeclaration::Alias { .. })))
+        .map(|decl| match decl {
+            Declaration::Const(decl) => {
+                gen_const_declaration(consts.fold_const(decl.clone()), hints)
+            }
+            Declaration::Fn(decl @ FnDeclaration::ExternFunction { location, name, .. })
+                if overloads.contains_key(&location.span) =>
+            {
+                let mut decl = decl.clone();
+                if let FnDeclaration::ExternFunction { name: renamed, .. } = &mut decl {
+                    *renamed = gen_overload_name(name, overloads[&location.span]).into();
+                }
+                gen_fn_declaration(decl, hints)
+            }
+            _ => gen_declaration((*decl).clone(), hints),
+        })
+        .collect();
+    // Prelude symbols, used by the module
+    let prelude = imports::prelude_imports(live, hints);
+    // Names, declared by the module, imports are renamed to avoid collisions
+    let declared = imports::declared_names(&module.declarations);
+    // Dependencies, names, that are erased from
+    // the generated code, aren't imported
+    let dependencies: Vec<Dependency> = module
+        .dependencies
+        .iter()
+        .cloned()
+        .filter_map(|mut dep| {
+            if let UseKind::ForNames(names) = &mut dep.kind {
+                names.retain(|name| !hints.erased_imports.contains(name));
+                if names.is_empty() {
+                    return None;
+                }
+            }
+            Some(dep)
+        })
+        .collect();
+    // Module doc comment
+    let module_doc = module.doc.as_ref().map(|doc| gen_module_doc(name, doc));
     // Gen
     quote! {
-        // Prelude
-        import {
-            $("$$match"),
-            $("$$equals"),
-            $("$$todo"),
-            $("$$range"),
-            $("$$EqPattern"),
-            $("$$UnwrapPattern"),
-            $("$$WildcardPattern"),
-            $("$$BindPattern"),
-            $("$$VariantPattern"),
-        } from $(quoted(format!("{dependencies_prefix}prelude.js")))
+        // Module doc comment, then prelude, only used symbols are imported
+        $(if let Some(doc) = module_doc { $doc$['\n'] })$(if !prelude.is_empty() {
+            import {
+                $(for symbol in prelude => $symbol,$['\r'])
+            } from $(quoted(format!("{dependencies_prefix}prelude.js")))
+        })
         // Dependencies
         //
         // for `AsName`: import * as $name from "$module"
         // for `ForNames`: import {$name, $name, ...} from "$module"
-        $(for dep in module.dependencies.clone() join ($['\r']) => $(match dep.kind {
+        $(for dep in dependencies join ($['\r']) => $(match dep.kind {
             UseKind::AsName(name) => {
                 import * as $(name.to_string()) from $(quoted(format!("{dependencies_prefix}{}.js", dep.path.module.as_str())))
             },
             UseKind::ForNames(names) => {
-                import {$(for name in names join(, ) => $(name.to_string()))} from $(quoted(format!("{dependencies_prefix}{}.js", dep.path.module.as_str())))
+                $(if names.iter().any(|name| declared.contains(name)) {
+                    $("// WARNING: import shadows local declaration")$['\r']
+                })
+                import {$(for name in names join(, ) => $(gen_imported_name(&name, &declared, hints)))} from $(quoted(format!("{dependencies_prefix}{}.js", dep.path.module.as_str())))
             },
         }))
         $['\n']
         // Declarations
-        $(for decl in module.declarations.clone() join ($['\n']) => $(gen_declaration(decl)))
+        $(for decl in declarations join ($['\n']) => $decl)
+    }
+}
+
+/// Generates name of the overloaded extern function
+/// overload, which is `name$idx`
+fn gen_overload_name(name: &EcoString, idx: usize) -> String {
+    format!("{}${idx}", try_escape_js(name))
+}
+
+/// Collects indices of the overloaded extern functions in the
+/// declaration order of their names, keyed by the declaration span
+fn overloaded_externs(declarations: &[Declaration]) -> HashMap<std::ops::Range<usize>, usize> {
+    let mut indices: HashMap<&EcoString, Vec<std::ops::Range<usize>>> = HashMap::new();
+    for decl in declarations {
+        if let Declaration::Fn(FnDeclaration::ExternFunction { location, name, .. }) = decl {
+            indices.entry(name).or_default().push(location.span.clone());
+        }
+    }
+    indices
+        .into_values()
+        .filter(|spans| spans.len() > 1)
+        .flat_map(|spans| spans.into_iter().enumerate().map(|(idx, span)| (span, idx)))
+        .collect()
+}
+
+/// Generates JSDoc comment of the module
+/// from the module doc comment
+fn gen_module_doc(name: &EcoString, doc: &EcoString) -> js::Tokens {
+    let mut lines = gen_doc_lines(doc);
+    lines.push(format!(" * @module {name}"));
+    quote! {
+        $("/**")
+        $(for line in lines join ($['\r']) => $line)
+        $(" */")$['\r']
+    }
+}
+
+/// Generates name imported with `ForNames`,
+/// name, that collides with the local declaration is renamed,
+/// since local declarations shadow imported names.
+/// Overloaded extern function is imported by its public overloads.
+fn gen_imported_name(name: &EcoString, declared: &HashSet<EcoString>, hints: &Hints) -> String {
+    if let Some(overloads) = hints.overloaded_imports.get(name) {
+        overloads
+            .iter()
+            .map(|idx| gen_overload_name(name, *idx))
+            .collect::<Vec<String>>()
+            .join(", ")
+    } else if declared.contains(name) {
+        format!("{name} as {name}$import")
+    } else {
+        name.to_string()
     }
 }
 
@@ -514,7 +1030,7 @@ pub fn gen_prelude() -> js::Tokens {
     quote! {
         // EnumEquals$fn
         function $("$$enum_equals")(a, b) {
-            // Gettting keys
+            // Getting keys
             let a_keys = Object.keys(a);
             let b_keys = Object.keys(b);
             // Checking length
@@ -538,8 +1054,42 @@ pub fn gen_prelude() -> js::Tokens {
             return true;
         }
 
+        // StructEquals$fn
+        function $("$$struct_equals")(a, b) {
+            // Checking type
+            if (a.$("$type") != b.$("$type")) {
+                return false;
+            }
+            // Getting keys
+            let a_keys = Object.keys(a);
+            let b_keys = Object.keys(b);
+            // Checking length
+            if (a_keys.length != b_keys.length) {
+                return false;
+            }
+            // Checking fields
+            for (const k1 of a_keys) {
+                // If b keys includes a key
+                if (b_keys.includes(k1)) {
+                    // Comparing fields recursively
+                    if ($("$$")equals(a[k1], b[k1]) == false) {
+                        return false;
+                    }
+                }
+                // Otherwise
+                else {
+                    return false;
+                }
+            };
+            return true;
+        }
+
         // Equals$Fn
         export function $("$$equals")(a, b) {
+            // If any is `null` of `Optional`
+            if (a === null || a === undefined || b === null || b === undefined) {
+                return a === b;
+            }
             // If both not objects
             if (typeof(a) !== "object" || typeof(b) !== "object") {
                 return a == b;
@@ -563,6 +1113,11 @@ pub fn gen_prelude() -> js::Tokens {
                                 // Comparing enums
                                 return $("$$")enum_equals(a, b);
                             }
+                            // If meta is $Type
+                            if (meta == "Type") {
+                                // Comparing structs
+                                return $("$$")struct_equals(a, b);
+                            }
                             return a === b;
                         }
                     }
@@ -670,6 +1225,23 @@ pub fn gen_prelude() -> js::Tokens {
             }
         }
 
+        // GuardPattern$Class
+        export class $("$$GuardPattern") {
+            constructor(pattern, guard) {
+                this.pattern = pattern;
+                this.guard = guard;
+            }
+            evaluate(value) {
+                // Checking pattern is matched and condition is true
+                let guarded = this.guard.evaluate(value);
+                if (guarded[0] == true && guarded[1] == true) {
+                    return this.pattern.evaluate(value);
+                } else {
+                    return [false, null];
+                }
+            }
+        }
+
         // Match$Fn
         export function $("$$match")(value, patterns) {
             for (const pat of patterns) {
@@ -699,6 +1271,52 @@ pub fn gen_prelude() -> js::Tokens {
             }
         }
 
+        // Propagate$Class
+        export class $("$$Propagate") {
+            constructor(value) {
+                this.value = value;
+            }
+        }
+
+        // Try$Fn
+        export function $("$$try")(value, field) {
+            // If variant is `Ok`, unwrapping its field
+            if (value.$("$variant") == "Ok") {
+                return value[field];
+            }
+            // Otherwise, propagating value
+            throw new $("$$Propagate")(value);
+        }
+
+        // TryOptional$Fn
+        export function $("$$try_optional")(value) {
+            // If value is `null`, propagating it
+            if (value === null || value === undefined) {
+                throw new $("$$Propagate")(null);
+            }
+            // Otherwise, value is present
+            return value;
+        }
+
+        // Thrown$Class
+        export class $("$$Thrown") {
+            constructor(value) {
+                this.value = value;
+            }
+        }
+
+        // Break$Class
+        export class $("$$Break") {
+            constructor(label) {
+                this.label = label;
+            }
+        }
+
+        // Break$Fn
+        export function $("$$break")(label) {
+            throw new $("$$Break")(label);
+        }
+
         // Range$Fn
         export function $("$$range")(from, to, offset) {
             const result = [];
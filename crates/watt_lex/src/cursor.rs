@@ -2,13 +2,36 @@
 pub(crate) struct Cursor<'cursor> {
     pub(crate) code: &'cursor [char],
     pub(crate) current: usize,
+    /// Utf-8 byte offsets of the chars,
+    /// last offset is the code length in bytes
+    offsets: Vec<usize>,
 }
 
 /// Cursor implementation
 impl<'cursor> Cursor<'cursor> {
     /// New cursor
     pub fn new(code: &'cursor [char]) -> Self {
-        Cursor { code, current: 0 }
+        // Computing byte offsets
+        let mut offsets = Vec::with_capacity(code.len() + 1);
+        let mut offset = 0;
+        offsets.push(offset);
+        for ch in code {
+            offset += ch.len_utf8();
+            offsets.push(offset);
+        }
+        Cursor {
+            code,
+            current: 0,
+            offsets,
+        }
+    }
+
+    /// Converts char index to the utf-8 byte offset
+    pub fn byte_offset(&self, index: usize) -> usize {
+        match self.offsets.get(index) {
+            Some(offset) => *offset,
+            None => *self.offsets.last().unwrap(),
+        }
     }
 
     /// Skips `n` chars
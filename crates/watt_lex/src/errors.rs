@@ -24,6 +24,25 @@ pub enum LexError<'a> {
         #[label("no ending quote specified.")]
         span: SourceSpan,
     },
+    #[error("unclosed block comment.")]
+    #[diagnostic(code(lex::unclosed_block_comment))]
+    UnclosedBlockComment {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this comment is never closed.")]
+        span: SourceSpan,
+    },
+    #[error("invalid heredoc label.")]
+    #[diagnostic(
+        code(lex::invalid_heredoc_label),
+        help("heredoc label should be an identifier, followed by a new line.")
+    )]
+    InvalidHeredocLabel {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this label isn't valid.")]
+        span: SourceSpan,
+    },
     #[error("number `{number}` isn't valid.")]
     #[diagnostic(code(lex::invalid_number))]
     InvalidNumber {
@@ -5,6 +5,7 @@ use crate::tokens::*;
 use ecow::EcoString;
 use miette::NamedSource;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::sync::Arc;
 use watt_common::address::Address;
 use watt_common::{bail, skip};
@@ -51,6 +52,11 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
             ("panic", TokenKind::Panic),
             ("todo", TokenKind::Todo),
             ("const", TokenKind::Const),
+            ("throw", TokenKind::Throw),
+            ("try", TokenKind::Try),
+            ("catch", TokenKind::Catch),
+            ("await", TokenKind::Await),
+            ("break", TokenKind::Break),
         ]);
         // Lexer
         Lexer {
@@ -118,12 +124,31 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                         self.add_tk(TokenKind::Star, "*");
                     }
                 }
-                '%' => self.add_tk(TokenKind::Percent, "%"),
+                '%' => {
+                    if self.is_match('=') {
+                        self.add_tk(TokenKind::ModAssign, "%=");
+                    } else {
+                        self.add_tk(TokenKind::Percent, "%");
+                    }
+                }
                 '/' => {
                     // compound operator
                     if self.is_match('=') {
                         self.add_tk(TokenKind::DivAssign, "/=");
                     }
+                    // doc comment
+                    else if self.cursor.peek() == '/'
+                        && self.cursor.next() == '/'
+                        && self.cursor.char_at(2) != '/'
+                    {
+                        let tk = self.scan_doc_comment(TokenKind::DocComment);
+                        self.tokens.push(tk);
+                    }
+                    // module doc comment
+                    else if self.cursor.peek() == '/' && self.cursor.next() == '!' {
+                        let tk = self.scan_doc_comment(TokenKind::ModuleDoc);
+                        self.tokens.push(tk);
+                    }
                     // line comment
                     else if self.is_match('/') {
                         while !self.is_match('\n') && !self.cursor.is_at_end() {
@@ -132,23 +157,22 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                     }
                     // multi-line comment
                     else if self.is_match('*') {
-                        while !(self.cursor.peek() == '*'
-                            && self.cursor.next() == '/'
-                            && self.cursor.is_at_end())
-                        {
-                            if self.is_match('\n') {
-                                continue;
-                            }
-                            self.advance();
-                        }
-                        // *
-                        self.advance();
-                        // /
-                        self.advance();
+                        self.skip_block_comment();
                     } else {
                         self.add_tk(TokenKind::Slash, "/");
                     }
                 }
+                '~' => {
+                    if self.is_match('/') {
+                        self.add_tk(TokenKind::TildeSlash, "~/")
+                    } else {
+                        bail!(LexError::UnexpectedCharacter {
+                            src: self.source.clone(),
+                            span: (self.cursor.current - 1).into(),
+                            ch
+                        })
+                    }
+                }
                 '(' => self.add_tk(TokenKind::Lparen, "("),
                 ')' => self.add_tk(TokenKind::Rparen, ")"),
                 '{' => self.add_tk(TokenKind::Lbrace, "{"),
@@ -156,6 +180,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                 '[' => self.add_tk(TokenKind::Lbracket, "["),
                 ']' => self.add_tk(TokenKind::Rbracket, "]"),
                 ',' => self.add_tk(TokenKind::Comma, ","),
+                '#' => self.add_tk(TokenKind::Hash, "#"),
                 '.' => {
                     if self.is_match('.') {
                         self.add_tk(TokenKind::Range, "..")
@@ -163,6 +188,13 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                         self.add_tk(TokenKind::Dot, ".");
                     }
                 }
+                '?' => {
+                    if self.is_match('.') {
+                        self.add_tk(TokenKind::QuestionDot, "?.")
+                    } else {
+                        self.add_tk(TokenKind::Question, "?")
+                    }
+                }
                 ':' => self.add_tk(TokenKind::Colon, ":"),
                 ';' => self.add_tk(TokenKind::Semicolon, ";"),
                 '<' => {
@@ -170,6 +202,14 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                         self.add_tk(TokenKind::LessEq, "<=");
                     } else if self.is_match('>') {
                         self.add_tk(TokenKind::Concat, "<>");
+                    } else if self.is_match('<') {
+                        // heredoc string
+                        if self.is_match('~') {
+                            let tk = self.scan_heredoc();
+                            self.tokens.push(tk)
+                        } else {
+                            self.add_tk(TokenKind::Shl, "<<");
+                        }
                     } else {
                         self.add_tk(TokenKind::Less, "<");
                     }
@@ -177,6 +217,8 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                 '>' => {
                     if self.is_match('=') {
                         self.add_tk(TokenKind::GreaterEq, ">=");
+                    } else if self.is_match('>') {
+                        self.add_tk(TokenKind::Shr, ">>");
                     } else {
                         self.add_tk(TokenKind::Greater, ">");
                     }
@@ -197,14 +239,33 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                 }
                 '\r' | '\t' | '\0' | ' ' | '\n' => skip!(),
                 '\"' => {
-                    let tk = self.scan_string();
-                    self.tokens.push(tk)
+                    // triple-quoted string
+                    if self.cursor.peek() == '\"' && self.cursor.next() == '\"' {
+                        self.cursor.bump();
+                        self.cursor.bump();
+                        let tk = self.scan_triple_quoted_string();
+                        self.tokens.push(tk)
+                    }
+                    // regular string
+                    else {
+                        let tk = self.scan_string();
+                        self.tokens.push(tk)
+                    }
                 }
                 '`' => {
                     let tk = self.scan_multiline_string();
                     self.tokens.push(tk);
                 }
+                // `_name` is an identifier, single `_` is a wildcard
+                '_' if self.is_id(self.cursor.peek()) => {
+                    let token = self.scan_id_or_keyword(ch);
+                    self.tokens.push(token);
+                }
                 '_' => self.add_tk(TokenKind::Wildcard, "_"),
+                '\'' => {
+                    let tk = self.scan_label();
+                    self.tokens.push(tk);
+                }
                 _ => {
                     // numbers
                     if self.is_digit(ch) {
@@ -272,7 +333,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                 cause: "expected unicode codepoint end `}`."
             })
         }
-        let result = match char::from_u32(u32::from_str_radix(&buffer, 16).expect("Invalid hex")) {
+        match char::from_u32(u32::from_str_radix(&buffer, 16).expect("Invalid hex")) {
             Some(c) => c,
             None => {
                 bail!(LexError::InvalidEscapeSequence {
@@ -281,8 +342,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                     cause: "failed to convert `unciode char` into `u32`."
                 })
             }
-        };
-        result
+        }
     }
 
     /// Scans byte codepoint.
@@ -316,7 +376,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                 cause: "expected byte codepoint end `}`."
             })
         }
-        let result = match char::from_u32(u32::from_str_radix(&buffer, 16).expect("Invalid hex")) {
+        match char::from_u32(u32::from_str_radix(&buffer, 16).expect("Invalid hex")) {
             Some(c) => c,
             None => {
                 bail!(LexError::InvalidEscapeSequence {
@@ -325,8 +385,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
                     cause: "failed to convert `unciode char` into `u32`."
                 })
             }
-        };
-        result
+        }
     }
 
     /// Scans escape sequence.
@@ -337,6 +396,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         match ch {
             'n' => '\n',
             'r' => '\r',
+            't' => '\t',
             '"' => '"',
             '`' => '`',
             '\\' => '\\',
@@ -350,6 +410,32 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         }
     }
 
+    /// Skips multi-line comment. Implies `/*` is already ate. Eats ending `*/`.
+    ///
+    /// Comments could be nested, so `/* a /* b */ c */` is a single comment.
+    ///
+    fn skip_block_comment(&mut self) {
+        let start_location = self.cursor.current - 2;
+        let mut depth = 1;
+
+        while depth > 0 {
+            if self.cursor.is_at_end() {
+                bail!(LexError::UnclosedBlockComment {
+                    src: self.source.clone(),
+                    span: (start_location..start_location + 2).into(),
+                })
+            }
+            if self.cursor.peek() == '/' && self.cursor.next() == '*' {
+                depth += 1;
+                self.advance();
+            } else if self.cursor.peek() == '*' && self.cursor.next() == '/' {
+                depth -= 1;
+                self.advance();
+            }
+            self.advance();
+        }
+    }
+
     /// Scans string. Implies quote is already ate. Eats ending quote.
     fn scan_string(&mut self) -> Token {
         let start_location = self.cursor.current;
@@ -379,7 +465,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         Token {
             tk_type: TokenKind::Text,
             value: text,
-            address: Address::span(self.source.clone(), start_location..end_location),
+            address: self.address(start_location..end_location),
         }
     }
 
@@ -411,7 +497,241 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         Token {
             tk_type: TokenKind::Text,
             value: text,
-            address: Address::span(self.source.clone(), start_location..end_location),
+            address: self.address(start_location..end_location),
+        }
+    }
+
+    /// Calculates common leading indent of the triple-quoted string lines,
+    /// whitespace-only lines are ignored. Doesn't move the cursor.
+    fn scan_triple_quoted_indent(&self) -> usize {
+        let code = &self.cursor.code[self.cursor.current..];
+        let mut indent: Option<usize> = None;
+        let mut column = 0;
+        let mut blank = true;
+        let mut i = 0;
+
+        while i < code.len() {
+            match code[i] {
+                // closing quotes
+                '"' if code[i..].starts_with(&['"', '"', '"']) => break,
+                // new line
+                '\n' => {
+                    column = 0;
+                    blank = true;
+                }
+                // indentation
+                ' ' | '\t' | '\r' if blank => column += 1,
+                // line content
+                ch => {
+                    if blank {
+                        indent = Some(indent.map_or(column, |it| it.min(column)));
+                        blank = false;
+                    }
+                    // skipping escaped character
+                    if ch == '\\' {
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        indent.unwrap_or(0)
+    }
+
+    /// Scans triple-quoted string. Implies quotes are already ate. Eats ending quotes.
+    ///
+    /// Newline right after the opening quotes is skipped,
+    /// and common leading indent is stripped from every line.
+    fn scan_triple_quoted_string(&mut self) -> Token {
+        let start_location = self.cursor.current;
+        let mut text: EcoString = EcoString::new();
+
+        // Skipping newline after opening quotes
+        if self.cursor.peek() == '\r' && self.cursor.next() == '\n' {
+            self.cursor.bump();
+        }
+        self.is_match('\n');
+
+        let indent = self.scan_triple_quoted_indent();
+        let mut line_start = true;
+
+        while !(self.cursor.peek() == '"'
+            && self.cursor.next() == '"'
+            && self.cursor.char_at(2) == '"')
+        {
+            if self.cursor.is_at_end() {
+                bail!(LexError::UnclosedStringQuotes {
+                    src: self.source.clone(),
+                    span: (start_location..self.cursor.current).into(),
+                })
+            }
+
+            // Stripping indent
+            if line_start {
+                let mut column = 0;
+                while column < indent && matches!(self.cursor.peek(), ' ' | '\t') {
+                    self.cursor.bump();
+                    column += 1;
+                }
+                line_start = false;
+                continue;
+            }
+
+            let ch = self.advance();
+
+            // String escaping
+            if ch == '\\' {
+                text.push(self.scan_escape_sequence());
+            } else {
+                text.push(ch);
+                line_start = ch == '\n';
+            }
+        }
+
+        self.cursor.bump();
+        self.cursor.bump();
+        self.cursor.bump();
+        let end_location = self.cursor.current;
+
+        Token {
+            tk_type: TokenKind::Text,
+            value: text,
+            address: self.address(start_location..end_location),
+        }
+    }
+
+    /// Scans doc comment `/// text` or module doc comment `//! text`
+    ///
+    /// Single leading space and trailing
+    /// whitespaces of the text are stripped.
+    ///
+    fn scan_doc_comment(&mut self, kind: TokenKind) -> Token {
+        let start_location = self.cursor.current - 1;
+
+        // Skipping `//` or `/!`
+        self.advance();
+        self.advance();
+
+        // Scanning text until the end of line
+        let mut text = String::new();
+        while !self.cursor.is_at_end() && self.cursor.peek() != '\n' {
+            text.push(self.advance());
+        }
+        let end_location = self.cursor.current;
+
+        Token {
+            tk_type: kind,
+            value: text.strip_prefix(' ').unwrap_or(&text).trim_end().into(),
+            address: self.address(start_location..end_location),
+        }
+    }
+
+    /// Scans label `'name`. Implies `'` is already ate.
+    ///
+    /// Token value is the label name without the quote.
+    ///
+    fn scan_label(&mut self) -> Token {
+        let start_location = self.cursor.current - 1;
+
+        // Label name should start with a letter
+        if self.cursor.is_at_end() || !self.is_letter(self.cursor.peek()) {
+            bail!(LexError::UnexpectedCharacter {
+                src: self.source.clone(),
+                span: start_location.into(),
+                ch: '\''
+            })
+        }
+
+        // Scanning name
+        let mut name = EcoString::new();
+        while !self.cursor.is_at_end() && self.is_id(self.cursor.peek()) {
+            name.push(self.advance());
+        }
+        let end_location = self.cursor.current;
+
+        Token {
+            tk_type: TokenKind::Label,
+            value: name,
+            address: self.address(start_location..end_location),
+        }
+    }
+
+    /// Scans heredoc string. Implies `<<~` is already ate. Eats closing label.
+    ///
+    /// Text starts on the line after the label and lasts until the line,
+    /// that contains only the label. Escape sequences aren't processed,
+    /// and common leading indent is stripped from every line.
+    fn scan_heredoc(&mut self) -> Token {
+        let start_location = self.cursor.current - 3;
+
+        // Scanning label
+        let mut label = String::new();
+        while !self.cursor.is_at_end() && self.is_id(self.cursor.peek()) {
+            label.push(self.advance());
+        }
+
+        // Skipping whitespaces after label
+        while matches!(self.cursor.peek(), ' ' | '\t' | '\r') && !self.cursor.is_at_end() {
+            self.advance();
+        }
+
+        // Label should be followed by a new line
+        if label.is_empty() || !self.is_match('\n') {
+            bail!(LexError::InvalidHeredocLabel {
+                src: self.source.clone(),
+                span: (start_location..self.cursor.current).into(),
+            })
+        }
+
+        // Scanning lines until the closing label
+        let mut lines: Vec<String> = Vec::new();
+        loop {
+            if self.cursor.is_at_end() {
+                bail!(LexError::UnclosedStringQuotes {
+                    src: self.source.clone(),
+                    span: (start_location..self.cursor.current).into(),
+                })
+            }
+
+            let mut line = String::new();
+            while !self.cursor.is_at_end() && self.cursor.peek() != '\n' {
+                line.push(self.advance());
+            }
+
+            if line.trim() == label {
+                break;
+            }
+
+            self.is_match('\n');
+            lines.push(line);
+        }
+
+        // Calculating common leading indent, blank lines are ignored
+        let indent = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.chars()
+                    .take_while(|ch| matches!(ch, ' ' | '\t'))
+                    .count()
+            })
+            .min()
+            .unwrap_or(0);
+
+        // Stripping indent
+        let mut text: EcoString = EcoString::new();
+        for line in lines {
+            text.push_str(&line.chars().skip(indent).collect::<String>());
+            text.push('\n');
+        }
+
+        let end_location = self.cursor.current;
+
+        Token {
+            tk_type: TokenKind::Text,
+            value: text,
+            address: self.address(start_location..end_location),
         }
     }
 
@@ -425,7 +745,14 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         let mut text: EcoString = EcoString::from(start);
         let mut is_float: bool = false;
 
-        while self.is_digit(self.cursor.peek()) || self.cursor.peek() == '.' {
+        while self.is_digit(self.cursor.peek())
+            || self.cursor.peek() == '.'
+            || self.cursor.peek() == '_'
+        {
+            if self.cursor.peek() == '_' {
+                self.skip_separator(start_location, &text, |ch| ch.is_ascii_digit());
+                continue;
+            }
             if self.cursor.peek() == '.' {
                 // Checking start of range
                 if self.cursor.next() == '.' {
@@ -457,8 +784,33 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         Token {
             tk_type: TokenKind::Number,
             value: text,
-            address: Address::span(self.source.clone(), start_location..end_location),
+            address: self.address(start_location..end_location),
+        }
+    }
+
+    /// Skips digit separator `_`, that should be placed
+    /// between two digits, like `1_000` or `0xFF_FF`
+    ///
+    /// # Arguments
+    /// * `start_location`: start of the number
+    /// * `text`: number text, scanned before the separator
+    /// * `is_digit`: checks char is a digit of the number
+    ///
+    fn skip_separator(
+        &mut self,
+        start_location: usize,
+        text: &EcoString,
+        is_digit: fn(char) -> bool,
+    ) {
+        let after_digit = text.chars().last().is_some_and(is_digit);
+        if !after_digit || !is_digit(self.cursor.next()) {
+            bail!(LexError::InvalidNumber {
+                src: self.source.clone(),
+                span: (start_location..self.cursor.current + 1).into(),
+                number: format!("{text}_").into()
+            })
         }
+        self.advance();
     }
 
     /// Scans hexadecimal numbers `0x{pattern}`
@@ -468,7 +820,11 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         self.advance();
         let mut text: EcoString = EcoString::from("0x");
 
-        while self.cursor.peek().is_ascii_hexdigit() {
+        while self.cursor.peek().is_ascii_hexdigit() || self.cursor.peek() == '_' {
+            if self.cursor.peek() == '_' {
+                self.skip_separator(start_location, &text, |ch| ch.is_ascii_hexdigit());
+                continue;
+            }
             text.push(self.advance());
             if self.cursor.is_at_end() {
                 break;
@@ -480,7 +836,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         Token {
             tk_type: TokenKind::Number,
             value: text,
-            address: Address::span(self.source.clone(), start_location..end_location),
+            address: self.address(start_location..end_location),
         }
     }
 
@@ -491,7 +847,11 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         self.advance();
         let mut text: EcoString = EcoString::from("0o");
 
-        while self.cursor.peek().is_digit(8) {
+        while self.cursor.peek().is_digit(8) || self.cursor.peek() == '_' {
+            if self.cursor.peek() == '_' {
+                self.skip_separator(start_location, &text, |ch| ch.is_digit(8));
+                continue;
+            }
             text.push(self.advance());
             if self.cursor.is_at_end() {
                 break;
@@ -503,7 +863,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         Token {
             tk_type: TokenKind::Number,
             value: text,
-            address: Address::span(self.source.clone(), start_location..end_location),
+            address: self.address(start_location..end_location),
         }
     }
 
@@ -514,7 +874,11 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         self.advance();
         let mut text: EcoString = EcoString::from("0b");
 
-        while self.cursor.peek().is_digit(2) {
+        while self.cursor.peek().is_digit(2) || self.cursor.peek() == '_' {
+            if self.cursor.peek() == '_' {
+                self.skip_separator(start_location, &text, |ch| ch.is_digit(2));
+                continue;
+            }
             text.push(self.advance());
             if self.cursor.is_at_end() {
                 break;
@@ -526,7 +890,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         Token {
             tk_type: TokenKind::Number,
             value: text,
-            address: Address::span(self.source.clone(), start_location..end_location),
+            address: self.address(start_location..end_location),
         }
     }
 
@@ -559,7 +923,7 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         Token {
             tk_type,
             value: text,
-            address: Address::span(self.source.clone(), start_location..end_location),
+            address: self.address(start_location..end_location),
         }
     }
 
@@ -584,10 +948,21 @@ impl<'source, 'cursor> Lexer<'source, 'cursor> {
         self.tokens.push(Token::new(
             tk_type,
             tk_value.into(),
-            Address::new(self.source.clone(), self.cursor.current),
+            Address::new(
+                self.source.clone(),
+                self.cursor.current,
+                self.cursor.byte_offset(self.cursor.current),
+            ),
         ));
     }
 
+    /// Creates address from the chars span,
+    /// computing its byte offsets
+    fn address(&self, span: Range<usize>) -> Address {
+        let bytes = self.cursor.byte_offset(span.start)..self.cursor.byte_offset(span.end);
+        Address::span(self.source.clone(), span, bytes)
+    }
+
     /// Checks character is '0..9'
     fn is_digit(&self, ch: char) -> bool {
         ch.is_ascii_digit()
@@ -6,68 +6,83 @@ use watt_common::address::Address;
 #[derive(Debug, Clone, Eq, PartialEq, Copy, Hash)]
 #[allow(dead_code)]
 pub enum TokenKind {
-    Let,       // let
-    Fn,        // fn
-    Plus,      // +
-    Minus,     // -
-    Star,      // *
-    Slash,     // /
-    Percent,   // %
-    Caret,     // ^
-    Or,        // || | or
-    And,       // && | and
-    Bar,       // |
-    Ampersand, // &
-    AddAssign, // +=
-    SubAssign, // -=
-    MulAssign, // *-
-    DivAssign, // /=
-    AndAssign, // &=
-    OrAssign,  // |=
-    XorAssign, // ^=
-    Lparen,    // (
-    Rparen,    // )
-    Lbrace,    // {
-    Rbrace,    // }
-    Eq,        // ==
-    NotEq,     // !=
-    Text,      // 'text'
-    Number,    // 1234567890.0123456789
-    Assign,    // =
-    Id,        // variable id
-    Comma,     // ,
-    If,        // if
-    Bool,      // bool
-    Loop,      // loop
-    Type,      // type
-    Enum,      // enum
-    Dot,       // .
-    Range,     // ..
-    Greater,   // >
-    Less,      // <
-    GreaterEq, // >=
-    LessEq,    // <=
-    Concat,    // <>
-    Elif,      // elif
-    Else,      // else
-    Use,       // use
-    Lbracket,  // [
-    Rbracket,  // ]
-    Colon,     // :
-    Semicolon, // ;
-    Bang,      // !
-    Wildcard,  // _
-    In,        // in
-    Unit,      // unit
-    As,        // as
-    Pub,       // pub
-    Match,     // match
-    Arrow,     // arrow
-    Extern,    // extern
-    For,       // for
-    Panic,     // panic
-    Todo,      // todo
-    Const,     // const
+    Let,         // let
+    Fn,          // fn
+    Plus,        // +
+    Minus,       // -
+    Star,        // *
+    Slash,       // /
+    TildeSlash,  // ~/
+    Percent,     // %
+    Caret,       // ^
+    Or,          // || | or
+    And,         // && | and
+    Bar,         // |
+    Ampersand,   // &
+    AddAssign,   // +=
+    SubAssign,   // -=
+    MulAssign,   // *-
+    DivAssign,   // /=
+    ModAssign,   // %=
+    AndAssign,   // &=
+    OrAssign,    // |=
+    XorAssign,   // ^=
+    Lparen,      // (
+    Rparen,      // )
+    Lbrace,      // {
+    Rbrace,      // }
+    Eq,          // ==
+    NotEq,       // !=
+    Text,        // 'text'
+    Number,      // 1234567890.0123456789
+    Assign,      // =
+    Id,          // variable id
+    Comma,       // ,
+    If,          // if
+    Bool,        // bool
+    Loop,        // loop
+    Type,        // type
+    Enum,        // enum
+    Dot,         // .
+    QuestionDot, // ?.
+    Question,    // ?
+    Range,       // ..
+    Greater,     // >
+    Less,        // <
+    GreaterEq,   // >=
+    LessEq,      // <=
+    Concat,      // <>
+    Shl,         // <<
+    Shr,         // >>
+    Elif,        // elif
+    Else,        // else
+    Use,         // use
+    Lbracket,    // [
+    Rbracket,    // ]
+    Colon,       // :
+    Semicolon,   // ;
+    Bang,        // !
+    Wildcard,    // _
+    In,          // in
+    Unit,        // unit
+    As,          // as
+    Pub,         // pub
+    Match,       // match
+    Arrow,       // arrow
+    Extern,      // extern
+    For,         // for
+    Panic,       // panic
+    Todo,        // todo
+    Const,       // const
+    DocComment,  // /// doc
+    ModuleDoc,   // //! doc
+    Throw,       // throw
+    Try,         // try
+    Catch,       // catch
+    Await,       // await
+    Hash,        // #
+    Label,       // 'label
+    Break,       // break
 }
 
 /// Token structure
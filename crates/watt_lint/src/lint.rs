@@ -5,8 +5,8 @@ use crate::{
     warnings::LintWarning,
 };
 use watt_ast::ast::{
-    Block, ConstDeclaration, Declaration, Either, ElseBranch, Expression, FnDeclaration, Module,
-    Range, Statement, TypeDeclaration,
+    Block, ChainAccess, ConstDeclaration, Declaration, Either, ElseBranch, Expression,
+    FnDeclaration, Module, Range, Statement, TypeDeclaration,
 };
 use watt_common::{package::DraftPackage, skip, warn};
 
@@ -121,6 +121,18 @@ impl<'cx, 'module> LintCx<'cx, 'module> {
                     }
                 }
             }
+            TypeDeclaration::Alias { location, name, .. } => {
+                // Checking type name is in `PascalCase`
+                if !case::is_pascal_case(name) {
+                    warn!(
+                        self,
+                        LintWarning::WrongTypeName {
+                            src: location.source.clone(),
+                            span: location.span.clone().into()
+                        }
+                    )
+                }
+            }
         }
     }
 
@@ -133,6 +145,13 @@ impl<'cx, 'module> LintCx<'cx, 'module> {
                 params,
                 body,
                 ..
+            }
+            | FnDeclaration::ConstFunction {
+                location,
+                name,
+                params,
+                body,
+                ..
             } => {
                 match body {
                     Either::Left(block) => self.lint_block(block),
@@ -257,6 +276,11 @@ impl<'cx, 'module> LintCx<'cx, 'module> {
                 self.lint_expr(what);
                 self.lint_expr(value);
             }
+            Statement::ParallelAssign { what, value, .. } => {
+                for expr in what.iter().chain(value) {
+                    self.lint_expr(expr);
+                }
+            }
             Statement::Expr(expr) => {
                 self.lint_expr(expr);
             }
@@ -277,6 +301,12 @@ impl<'cx, 'module> LintCx<'cx, 'module> {
             Statement::Semi(expr) => {
                 self.lint_expr(expr);
             }
+            Statement::Throw { value, .. } => {
+                self.lint_expr(value);
+            }
+            Statement::Labeled { body, .. } => {
+                self.lint_block(body);
+            }
         }
     }
 
@@ -329,6 +359,14 @@ impl<'cx, 'module> LintCx<'cx, 'module> {
                     self.lint_expr(arg);
                 }
             }
+            Expression::OptionalChain { base, access, .. } => {
+                self.lint_expr(base);
+                if let ChainAccess::Call(_, args) = access {
+                    for arg in args {
+                        self.lint_expr(arg);
+                    }
+                }
+            }
             Expression::Function {
                 location,
                 params,
@@ -360,6 +398,13 @@ impl<'cx, 'module> LintCx<'cx, 'module> {
                     }
                 }
             }
+            Expression::TryCatch { body, handler, .. } => {
+                self.lint_block(body);
+                self.lint_block(handler);
+            }
+            Expression::Try { value, .. } | Expression::Await { value, .. } => {
+                self.lint_expr(value)
+            }
             _ => skip!(),
         }
     }
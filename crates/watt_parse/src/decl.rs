@@ -1,8 +1,9 @@
 /// Imports
 use crate::{errors::ParseError, parser::Parser};
+use ecow::EcoString;
 use watt_ast::ast::{
-    ConstDeclaration, Declaration, Dependency, EnumConstructor, Field, FnDeclaration, Publicity,
-    TypeDeclaration, UseKind,
+    Attribute, ConstDeclaration, Declaration, Dependency, EnumConstructor, Field, FnDeclaration,
+    Publicity, TypeDeclaration, UseKind,
 };
 use watt_common::bail;
 use watt_lex::tokens::TokenKind;
@@ -10,7 +11,12 @@ use watt_lex::tokens::TokenKind;
 /// Implementation of declarations parsing
 impl<'file> Parser<'file> {
     /// Fn declaration parsing
-    fn fn_declaration(&mut self, publicity: Publicity) -> FnDeclaration {
+    fn fn_declaration(
+        &mut self,
+        publicity: Publicity,
+        doc: Option<EcoString>,
+        attributes: Vec<Attribute>,
+    ) -> FnDeclaration {
         // parsing function name
         let start_location = self.peek().address.clone();
         self.consume(TokenKind::Fn);
@@ -53,11 +59,58 @@ impl<'file> Parser<'file> {
             params,
             body,
             typ,
+            doc,
+            attributes,
+        }
+    }
+
+    /// Const fn declaration parsing
+    fn const_fn_declaration(
+        &mut self,
+        publicity: Publicity,
+        doc: Option<EcoString>,
+        attributes: Vec<Attribute>,
+    ) -> FnDeclaration {
+        // parsing function `const fn ...`
+        let start_location = self.peek().address.clone();
+        self.consume(TokenKind::Const);
+        match self.fn_declaration(publicity, doc, attributes) {
+            FnDeclaration::Function {
+                location,
+                publicity,
+                name,
+                generics,
+                params,
+                body,
+                typ,
+                doc,
+                attributes,
+            } => {
+                // checking function body is const
+                self.check_fn_const(&body);
+
+                FnDeclaration::ConstFunction {
+                    location: start_location + location,
+                    publicity,
+                    name,
+                    generics,
+                    params,
+                    body,
+                    typ,
+                    doc,
+                    attributes,
+                }
+            }
+            _ => unreachable!(),
         }
     }
 
     /// Constant declaration parsing
-    fn const_declaration(&mut self, publicity: Publicity) -> ConstDeclaration {
+    fn const_declaration(
+        &mut self,
+        publicity: Publicity,
+        attributes: Vec<Attribute>,
+    ) -> ConstDeclaration {
         // parsing constant name `const $id`
         self.consume(TokenKind::Const);
         let name = self.consume(TokenKind::Id).clone();
@@ -79,11 +132,17 @@ impl<'file> Parser<'file> {
             name: name.value,
             typ,
             value,
+            attributes,
         }
     }
 
     /// Extern fn declaration parsing
-    fn extern_fn_declaration(&mut self, publicity: Publicity) -> FnDeclaration {
+    fn extern_fn_declaration(
+        &mut self,
+        publicity: Publicity,
+        doc: Option<EcoString>,
+        attributes: Vec<Attribute>,
+    ) -> FnDeclaration {
         // parsing function name
         let start_location = self.peek().address.clone();
         self.consume(TokenKind::Extern);
@@ -128,6 +187,8 @@ impl<'file> Parser<'file> {
             params,
             typ,
             body,
+            doc,
+            attributes,
         }
     }
 
@@ -150,12 +211,31 @@ impl<'file> Parser<'file> {
     }
 
     /// Type declaration parsing
-    fn type_declaration(&mut self, publicity: Publicity) -> TypeDeclaration {
+    fn type_declaration(
+        &mut self,
+        publicity: Publicity,
+        attributes: Vec<Attribute>,
+    ) -> TypeDeclaration {
         // parsing type name
         let start_location = self.peek().address.clone();
         self.consume(TokenKind::Type);
         let name = self.consume(TokenKind::Id).clone();
 
+        // parsing type alias `type $name = $target`
+        if self.check(TokenKind::Assign) {
+            self.consume(TokenKind::Assign);
+            let target = self.type_annotation();
+            let end_location = self.previous().address.clone();
+
+            return TypeDeclaration::Alias {
+                location: start_location + end_location,
+                publicity,
+                name: name.value,
+                target,
+                attributes,
+            };
+        }
+
         // parsing generic parameters
         let generics = if self.check(TokenKind::Lbracket) {
             self.generics()
@@ -178,6 +258,7 @@ impl<'file> Parser<'file> {
             name: name.value,
             fields,
             generics,
+            attributes,
         }
     }
 
@@ -203,7 +284,11 @@ impl<'file> Parser<'file> {
     }
 
     /// Enum declaration parsing
-    fn enum_declaration(&mut self, publicity: Publicity) -> TypeDeclaration {
+    fn enum_declaration(
+        &mut self,
+        publicity: Publicity,
+        attributes: Vec<Attribute>,
+    ) -> TypeDeclaration {
         // parsing enum name
         let start_location = self.peek().address.clone();
         self.consume(TokenKind::Enum);
@@ -231,6 +316,7 @@ impl<'file> Parser<'file> {
             name: name.value,
             generics,
             variants,
+            attributes,
         }
     }
 
@@ -274,14 +360,66 @@ impl<'file> Parser<'file> {
         }
     }
 
+    /// Attributes parsing
+    ///
+    /// `#[deprecated]` or `#[deprecated("message")]`
+    ///
+    pub(crate) fn attributes(&mut self) -> Vec<Attribute> {
+        let mut attributes = Vec::new();
+        while self.check(TokenKind::Hash) {
+            // parsing attribute name `#[$id`
+            let start_location = self.peek().address.clone();
+            self.consume(TokenKind::Hash);
+            self.consume(TokenKind::Lbracket);
+            let name = self.consume(TokenKind::Id).clone();
+
+            // parsing attribute argument `($text)`, if given
+            let argument = if self.check(TokenKind::Lparen) {
+                self.consume(TokenKind::Lparen);
+                let argument = self.consume(TokenKind::Text).value.clone();
+                self.consume(TokenKind::Rparen);
+                Some(argument)
+            } else {
+                None
+            };
+
+            // parsing attribute end `]`
+            self.consume(TokenKind::Rbracket);
+            let end_location = self.previous().address.clone();
+
+            attributes.push(match name.value.as_str() {
+                "deprecated" => Attribute::Deprecated {
+                    location: start_location + end_location,
+                    message: argument,
+                },
+                _ => bail!(ParseError::UnknownAttribute {
+                    src: self.source.clone(),
+                    span: name.address.span.into(),
+                    name: name.value
+                }),
+            });
+        }
+        attributes
+    }
+
     /// Declaration parsing
-    pub(crate) fn declaration(&mut self, publicity: Publicity) -> Declaration {
+    pub(crate) fn declaration(
+        &mut self,
+        publicity: Publicity,
+        doc: Option<EcoString>,
+        attributes: Vec<Attribute>,
+    ) -> Declaration {
         match self.peek().tk_type {
-            TokenKind::Type => Declaration::Type(self.type_declaration(publicity)),
-            TokenKind::Fn => Declaration::Fn(self.fn_declaration(publicity)),
-            TokenKind::Enum => Declaration::Type(self.enum_declaration(publicity)),
-            TokenKind::Const => Declaration::Const(self.const_declaration(publicity)),
-            TokenKind::Extern => Declaration::Fn(self.extern_fn_declaration(publicity)),
+            TokenKind::Type => Declaration::Type(self.type_declaration(publicity, attributes)),
+            TokenKind::Fn => Declaration::Fn(self.fn_declaration(publicity, doc, attributes)),
+            TokenKind::Enum => Declaration::Type(self.enum_declaration(publicity, attributes)),
+            TokenKind::Const if self.check_next(TokenKind::Fn) => {
+                Declaration::Fn(self.const_fn_declaration(publicity, doc, attributes))
+            }
+            TokenKind::Const => Declaration::Const(self.const_declaration(publicity, attributes)),
+            TokenKind::Extern => {
+                Declaration::Fn(self.extern_fn_declaration(publicity, doc, attributes))
+            }
             _ => {
                 let token = self.peek().clone();
                 bail!(ParseError::UnexpectedDeclarationToken {
@@ -53,6 +53,19 @@ pub(crate) enum ParseError {
         #[label("this in unacceptable with assignment operation.")]
         span: SourceSpan,
     },
+    #[error("could not assign {values} values to {targets} targets.")]
+    #[diagnostic(
+        code(parse::parallel_assignment_mismatch),
+        help("parallel assignment requires a value for each target.")
+    )]
+    ParallelAssignmentMismatch {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("values amount differs from targets amount.")]
+        span: SourceSpan,
+        targets: usize,
+        values: usize,
+    },
     #[error("unexpected `{unexpected}` in expression parsing.")]
     #[diagnostic(code(parse::unexpected_expression_token))]
     UnexpectedExpressionToken {
@@ -76,12 +89,24 @@ pub(crate) enum ParseError {
         span: SourceSpan,
         unexpected: EcoString,
     },
+    #[error("unknown attribute `{name}`.")]
+    #[diagnostic(
+        code(parse::unknown_attribute),
+        help("only `deprecated` attribute is supported.")
+    )]
+    UnknownAttribute {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this attribute is unknown.")]
+        span: SourceSpan,
+        name: EcoString,
+    },
     #[error("non-const value.")]
     #[diagnostic(
         code(parse::nonconst_expr),
         help(
             "constant values can't depend on the logical clauses,
-            variables, functions, custom enums and types."
+            non-const functions, fields, custom enums and types."
         )
     )]
     NonConstExpr {
@@ -90,4 +115,18 @@ pub(crate) enum ParseError {
         #[label("this can not be used as a constant value.")]
         span: SourceSpan,
     },
+    #[error("non-const function body.")]
+    #[diagnostic(
+        code(parse::nonconst_fn_body),
+        help(
+            "const functions could only contain literals, operations,
+            `let`, `if`, `match` and const functions calls."
+        )
+    )]
+    NonConstFnBody {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this can not be used in the const function.")]
+        span: SourceSpan,
+    },
 }
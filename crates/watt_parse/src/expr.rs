@@ -1,11 +1,12 @@
 /// Imports
 use crate::{errors::ParseError, parser::Parser};
-use miette::SourceSpan;
-use watt_ast::ast::{BinaryOp, Case, Either, ElseBranch, Expression, Parameter, Pattern, UnaryOp};
+use watt_ast::ast::{
+    BinaryOp, Case, ChainAccess, Either, ElseBranch, Expression, Parameter, Pattern, UnaryOp,
+};
 use watt_common::bail;
 use watt_lex::tokens::TokenKind;
 
-/// Implementation of epxression parsing
+/// Implementation of expression parsing
 impl<'file> Parser<'file> {
     /// Anonymous fn expr
     /// TODO: rework syntax
@@ -67,7 +68,7 @@ impl<'file> Parser<'file> {
 
         ElseBranch::Elif {
             location: start_location + end_location,
-            logical,
+            logical: Box::new(logical),
             body,
         }
     }
@@ -98,6 +99,31 @@ impl<'file> Parser<'file> {
         }
     }
 
+    /// Try-catch expr parsing
+    fn try_catch_expr(&mut self) -> Expression {
+        let start_location = self.consume(TokenKind::Try).address.clone();
+        let body = self.block();
+        self.consume(TokenKind::Catch);
+        let bind = self.consume(TokenKind::Id).value.clone();
+        // `: $type`, if given
+        let typ = if self.check(TokenKind::Colon) {
+            self.consume(TokenKind::Colon);
+            Some(self.type_annotation())
+        } else {
+            None
+        };
+        let handler = self.block();
+        let end_location = self.previous().address.clone();
+
+        Expression::TryCatch {
+            location: start_location + end_location,
+            body,
+            bind,
+            typ,
+            handler,
+        }
+    }
+
     /// Variable parsing
     pub(crate) fn variable(&mut self) -> Expression {
         // parsing base identifier
@@ -123,6 +149,32 @@ impl<'file> Parser<'file> {
                 };
                 continue;
             }
+            // checking for optional chain `a?.b`, `a?.b()`
+            if self.check(TokenKind::QuestionDot) {
+                self.consume(TokenKind::QuestionDot);
+                let variable = self.consume(TokenKind::Id).clone();
+                let access = if self.check(TokenKind::Lparen) {
+                    ChainAccess::Call(variable.value, self.args())
+                } else {
+                    ChainAccess::Field(variable.value)
+                };
+                let span_end = self.previous().address.clone();
+                result = Expression::OptionalChain {
+                    location: span_start.clone() + span_end,
+                    base: Box::new(result),
+                    access,
+                };
+                continue;
+            }
+            // checking for error propagation `a?`
+            if self.check(TokenKind::Question) {
+                let span_end = self.consume(TokenKind::Question).address.clone();
+                result = Expression::Try {
+                    location: span_start.clone() + span_end,
+                    value: Box::new(result),
+                };
+                continue;
+            }
             // checking for call
             if self.check(TokenKind::Lparen) {
                 let args = self.args();
@@ -199,6 +251,17 @@ impl<'file> Parser<'file> {
         }
     }
 
+    /// Break expr `break 'label`
+    #[inline]
+    fn break_expr(&mut self) -> Expression {
+        let span_start = self.consume(TokenKind::Break).address.clone();
+        let label = self.consume(TokenKind::Label).clone();
+        Expression::Break {
+            location: span_start + label.address,
+            label: label.value,
+        }
+    }
+
     /// Primary expr parsing
     fn primary_expr(&mut self) -> Expression {
         match self.peek().tk_type {
@@ -233,10 +296,12 @@ impl<'file> Parser<'file> {
             }
             TokenKind::Todo => self.todo_expr(),
             TokenKind::Panic => self.panic_expr(),
+            TokenKind::Break => self.break_expr(),
             TokenKind::Lparen => self.grouping_expr(),
             TokenKind::Fn => self.anonymous_fn_expr(),
             TokenKind::Match => self.pattern_matching(),
             TokenKind::If => self.if_expr(),
+            TokenKind::Try => self.try_catch_expr(),
             _ => {
                 let token = self.peek().clone();
                 bail!(ParseError::UnexpectedExpressionToken {
@@ -249,7 +314,7 @@ impl<'file> Parser<'file> {
         }
     }
 
-    /// Unary expr `!` and `-` parsing
+    /// Unary expr `!`, `-` and `await` parsing
     fn unary_expr(&mut self) -> Expression {
         if self.check(TokenKind::Bang) || self.check(TokenKind::Minus) {
             let op = self.advance().clone();
@@ -263,18 +328,26 @@ impl<'file> Parser<'file> {
                 },
                 value: Box::new(self.unary_expr()),
             }
+        } else if self.check(TokenKind::Await) {
+            let location = self.advance().address.clone();
+
+            Expression::Await {
+                location,
+                value: Box::new(self.unary_expr()),
+            }
         } else {
             self.primary_expr()
         }
     }
 
-    /// Binary operations `*`, `/`, `%`, `^`, `&`, `|` parsing
+    /// Binary operations `*`, `/`, `~/`, `%`, `^`, `&`, `|` parsing
     fn multiplicative_expr(&mut self) -> Expression {
         let start_location = self.peek().address.clone();
         let mut left = self.unary_expr();
 
         while self.check(TokenKind::Star)
             || self.check(TokenKind::Slash)
+            || self.check(TokenKind::TildeSlash)
             || self.check(TokenKind::Percent)
             || self.check(TokenKind::Caret)
             || self.check(TokenKind::Ampersand)
@@ -291,6 +364,7 @@ impl<'file> Parser<'file> {
                 op: match op.tk_type {
                     TokenKind::Star => BinaryOp::Mul,
                     TokenKind::Slash => BinaryOp::Div,
+                    TokenKind::TildeSlash => BinaryOp::IntDiv,
                     TokenKind::Ampersand => BinaryOp::BitwiseAnd,
                     TokenKind::Bar => BinaryOp::BitwiseOr,
                     TokenKind::Percent => BinaryOp::Mod,
@@ -331,10 +405,35 @@ impl<'file> Parser<'file> {
         left
     }
 
+    /// Shift operations `<<`, `>>` parsing
+    fn shift_expr(&mut self) -> Expression {
+        let start_location = self.peek().address.clone();
+        let mut left = self.additive_expr();
+
+        while self.check(TokenKind::Shl) || self.check(TokenKind::Shr) {
+            let op = self.peek().clone();
+            self.bump();
+            let right = self.additive_expr();
+            let end_location = self.previous().address.clone();
+            left = Expression::Bin {
+                location: start_location.clone() + end_location,
+                left: Box::new(left),
+                right: Box::new(right),
+                op: match op.tk_type {
+                    TokenKind::Shl => BinaryOp::Shl,
+                    TokenKind::Shr => BinaryOp::Shr,
+                    _ => unreachable!(),
+                },
+            };
+        }
+
+        left
+    }
+
     /// Compare operations `<`, `>`, `<=`, `>=` parsing
     fn compare_expr(&mut self) -> Expression {
         let span_start = self.peek().address.clone();
-        let mut left = self.additive_expr();
+        let mut left = self.shift_expr();
 
         if self.check(TokenKind::Greater)
             || self.check(TokenKind::GreaterEq)
@@ -342,7 +441,7 @@ impl<'file> Parser<'file> {
             || self.check(TokenKind::LessEq)
         {
             let op = self.advance().clone();
-            let right = self.additive_expr();
+            let right = self.shift_expr();
             let span_end = self.previous().address.clone();
             left = Expression::Bin {
                 location: span_start + span_end,
@@ -573,6 +672,19 @@ impl<'file> Parser<'file> {
             let start_location = self.peek().address.clone();
             // Pattern of case
             let pattern = self.pattern();
+            // `if $condition` guard
+            let pattern = if self.check(TokenKind::If) {
+                self.consume(TokenKind::If);
+                let condition = self.expr();
+                let end_location = self.previous().address.clone();
+                Pattern::Guard {
+                    address: start_location.clone() + end_location,
+                    pattern: Box::new(pattern),
+                    condition,
+                }
+            } else {
+                pattern
+            };
             // -> { body, ... }
             self.consume(TokenKind::Arrow);
             let body = if self.check(TokenKind::Lbrace) {
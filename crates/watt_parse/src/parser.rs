@@ -1,7 +1,13 @@
 /// Imports
 use crate::errors::ParseError;
+use ecow::EcoString;
 use miette::NamedSource;
-use std::sync::Arc;
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+};
 use watt_ast::ast::*;
 use watt_common::{bail, skip};
 use watt_lex::tokens::{Token, TokenKind};
@@ -12,6 +18,11 @@ pub struct Parser<'file> {
     tokens: Vec<Token>,
     /// Current index
     pub(crate) current: u128,
+    /// Doc comments, by index of the
+    /// token they are attached to
+    docs: HashMap<usize, EcoString>,
+    /// Module doc comment
+    module_doc: Option<EcoString>,
     /// Source file
     pub(crate) source: &'file Arc<NamedSource<String>>,
 }
@@ -20,35 +31,147 @@ pub struct Parser<'file> {
 #[allow(unused_qualifications)]
 impl<'file> Parser<'file> {
     /// New parser
+    ///
+    /// Doc comment tokens are removed from the tokens buffer,
+    /// and attached to the token, that follows them.
+    /// Module doc comment tokens are joined into the module doc.
+    ///
     pub fn new(tokens: Vec<Token>, source: &'file Arc<NamedSource<String>>) -> Self {
+        let mut buffer = Vec::with_capacity(tokens.len());
+        let mut docs: HashMap<usize, EcoString> = HashMap::new();
+        let mut doc: Vec<EcoString> = Vec::new();
+        let mut module_doc: Vec<EcoString> = Vec::new();
+        for tk in tokens {
+            if tk.tk_type == TokenKind::DocComment {
+                doc.push(tk.value);
+            } else if tk.tk_type == TokenKind::ModuleDoc {
+                module_doc.push(tk.value);
+            } else {
+                if !doc.is_empty() {
+                    docs.insert(buffer.len(), doc.join("\n").into());
+                    doc.clear();
+                }
+                buffer.push(tk);
+            }
+        }
         Parser {
-            tokens,
+            tokens: buffer,
             current: 0,
+            docs,
+            module_doc: if module_doc.is_empty() {
+                None
+            } else {
+                Some(module_doc.join("\n").into())
+            },
             source,
         }
     }
 
     /// Parsing all declarations
+    ///
+    /// If syntax errors were found, all of them
+    /// are reported at once, after the parsing.
+    ///
     pub fn parse(&mut self) -> Module {
+        let (module, errors) = self.parse_recovering();
+        if !errors.is_empty() {
+            // errors are already printed, when raised,
+            // so unwinding is resumed without the panic hook
+            panic::resume_unwind(Box::new(errors.join("\n")));
+        }
+        module
+    }
+
+    /// Parsing all declarations, recovering from the syntax errors
+    ///
+    /// When declaration couldn't be parsed, the error is collected
+    /// and tokens are skipped until the next declaration start.
+    /// Returns best-effort module with the successfully parsed
+    /// declarations, and the rendered errors.
+    ///
+    pub fn parse_recovering(&mut self) -> (Module, Vec<String>) {
         // parsing declaration before reaching
         // end of file
         let mut declarations: Vec<Declaration> = Vec::new();
         let mut dependencies: Vec<Dependency> = Vec::new();
+        let mut errors: Vec<String> = Vec::new();
         while !self.is_at_end() {
-            match self.peek().tk_type {
-                TokenKind::Pub => {
-                    self.consume(TokenKind::Pub);
-                    declarations.push(self.declaration(Publicity::Public))
+            let start = self.current;
+            let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+                // doc comment could be placed before or after attributes
+                let doc = self.doc();
+                let attributes = self.attributes();
+                let doc = doc.or_else(|| self.doc());
+                match self.peek().tk_type {
+                    TokenKind::Pub => {
+                        self.consume(TokenKind::Pub);
+                        Either::Left(self.declaration(Publicity::Public, doc, attributes))
+                    }
+                    TokenKind::Use if attributes.is_empty() => {
+                        Either::Right(self.use_declaration())
+                    }
+                    _ => Either::Left(self.declaration(Publicity::Private, doc, attributes)),
+                }
+            }));
+            match parsed {
+                Ok(Either::Left(declaration)) => declarations.push(declaration),
+                Ok(Either::Right(dependency)) => dependencies.push(dependency),
+                Err(payload) => {
+                    errors.push(match payload.downcast::<String>() {
+                        Ok(report) => *report,
+                        Err(payload) => match payload.downcast::<&str>() {
+                            Ok(report) => report.to_string(),
+                            Err(payload) => panic::resume_unwind(payload),
+                        },
+                    });
+                    self.synchronize(start);
                 }
-                TokenKind::Use => dependencies.push(self.use_declaration()),
-                _ => declarations.push(self.declaration(Publicity::Private)),
             }
         }
 
-        Module {
-            source: self.source.to_owned(),
-            dependencies,
-            declarations,
+        (
+            Module {
+                source: self.source.to_owned(),
+                doc: self.module_doc.clone(),
+                dependencies,
+                declarations,
+            },
+            errors,
+        )
+    }
+
+    /// Skips tokens until the next declaration start,
+    /// at least one token after `start` is skipped.
+    ///
+    fn synchronize(&mut self, start: u128) {
+        if self.current <= start {
+            self.current = start + 1;
+        }
+        while !self.is_at_end() && !self.is_declaration_start() {
+            self.bump();
+        }
+    }
+
+    /// Checks current token starts the declaration
+    ///
+    /// # Notes
+    /// `fn` should be followed by the name,
+    /// to not be confused with an anonymous function.
+    ///
+    fn is_declaration_start(&self) -> bool {
+        match self.tokens.get(self.current as usize) {
+            Some(tk) => match tk.tk_type {
+                TokenKind::Hash
+                | TokenKind::Type
+                | TokenKind::Enum
+                | TokenKind::Const
+                | TokenKind::Extern
+                | TokenKind::Pub
+                | TokenKind::Use => true,
+                TokenKind::Fn => self.check_next(TokenKind::Id),
+                _ => false,
+            },
+            None => false,
         }
     }
 
@@ -98,19 +221,25 @@ impl<'file> Parser<'file> {
     pub(crate) fn check_value_const(&mut self, expr: &Expression) {
         #[allow(unused_variables)]
         match expr {
-            // expressions that depedends on variables
+            // expressions that depedends on fields
             // or logical clauses are non-const by default.
-            Expression::PrefixVar { location, .. }
-            | Expression::SuffixVar { location, .. }
-            | Expression::Call { location, .. }
+            Expression::SuffixVar { location, .. }
+            | Expression::OptionalChain { location, .. }
             | Expression::Function { location, .. }
             | Expression::Match { location, .. }
             | Expression::Todo { location, .. }
             | Expression::Panic { location, .. }
-            | Expression::If { location, .. } => bail!(ParseError::NonConstExpr {
+            | Expression::If { location, .. }
+            | Expression::TryCatch { location, .. }
+            | Expression::Try { location, .. }
+            | Expression::Await { location, .. }
+            | Expression::Break { location, .. } => bail!(ParseError::NonConstExpr {
                 src: self.source.clone(),
                 span: location.span.clone().into(),
             }),
+            // variables are checked to be constants
+            // during type checking.
+            Expression::PrefixVar { .. } => skip!(),
             // `literals` are const by default.
             Expression::Int { location, .. }
             | Expression::Float { location, .. }
@@ -128,6 +257,137 @@ impl<'file> Parser<'file> {
             Expression::Paren { expr, .. } => {
                 self.check_value_const(expr);
             }
+            // calls of the named functions need their arguments to be checked,
+            // callee is checked to be a const function during type checking.
+            Expression::Call { what, args, .. } => match what.as_ref() {
+                Expression::PrefixVar { .. } => {
+                    args.iter().for_each(|arg| self.check_value_const(arg));
+                }
+                _ => bail!(ParseError::NonConstExpr {
+                    src: self.source.clone(),
+                    span: what.location().span.into(),
+                }),
+            },
+        }
+    }
+
+    /// Checks const function body could be evaluated at compile time
+    ///
+    /// # Notes
+    /// Calls are checked during the type analysis,
+    /// because only const functions could be called.
+    ///
+    pub(crate) fn check_fn_const<E: Borrow<Expression>>(&mut self, body: &Either<Block, E>) {
+        match body {
+            Either::Left(block) => self.check_block_const(block),
+            Either::Right(expr) => self.check_expr_const(expr.borrow()),
+        }
+    }
+
+    /// Checks const function block
+    fn check_block_const(&mut self, block: &Block) {
+        for stmt in &block.body {
+            match stmt {
+                // `let` bindings, and expressions need to be checked.
+                Statement::VarDef { value, .. } => self.check_expr_const(value),
+                Statement::Expr(expr) | Statement::Semi(expr) => self.check_expr_const(expr),
+                // mutations, loops, throws and labeled blocks are non-const.
+                Statement::VarAssign { location, .. }
+                | Statement::ParallelAssign { location, .. }
+                | Statement::Loop { location, .. }
+                | Statement::For { location, .. }
+                | Statement::Throw { location, .. }
+                | Statement::Labeled { location, .. } => bail!(ParseError::NonConstFnBody {
+                    src: self.source.clone(),
+                    span: location.span.clone().into(),
+                }),
+            }
+        }
+    }
+
+    /// Checks const function expression
+    fn check_expr_const(&mut self, expr: &Expression) {
+        match expr {
+            // closures, errors handling, breaks
+            // and promises are non-const.
+            Expression::Function { location, .. }
+            | Expression::TryCatch { location, .. }
+            | Expression::Try { location, .. }
+            | Expression::Await { location, .. }
+            | Expression::Break { location, .. } => bail!(ParseError::NonConstFnBody {
+                src: self.source.clone(),
+                span: location.span.clone().into(),
+            }),
+            // variables and literals are const.
+            Expression::PrefixVar { .. }
+            | Expression::Int { .. }
+            | Expression::Float { .. }
+            | Expression::String { .. }
+            | Expression::Bool { .. }
+            | Expression::Todo { .. }
+            | Expression::Panic { .. } => skip!(),
+            // other expressions need to be checked.
+            Expression::SuffixVar { container, .. } => self.check_expr_const(container),
+            Expression::Bin { left, right, .. } => {
+                self.check_expr_const(left);
+                self.check_expr_const(right);
+            }
+            Expression::As { value, .. } | Expression::Unary { value, .. } => {
+                self.check_expr_const(value)
+            }
+            Expression::Paren { expr, .. } => self.check_expr_const(expr),
+            Expression::Call { what, args, .. } => {
+                self.check_expr_const(what);
+                args.iter().for_each(|arg| self.check_expr_const(arg));
+            }
+            Expression::OptionalChain { base, access, .. } => {
+                self.check_expr_const(base);
+                if let ChainAccess::Call(_, args) = access {
+                    args.iter().for_each(|arg| self.check_expr_const(arg));
+                }
+            }
+            Expression::If {
+                logical,
+                body,
+                else_branches,
+                ..
+            } => {
+                self.check_expr_const(logical);
+                self.check_fn_const(body);
+                for branch in else_branches {
+                    match branch {
+                        ElseBranch::Elif { logical, body, .. } => {
+                            self.check_expr_const(logical);
+                            self.check_fn_const(body);
+                        }
+                        ElseBranch::Else { body, .. } => self.check_fn_const(body),
+                    }
+                }
+            }
+            Expression::Match { value, cases, .. } => {
+                self.check_expr_const(value);
+                for case in cases {
+                    self.check_pattern_const(&case.pattern);
+                    self.check_fn_const(&case.body);
+                }
+            }
+        }
+    }
+
+    /// Checks const function pattern guards
+    fn check_pattern_const(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Or(pat1, pat2) => {
+                self.check_pattern_const(pat1);
+                self.check_pattern_const(pat2);
+            }
+            Pattern::Guard {
+                pattern, condition, ..
+            } => {
+                self.check_pattern_const(pattern);
+                self.check_expr_const(condition);
+            }
+            _ => skip!(),
         }
     }
 
@@ -200,6 +460,11 @@ impl<'file> Parser<'file> {
         }
     }
 
+    /// Takes doc comment, attached to the current token
+    pub(crate) fn doc(&mut self) -> Option<EcoString> {
+        self.docs.remove(&(self.current as usize))
+    }
+
     /// Check `self.current >= self.tokens.len()`
     pub(crate) fn is_at_end(&self) -> bool {
         self.current as usize >= self.tokens.len()
@@ -7,6 +7,14 @@ use watt_lex::tokens::TokenKind;
 /// Implementation of statements parsing
 impl<'file> Parser<'file> {
     /// Packs compound assignment
+    ///
+    /// `a += b` is desugared into `a = a + b`.
+    ///
+    /// # Notes
+    /// The target expression is duplicated into the right-hand side,
+    /// so for field targets like `a.b.c += 1` the container `a.b`
+    /// is evaluated twice. For simple variables it's fine.
+    ///
     fn compound_assignment(
         &mut self,
         op: BinaryOp,
@@ -31,10 +39,12 @@ impl<'file> Parser<'file> {
     /// Assignment parsing
     fn assignment(&mut self, address: Address, variable: Expression) -> Statement {
         match variable {
-            Expression::Call { location, .. } => bail!(ParseError::InvalidAssignmentOperation {
-                src: location.source,
-                span: location.span.into()
-            }),
+            Expression::Call { location, .. } | Expression::OptionalChain { location, .. } => {
+                bail!(ParseError::InvalidAssignmentOperation {
+                    src: location.source,
+                    span: location.span.into()
+                })
+            }
             _ => {
                 let op = self.advance().clone();
                 match op.tk_type {
@@ -60,6 +70,9 @@ impl<'file> Parser<'file> {
                     TokenKind::DivAssign => {
                         self.compound_assignment(BinaryOp::Div, address, variable)
                     }
+                    TokenKind::ModAssign => {
+                        self.compound_assignment(BinaryOp::Mod, address, variable)
+                    }
                     TokenKind::AndAssign => {
                         self.compound_assignment(BinaryOp::And, address, variable)
                     }
@@ -79,6 +92,57 @@ impl<'file> Parser<'file> {
         }
     }
 
+    /// Parallel assignment parsing
+    ///
+    /// `a, b = b, a` evaluates all values
+    /// first, then assigns them to the targets.
+    ///
+    fn parallel_assignment(&mut self, variable: Expression) -> Statement {
+        // `$target, $target, ...`
+        let span_start = variable.location();
+        let mut what = vec![variable];
+        while self.check(TokenKind::Comma) {
+            self.consume(TokenKind::Comma);
+            what.push(self.variable());
+        }
+        for target in &what {
+            if let Expression::Call { location, .. } | Expression::OptionalChain { location, .. } =
+                target
+            {
+                bail!(ParseError::InvalidAssignmentOperation {
+                    src: location.source.clone(),
+                    span: location.span.clone().into()
+                })
+            }
+        }
+
+        // `= $value, $value, ...`
+        self.consume(TokenKind::Assign);
+        let mut value = vec![self.expr()];
+        while self.check(TokenKind::Comma) {
+            self.consume(TokenKind::Comma);
+            value.push(self.expr());
+        }
+        let span_end = self.previous().address.clone();
+        let location = span_start + span_end;
+
+        // checking values amount
+        if what.len() != value.len() {
+            bail!(ParseError::ParallelAssignmentMismatch {
+                src: location.source,
+                span: location.span.into(),
+                targets: what.len(),
+                values: value.len()
+            })
+        }
+
+        Statement::ParallelAssign {
+            location,
+            what,
+            value,
+        }
+    }
+
     /// Let statement parsing
     fn let_stmt(&mut self) -> Statement {
         // `let $id`
@@ -141,6 +205,32 @@ impl<'file> Parser<'file> {
         }
     }
 
+    /// Throw statement parsing
+    fn throw_stmt(&mut self) -> Statement {
+        let start_location = self.consume(TokenKind::Throw).address.clone();
+        let value = self.expr();
+        let end_location = self.previous().address.clone();
+
+        Statement::Throw {
+            location: start_location + end_location,
+            value,
+        }
+    }
+
+    /// Labeled block statement parsing
+    fn labeled_stmt(&mut self) -> Statement {
+        let label = self.consume(TokenKind::Label).clone();
+        self.consume(TokenKind::Colon);
+        let body = self.block();
+        let end_location = self.previous().address.clone();
+
+        Statement::Labeled {
+            location: label.address + end_location,
+            label: label.value,
+            body,
+        }
+    }
+
     /// Expression statement parsing
     fn expr_statement(&mut self) -> Statement {
         let expr = self.expr();
@@ -153,12 +243,14 @@ impl<'file> Parser<'file> {
 
     /// Is statement requires semicolon
     fn statement_requires_semi(&self, stmt: &Statement) -> bool {
-        match stmt {
-            Statement::Loop { .. } => false,
-            Statement::For { .. } => false,
-            Statement::Expr(Expression::If { .. }) => false,
-            _ => true,
-        }
+        !matches!(
+            stmt,
+            Statement::Loop { .. }
+                | Statement::For { .. }
+                | Statement::Labeled { .. }
+                | Statement::Expr(Expression::If { .. })
+                | Statement::Expr(Expression::TryCatch { .. })
+        )
     }
 
     /// Identifier statement
@@ -178,7 +270,10 @@ impl<'file> Parser<'file> {
             | TokenKind::DivAssign
             | TokenKind::MulAssign
             | TokenKind::SubAssign
+            | TokenKind::ModAssign
             | TokenKind::Assign => self.assignment(start + end, variable),
+            // if found comma, parsing parallel assignment
+            TokenKind::Comma => self.parallel_assignment(variable),
             // if not, recovering to `recovert_point` and parsing expr-statement
             _ => {
                 self.current = recover_point;
@@ -194,6 +289,8 @@ impl<'file> Parser<'file> {
             TokenKind::Loop => self.loop_stmt(),
             TokenKind::For => self.for_stmt(),
             TokenKind::Let => self.let_stmt(),
+            TokenKind::Throw => self.throw_stmt(),
+            TokenKind::Label => self.labeled_stmt(),
             TokenKind::Id => self.id_stmt(),
             _ => self.expr_statement(),
         };
@@ -0,0 +1,52 @@
+/// Imports
+use crate::errors::PackageError;
+use camino::{Utf8Path, Utf8PathBuf};
+use console::style;
+use std::fs;
+use watt_common::bail;
+
+/// Finds project root, the nearest
+/// directory containing `watt.toml`
+fn find_root(path: &Utf8Path) -> Utf8PathBuf {
+    match path.ancestors().find(|dir| dir.join("watt.toml").exists()) {
+        Some(root) => root.to_path_buf(),
+        None => bail!(PackageError::FailedToFindConfig {
+            path: path.to_path_buf()
+        }),
+    }
+}
+
+/// Removes `.cache` and `target` directories of the project.
+///
+/// # Notes
+/// Workspace members share `.cache` of the workspace root with
+/// the dependencies, so cleaning the member removes only its own
+/// `.cache` with modules hashes and its `target`.
+/// If `dry_run` is set, only prints what would be removed.
+///
+pub fn clean(path: &Utf8PathBuf, dry_run: bool) {
+    // Project root
+    let root = find_root(path);
+    // Removing directories
+    let mut cleaned = false;
+    for dir in [root.join(".cache"), root.join("target")] {
+        if !dir.exists() {
+            continue;
+        }
+        if dry_run {
+            println!("{} Would remove: {dir}", style("[🧹]").bold().yellow());
+        } else {
+            if let Err(error) = fs::remove_dir_all(&dir) {
+                bail!(PackageError::FailedToRemoveDirectory {
+                    path: dir,
+                    error: error.to_string()
+                })
+            }
+            println!("{} Removed: {dir}", style("[✓]").bold().green());
+        }
+        cleaned = true;
+    }
+    if !cleaned {
+        println!("{} Nothing to clean.", style("[✓]").bold().green());
+    }
+}
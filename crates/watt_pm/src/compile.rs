@@ -1,66 +1,123 @@
 /// Imports
 use crate::{
-    config::{self, WattConfig},
+    config::{self, PackageType},
     dependencies::{self, Package},
     errors::PackageError,
+    lock,
     runtime::JsRuntime,
     url::path_to_pkg_name,
 };
 use camino::{Utf8Path, Utf8PathBuf};
 use console::style;
-use std::process::Command;
+use std::{fs, process::Command};
 use tracing::info;
 use watt_common::{
     bail,
     package::{DraftPackage, DraftPackageLints},
-    skip,
 };
 use watt_compile::{
     io,
     project::{Built, ProjectCompiler},
 };
 
-/// Runs using runtime
-fn run_by_rt(index: Utf8PathBuf, rt: JsRuntime) {
+/// Runs using runtime,
+/// returns exit code of the runtime process
+fn run_by_rt(index: Utf8PathBuf, rt: JsRuntime) -> i32 {
     println!(
         "{} Preparing for {rt:?} runtime...",
         style("[📌]").bold().red()
     );
-    match rt {
+    // Preparing runtime command,
+    // stdout and stderr are inherited from the current process
+    let mut command = match rt {
         JsRuntime::Deno => {
-            // `deno init $path`
-            if let Err(error) = Command::new("deno").args(["run", index.as_str()]).status() {
-                bail!(PackageError::FailedToRunProject {
-                    rt,
-                    error: error.to_string()
-                })
-            }
+            // `deno run --allow-read $index`
+            let mut command = Command::new("deno");
+            command.args(["run", "--allow-read", index.as_str()]);
+            command
         }
         JsRuntime::Node => {
-            // `npm init -y` in target path
-            if let Err(error) = Command::new("node").args([index.as_str()]).status() {
-                bail!(PackageError::FailedToRunProject {
-                    rt,
-                    error: error.to_string()
-                })
-            }
+            // `node $index`
+            let mut command = Command::new("node");
+            command.arg(index.as_str());
+            command
         }
         JsRuntime::Bun => {
-            // `bun init -y` in target path
-            if let Err(error) = Command::new("bun").arg(index.as_str()).status() {
-                bail!(PackageError::FailedToRunProject {
-                    rt,
-                    error: error.to_string()
-                })
-            }
+            // `bun run $index`
+            let mut command = Command::new("bun");
+            command.args(["run", index.as_str()]);
+            command
         }
-        JsRuntime::Common => skip!(),
+        JsRuntime::Common => bail!(PackageError::RuntimeCouldNotRun { rt }),
+    };
+    // Running
+    match command.status() {
+        // Process could be terminated by a signal,
+        // so there is no exit code
+        Ok(status) => status.code().unwrap_or(1),
+        Err(error) => bail!(PackageError::FailedToRunProject {
+            rt,
+            error: error.to_string()
+        }),
+    }
+}
+
+/// Returns path to the file, where codegen
+/// options of the last compilation are recorded
+fn options_path(path: &Utf8PathBuf) -> Utf8PathBuf {
+    path.join(".cache").join("options")
+}
+
+/// Renders codegen options of the compilation
+fn options(dce: bool) -> &'static str {
+    if dce { "dce" } else { "no-dce" }
+}
+
+/// Checks that compiled project is stale,
+/// comparing modification time of the `index.js` with the
+/// modification time of the sources and `watt.toml` of the
+/// package and its local dependencies, the workspace `watt.toml`
+/// and `watt.lock`
+///
+/// Project is also stale, if it was compiled
+/// with other codegen options, like `dce`.
+fn is_stale(path: &Utf8PathBuf, index: &Utf8PathBuf, dce: bool) -> bool {
+    // Checking codegen options
+    if fs::read_to_string(options_path(path)).ok().as_deref() != Some(options(dce)) {
+        return true;
     }
+    // Retrieves file modification time
+    let modified = |path: &Utf8PathBuf| fs::metadata(path).and_then(|meta| meta.modified());
+    // Retrieving `index.js` modification time
+    let compiled = match modified(index) {
+        Ok(time) => time,
+        Err(_) => return true,
+    };
+    // Checking sources and configs of the packages,
+    // lockfile and workspace config could be missing
+    let packages = dependencies::local_packages(path);
+    let lock = lock::lock_path(path);
+    let workspace = config::find_workspace(path).map(|(root, _)| root.join("watt.toml"));
+    packages
+        .iter()
+        .flat_map(|package| {
+            io::collect_sources(package)
+                .iter()
+                .map(|file| file.path().clone())
+                .chain(std::iter::once(package.join("watt.toml")))
+                .collect::<Vec<Utf8PathBuf>>()
+        })
+        .chain(Some(lock).filter(|lock| lock.exists()))
+        .chain(workspace)
+        .any(|source| match modified(&source) {
+            Ok(time) => time > compiled,
+            Err(_) => true,
+        })
 }
 
 /// Check for the main function
 /// existence and correctness in the module
-fn check_for_main_fn(built: &Built, project_path: &Utf8PathBuf, config: &WattConfig) {
+fn check_for_main_fn(built: &Built, project_path: &Utf8PathBuf, main_module_name: &str) {
     // Retrieving main package from completed packages
     let main_package = match built
         .compiled
@@ -73,14 +130,6 @@ fn check_for_main_fn(built: &Built, project_path: &Utf8PathBuf, config: &WattCon
         }),
     };
 
-    // Retrieving main module name from config
-    let main_module_name = match &config.pkg.main {
-        Some(m) => m.clone(),
-        None => bail!(PackageError::NoMainModuleFoundSpecified {
-            path: project_path.clone()
-        }),
-    };
-
     // Retrieving main module with $main_module_name
     // from the main package, checking for module existence
     let main_module = match main_package
@@ -90,7 +139,7 @@ fn check_for_main_fn(built: &Built, project_path: &Utf8PathBuf, config: &WattCon
     {
         Some(m) => m,
         None => bail!(PackageError::NoMainModuleFound {
-            module: main_module_name.clone()
+            module: main_module_name.to_string()
         }),
     };
 
@@ -102,24 +151,14 @@ fn check_for_main_fn(built: &Built, project_path: &Utf8PathBuf, config: &WattCon
         .contains_key("main")
     {
         bail!(PackageError::NoMainFnFound {
-            module: main_module_name.clone()
+            module: main_module_name.to_string()
         });
     }
 }
 
 /// Writes `index.js`
 /// returns path to it
-fn write_index(
-    project_path: Utf8PathBuf,
-    target_path: &Utf8PathBuf,
-    config: &WattConfig,
-) -> Utf8PathBuf {
-    // Retrieving main module name from config
-    let main_module_name = match &config.pkg.main {
-        Some(m) => m.clone(),
-        None => bail!(PackageError::NoMainModuleFoundSpecified { path: project_path }),
-    };
-
+fn write_index(target_path: &Utf8PathBuf, main_module_name: String) -> Utf8PathBuf {
     // Generating `index.js`
     let mut index_path = Utf8PathBuf::from(target_path);
     index_path.push(Utf8Path::new("index.js"));
@@ -135,24 +174,32 @@ fn write_index(
 
 /// Compiles project to js
 /// returns path to `index.js`
-pub fn compile(path: Utf8PathBuf) -> Utf8PathBuf {
+///
+/// If `dce` is true, unreachable private
+/// functions are omitted from the generated code.
+/// If `update` is true, git dependencies are
+/// resolved again, ignoring `watt.lock`.
+///
+pub fn compile(path: Utf8PathBuf, dce: bool, update: bool) -> Utf8PathBuf {
     // Cache path
-    let mut cache_path = path.clone();
-    cache_path.push(".cache");
+    let cache_path = dependencies::cache_path(&path);
     // Config
     let config = config::retrieve_config(&path);
+    // Main module
+    let main = config::retrieve_main(&path, &config);
     // Retrieving project name
     let name = path_to_pkg_name(&path);
     info!("Crawled project name {name} from {path}.");
     // Getting toposorted packages
     println!("{} Resolving packages...", style("[🔍]").bold().cyan());
     let resolved = dependencies::solve(
-        cache_path,
+        cache_path.clone(),
         Package {
-            name: name,
+            name,
             path: path.clone(),
         },
         &config.pkg,
+        update,
     );
     println!("{} Packages resolved.", style("[✓]").bold().cyan());
     info!("Resolved packages: {resolved:?}");
@@ -163,9 +210,15 @@ pub fn compile(path: Utf8PathBuf) -> Utf8PathBuf {
             let config = config::retrieve_config(&pkg.path);
             // Generating draft package
             DraftPackage {
+                main: if pkg.path == path {
+                    Some(main.clone())
+                } else {
+                    None
+                },
                 path: pkg.path,
                 lints: DraftPackageLints {
                     disabled: config.lints.disabled,
+                    enabled: config.lints.enabled,
                 },
             }
         })
@@ -178,14 +231,21 @@ pub fn compile(path: Utf8PathBuf) -> Utf8PathBuf {
         target_path.push("target");
         target_path
     };
+    // Modules cache path, hashes are kept in `.cache` of the package
+    // itself, even inside of the workspace, since modules are generated
+    // into the package own `target`
+    let modules_cache_path = path.join(".cache");
     // Compiling
     println!("{} Compiling...", style("[🚚]").bold().yellow());
-    let mut pcx = ProjectCompiler::new(packages, &target_path);
+    let mut pcx = ProjectCompiler::new(packages, &target_path, &modules_cache_path, dce);
     let built = pcx.compile();
     // Checking for main function
-    check_for_main_fn(&built, &path, &config);
+    check_for_main_fn(&built, &path, &main);
     // Writing `index.js`
-    let index_path = write_index(path, &target_path, &config);
+    let index_path = write_index(&target_path, main);
+    // Recording codegen options
+    io::mkdir_all(&modules_cache_path);
+    io::write(&options_path(&path), options(dce));
     // Done
     println!("{} Done.", style("[✓]").bold().yellow());
     index_path
@@ -195,8 +255,7 @@ pub fn compile(path: Utf8PathBuf) -> Utf8PathBuf {
 /// returns path to `index.js`
 pub fn analyze(path: Utf8PathBuf) {
     // Cache path
-    let mut cache_path = path.clone();
-    cache_path.push(".cache");
+    let cache_path = dependencies::cache_path(&path);
 
     // Config
     let config = config::retrieve_config(&path);
@@ -210,14 +269,21 @@ pub fn analyze(path: Utf8PathBuf) {
     let resolved = dependencies::solve(
         cache_path.clone(),
         Package {
-            name: name,
+            name,
             path: path.clone(),
         },
         &config.pkg,
+        false,
     );
     println!("{} Packages resolved.", style("[✓]").bold().cyan());
     info!("Resolved packages: {resolved:?}");
 
+    // Main module, library has no one
+    let main = match config.pkg.pkg {
+        PackageType::App => Some(config::retrieve_main(&path, &config)),
+        PackageType::Lib => None,
+    };
+
     // Packages paths
     let packages = {
         resolved.into_iter().map(|pkg| {
@@ -225,9 +291,11 @@ pub fn analyze(path: Utf8PathBuf) {
             let config = config::retrieve_config(&pkg.path);
             // Generating draft package
             DraftPackage {
+                main: if pkg.path == path { main.clone() } else { None },
                 path: pkg.path,
                 lints: DraftPackageLints {
                     disabled: config.lints.disabled,
+                    enabled: config.lints.enabled,
                 },
             }
         })
@@ -243,16 +311,39 @@ pub fn analyze(path: Utf8PathBuf) {
     };
 
     println!("{} Checking...", style("[🔍]").bold().yellow());
-    let mut project_compiler = ProjectCompiler::new(packages, &target_path);
+    let mut project_compiler = ProjectCompiler::new(packages, &target_path, &cache_path, false);
     project_compiler.analyze();
 
     println!("{} Done.", style("[✓]").bold().yellow());
 }
 
-/// Runs project
-pub fn run(path: Utf8PathBuf, rt: JsRuntime) {
-    // Compiling project
-    let index_path = compile(path);
+/// Compiles project, if it's stale,
+/// returns path to the `index.js`
+pub fn build(path: Utf8PathBuf, dce: bool, update: bool) -> Utf8PathBuf {
+    // Path to `index.js`
+    let index_path = path.join("target").join("index.js");
+    // Compiling project, if needed
+    if update || is_stale(&path, &index_path, dce) {
+        compile(path, dce, update)
+    } else {
+        println!("{} Project is up to date.", style("[✓]").bold().yellow());
+        index_path
+    }
+}
+
+/// Runs project,
+/// returns exit code of the runtime process
+pub fn run(path: Utf8PathBuf, rt: JsRuntime, no_compile: bool, dce: bool, update: bool) -> i32 {
+    // Path to `index.js`
+    let index_path = if no_compile {
+        let index_path = path.join("target").join("index.js");
+        if !index_path.exists() {
+            bail!(PackageError::NoCompiledProjectFound { path: index_path })
+        }
+        index_path
+    } else {
+        build(path, dce, update)
+    };
     // Running it
-    run_by_rt(index_path, rt);
+    run_by_rt(index_path, rt)
 }
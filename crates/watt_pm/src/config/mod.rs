@@ -3,7 +3,8 @@ use crate::errors::PackageError;
 use camino::Utf8PathBuf;
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
-use std::fs;
+use std::{collections::BTreeMap, fs};
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
 use watt_common::bail;
 use watt_compile::io;
 
@@ -17,7 +18,7 @@ pub enum PackageType {
 }
 
 /// Package dependency
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum PackageDependency {
     /// Local dependency
@@ -41,6 +42,8 @@ pub struct PackageConfig {
 #[allow(dead_code)]
 pub struct LintsConfig {
     pub disabled: Vec<String>,
+    #[serde(default)]
+    pub enabled: Vec<String>,
 }
 
 /// watt.toml
@@ -48,13 +51,32 @@ pub struct LintsConfig {
 pub struct WattConfig {
     pub pkg: PackageConfig,
     pub lints: LintsConfig,
+    /// Custom shell commands, ran by `watt run-script $name`,
+    /// sorted by name, so saved config is stable
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scripts: BTreeMap<String, String>,
+}
+
+/// Workspace config
+#[derive(Deserialize, Serialize)]
+pub struct WorkspaceConfig {
+    pub members: Vec<String>,
+}
+
+/// watt.toml of the workspace root
+#[derive(Deserialize)]
+struct WattWorkspace {
+    workspace: WorkspaceConfig,
 }
 
 /// Parses config
 pub fn parse(path: &Utf8PathBuf, text: String) -> WattConfig {
     match toml::from_str(&text) {
         Ok(cfg) => cfg,
-        Err(e) => bail!(PackageError::FailedToParseConfig { path: path.clone(), reason: e }),
+        Err(e) => bail!(PackageError::FailedToParseConfig {
+            path: path.clone(),
+            reason: e
+        }),
     }
 }
 
@@ -67,15 +89,186 @@ pub fn locate(path: &Utf8PathBuf) -> Result<String, PackageError> {
     }
 }
 
+/// Parses workspace config,
+/// returns `None` if there's no `[workspace]` table
+pub fn parse_workspace(path: &Utf8PathBuf, text: &str) -> Option<WorkspaceConfig> {
+    // Checking for `[workspace]` table
+    match toml::from_str::<toml::Table>(text) {
+        Ok(table) if table.contains_key("workspace") => {}
+        _ => return None,
+    }
+    // Parsing workspace
+    match toml::from_str::<WattWorkspace>(text) {
+        Ok(cfg) => Some(cfg.workspace),
+        Err(e) => bail!(PackageError::FailedToParseConfig {
+            path: path.clone(),
+            reason: e
+        }),
+    }
+}
+
 /// Locates and parses config
 pub fn retrieve_config(path: &Utf8PathBuf) -> WattConfig {
-    parse(
-        path,
-        match locate(path) {
-            Ok(text) => text,
-            Err(error) => bail!(error),
-        },
-    )
+    let text = match locate(path) {
+        Ok(text) => text,
+        Err(error) => bail!(error),
+    };
+    // Workspace root is not a package
+    if parse_workspace(path, &text).is_some() {
+        bail!(PackageError::WorkspaceRootIsNotAPackage { path: path.clone() })
+    }
+    parse(path, text)
+}
+
+/// Locates and parses workspace config,
+/// returns `None` if `path` is not a workspace root
+pub fn retrieve_workspace(path: &Utf8PathBuf) -> Option<WorkspaceConfig> {
+    match locate(path) {
+        Ok(text) => parse_workspace(path, &text),
+        Err(_) => None,
+    }
+}
+
+/// Finds workspace, that package at `path` is member of.
+///
+/// Walks up from the `path` and returns the first
+/// workspace root, which members contain the package.
+///
+pub fn find_workspace(path: &Utf8PathBuf) -> Option<(Utf8PathBuf, WorkspaceConfig)> {
+    let package = path.canonicalize_utf8().ok()?;
+    package.ancestors().skip(1).find_map(|root| {
+        let root = root.to_path_buf();
+        let workspace = retrieve_workspace(&root)?;
+        let is_member = workspace.members.iter().any(|member| {
+            root.join(member)
+                .canonicalize_utf8()
+                .is_ok_and(|member| member == package)
+        });
+        if is_member {
+            Some((root, workspace))
+        } else {
+            None
+        }
+    })
+}
+
+/// Retrieves main module name of the package at `path`,
+/// `$name/main` is used, if it's not specified.
+///
+/// Checks that main module resolves to
+/// an existing `.wt` file of the package.
+///
+pub fn retrieve_main(path: &Utf8PathBuf, config: &WattConfig) -> String {
+    // Retrieving main module name
+    let main = match &config.pkg.main {
+        Some(main) => main.clone(),
+        None => format!("{}/main", config.pkg.name),
+    };
+
+    // Checking main module file existence
+    if !path.join(format!("{main}.wt")).is_file() {
+        bail!(PackageError::MainNotFound {
+            name: config.pkg.name.clone(),
+            main
+        })
+    }
+
+    main
+}
+
+/// Saves config
+/// into `watt.toml` file in `path`
+///
+/// Existing `watt.toml` is edited in place, so comments
+/// and formatting of the unchanged entries are preserved.
+///
+pub fn save(path: &Utf8PathBuf, config: &WattConfig) {
+    let serialized = match toml::to_string(config) {
+        Ok(text) => text,
+        Err(_) => bail!(PackageError::FailedToSerializeConfig { path: path.into() }),
+    };
+    let document = match serialized.parse::<DocumentMut>() {
+        Ok(document) => document,
+        Err(_) => bail!(PackageError::FailedToSerializeConfig { path: path.into() }),
+    };
+
+    let config_path = path.join("watt.toml");
+    let existing = fs::read_to_string(&config_path)
+        .ok()
+        .and_then(|text| text.parse::<DocumentMut>().ok());
+    match existing {
+        Some(mut existing) => {
+            merge_table(existing.as_table_mut(), document.as_table());
+            io::write(&config_path, &existing.to_string());
+        }
+        None => io::write(&config_path, &serialized),
+    }
+}
+
+/// Merges `new` table into the `existing` one,
+/// keeping decor of the existing entries
+fn merge_table(existing: &mut Table, new: &Table) {
+    existing.retain(|key, _| new.contains_key(key));
+    for (key, item) in new.iter() {
+        match (existing.get_mut(key), item) {
+            (Some(Item::Table(old)), Item::Table(new)) => merge_table(old, new),
+            (Some(Item::Value(old)), Item::Value(new)) => merge_value(old, new),
+            _ => {
+                existing.insert(key, item.clone());
+            }
+        }
+    }
+}
+
+/// Replaces `existing` value with the `new` one, if they differ,
+/// keeping decor of the value and of the unchanged array items
+fn merge_value(existing: &mut Value, new: &Value) {
+    if same_value(existing, new) {
+        return;
+    }
+    let decor = existing.decor().clone();
+    *existing = match (&*existing, new) {
+        (Value::Array(old), Value::Array(new)) => {
+            let mut array = Array::new();
+            for value in new.iter() {
+                match old.iter().find(|item| same_value(item, value)) {
+                    Some(item) => array.push_formatted(item.clone()),
+                    None => {
+                        let mut value = value.clone();
+                        if let Some(first) = old.get(0) {
+                            *value.decor_mut() = first.decor().clone();
+                        }
+                        array.push_formatted(value)
+                    }
+                }
+            }
+            array.set_trailing(old.trailing().clone());
+            array.set_trailing_comma(old.trailing_comma());
+            Value::Array(array)
+        }
+        _ => new.clone(),
+    };
+    *existing.decor_mut() = decor;
+}
+
+/// Checks values are same, ignoring their decor
+fn same_value(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| same_value(a, b))
+        }
+        (Value::InlineTable(a), Value::InlineTable(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| same_value(a, b)))
+        }
+        _ => {
+            let (mut a, mut b) = (a.clone(), b.clone());
+            a.decor_mut().clear();
+            b.decor_mut().clear();
+            a.to_string() == b.to_string()
+        }
+    }
 }
 
 /// Generates config
@@ -91,16 +284,14 @@ pub fn generate(path: &Utf8PathBuf, name: &str, ty: PackageType, main: Option<St
                     main,
                     dependencies: vec![],
                 },
-                lints: LintsConfig { disabled: vec![] },
-            };
-            
-            let serialized = match toml::to_string(&config) {
-                Ok(text) => text,
-                Err(_) => bail!(PackageError::FailedToSerializeConfig { path: path.into() }),
+                lints: LintsConfig {
+                    disabled: vec![],
+                    enabled: vec![],
+                },
+                scripts: BTreeMap::new(),
             };
 
-            let config_path = path.join("watt.toml");
-            io::write(&config_path, &serialized);
+            save(path, &config);
         }
     }
 }
@@ -2,11 +2,12 @@
 use crate::{
     config::{self, PackageConfig, PackageDependency, PackageType},
     errors::PackageError,
+    lock::{self, Lockfile},
     url::{path_to_pkg_name, url_to_pkg_name},
 };
 use camino::Utf8PathBuf;
 use console::style;
-use git2::Repository;
+use git2::{Oid, Repository, build::CheckoutBuilder};
 use petgraph::{Direction, prelude::DiGraphMap};
 use std::collections::{HashMap, HashSet};
 use tracing::{debug, info};
@@ -98,6 +99,49 @@ fn toposort<'s>(deps: HashMap<&'s Package, Vec<&'s Package>>) -> Vec<&'s Package
     }
 }
 
+/// Returns `.cache` folder path of the package at `path`,
+/// where git dependencies are downloaded.
+///
+/// Members of the workspace share the
+/// `.cache` folder of the workspace root.
+///
+pub fn cache_path(path: &Utf8PathBuf) -> Utf8PathBuf {
+    match config::find_workspace(path) {
+        Some((root, _)) => root.join(".cache"),
+        None => path.join(".cache"),
+    }
+}
+
+/// Resolves local dependency path
+/// relative to the dependent package path
+fn local_path(package: &Utf8PathBuf, path: &str) -> Utf8PathBuf {
+    let path = package.join(path);
+    path.canonicalize_utf8().unwrap_or(path)
+}
+
+/// Returns paths of the package at `path` and of its
+/// local dependencies, including workspace members,
+/// collected transitively. Git dependencies are skipped,
+/// since they are pinned by the lockfile.
+///
+pub fn local_packages(path: &Utf8PathBuf) -> Vec<Utf8PathBuf> {
+    let mut packages = vec![path.clone()];
+    let mut current = 0;
+    while current < packages.len() {
+        let config = config::retrieve_config(&packages[current]);
+        for dependency in &config.pkg.dependencies {
+            if let PackageDependency::Local { path } = dependency {
+                let path = local_path(&packages[current], path);
+                if !packages.contains(&path) {
+                    packages.push(path);
+                }
+            }
+        }
+        current += 1;
+    }
+    packages
+}
+
 /// Download dependency to cache,
 /// If not already downloaded
 ///
@@ -140,18 +184,110 @@ pub fn download(url: &String, cache: Utf8PathBuf) -> Package {
     }
 }
 
+/// Fetches repository from `origin` remote
+fn fetch(repo: &Repository) -> Result<(), git2::Error> {
+    repo.find_remote("origin")?
+        .fetch(&[] as &[&str], None, None)
+}
+
+/// Checks out commit of the repository, detaching head
+fn checkout_commit(repo: &Repository, oid: Oid) -> Result<(), git2::Error> {
+    let commit = repo.find_commit(oid)?;
+    repo.checkout_tree(commit.as_object(), Some(CheckoutBuilder::new().force()))?;
+    repo.set_head_detached(oid)
+}
+
+/// Checks out locked commit of the downloaded git dependency.
+///
+/// If `locked` is `None`, refs are resolved again:
+/// repository is fetched and the latest commit of
+/// the `origin` is checked out. Dependency, that is not
+/// locked, stays at the downloaded commit.
+///
+/// Returns hash of the checked out commit.
+///
+fn checkout(url: &String, pkg: &Package, locked: Option<&Lockfile>) -> String {
+    // Opening repository
+    let repo = match Repository::open(&pkg.path) {
+        Ok(repo) => repo,
+        Err(e) => bail!(PackageError::FailedToCheckoutRepo {
+            url: url.clone(),
+            error: e.message().to_string()
+        }),
+    };
+    // Checking out commit
+    let result = match locked.map(|lock| lock.commit(url)) {
+        // Resolving refs again
+        None => {
+            info!("Updating repository {url}.");
+            fetch(&repo)
+                .and_then(|_| repo.refname_to_id("refs/remotes/origin/HEAD"))
+                .and_then(|oid| checkout_commit(&repo, oid))
+        }
+        // Locked commit
+        Some(Some(commit)) => {
+            let oid = match Oid::from_str(commit) {
+                Ok(oid) => oid,
+                Err(_) => bail!(PackageError::LockedCommitNotFound {
+                    url: url.clone(),
+                    commit: commit.to_string()
+                }),
+            };
+            // Fetching, if commit is not downloaded yet
+            if repo.find_commit(oid).is_err() {
+                info!("Locked commit {commit} of {url} is not downloaded, fetching.");
+                let _ = fetch(&repo);
+            }
+            if repo.find_commit(oid).is_err() {
+                bail!(PackageError::LockedCommitNotFound {
+                    url: url.clone(),
+                    commit: commit.to_string()
+                })
+            }
+            // Checking out, if head is not already at the commit
+            match repo.head().ok().and_then(|head| head.target()) {
+                Some(head) if head == oid => Ok(()),
+                _ => {
+                    info!("Checking out locked commit {commit} of {url}.");
+                    checkout_commit(&repo, oid)
+                }
+            }
+        }
+        // Not locked yet
+        Some(None) => Ok(()),
+    };
+    if let Err(e) = result {
+        bail!(PackageError::FailedToCheckoutRepo {
+            url: url.clone(),
+            error: e.message().to_string()
+        })
+    }
+    // Retrieving checked out commit
+    match repo.head().and_then(|head| head.peel_to_commit()) {
+        Ok(commit) => commit.id().to_string(),
+        Err(e) => bail!(PackageError::FailedToCheckoutRepo {
+            url: url.clone(),
+            error: e.message().to_string()
+        }),
+    }
+}
+
 /// Resolves packages,
 /// returns hash map of recursively solved modules.
 ///
 /// # Parameters
 /// - cache - `.cache` folder path
 /// - solved - already solved packages
+/// - locked - previous lockfile, `None` to resolve refs again
+/// - resolved - lockfile of the resolved git dependencies
 /// - package - package
 /// - config - package config
 ///
 fn resolve_packages<'solved>(
     cache: &Utf8PathBuf,
     solved: &'solved mut HashMap<Package, Vec<Package>>,
+    locked: Option<&Lockfile>,
+    resolved: &mut Lockfile,
     package: Package,
     config: &PackageConfig,
 ) -> &'solved mut HashMap<Package, Vec<Package>> {
@@ -163,7 +299,7 @@ fn resolve_packages<'solved>(
     else {
         info!("Resolving packages that {package:?} depends on.");
         debug!("Dependencies: {:?}", &config.dependencies);
-        
+
         // Inserting vector
         solved.insert(package.clone(), Vec::new());
         // Dependencies
@@ -173,7 +309,7 @@ fn resolve_packages<'solved>(
                 // Local dependency
                 PackageDependency::Local { path } => {
                     // Retrieving dependency config
-                    let path = Utf8PathBuf::from(path);
+                    let path = local_path(&package.path, path);
                     let pkg = Package {
                         name: path_to_pkg_name(&path),
                         path: path.clone(),
@@ -189,7 +325,7 @@ fn resolve_packages<'solved>(
                                 None => bail!(PackageError::NoSolvedKeyFound { key: pkg.name }),
                             }
                             // Resolving dependency packages
-                            resolve_packages(cache, solved, pkg, &pkg_config.pkg);
+                            resolve_packages(cache, solved, locked, resolved, pkg, &pkg_config.pkg);
                         }
                         PackageType::App => bail!(PackageError::UseOfAppPackageAsDependency {
                             name: pkg.name,
@@ -200,6 +336,9 @@ fn resolve_packages<'solved>(
                 PackageDependency::Git(dependency) => {
                     // Downloading dependency if not already downloaded
                     let pkg = download(dependency, cache.clone());
+                    // Checking out locked commit
+                    let commit = checkout(dependency, &pkg, locked);
+                    resolved.record(&pkg.name, dependency, commit);
                     let path = &pkg.path;
                     let pkg_config = config::retrieve_config(path);
                     info!("+ Found git dependency {} of {pkg:?}", &package.name);
@@ -212,7 +351,7 @@ fn resolve_packages<'solved>(
                                 None => bail!(PackageError::NoSolvedKeyFound { key: pkg.name }),
                             }
                             // Resolving dependency packages
-                            resolve_packages(cache, solved, pkg, &pkg_config.pkg);
+                            resolve_packages(cache, solved, locked, resolved, pkg, &pkg_config.pkg);
                         }
                         PackageType::App => bail!(PackageError::UseOfAppPackageAsDependency {
                             name: pkg.name,
@@ -226,13 +365,58 @@ fn resolve_packages<'solved>(
     }
 }
 
+/// Resolves dependencies,
+/// returns hash map of the packages
+/// and their direct dependencies
+///
+/// Git dependencies are checked out at commits, locked in
+/// `watt.lock` of the package. If `update` is true, refs
+/// are resolved again and the lockfile is rewritten.
+///
+fn resolve(
+    cache: Utf8PathBuf,
+    pkg: Package,
+    config: &PackageConfig,
+    update: bool,
+) -> HashMap<Package, Vec<Package>> {
+    // Lockfiles
+    let path = pkg.path.clone();
+    let locked = lock::read(&path);
+    let mut resolved = Lockfile::default();
+    // Solved packages
+    let packages = resolve_packages(
+        &cache,
+        &mut HashMap::new(),
+        if update { None } else { Some(&locked) },
+        &mut resolved,
+        pkg,
+        config,
+    )
+    .to_owned();
+    // Saving lockfile
+    if resolved != locked {
+        lock::save(&path, resolved);
+    }
+    packages
+}
+
 /// Solves dependencies,
 ///
 /// returns toposorted vector
 /// of packages
-pub fn solve(cache: Utf8PathBuf, pkg: Package, config: &PackageConfig) -> Vec<Package> {
+///
+/// Git dependencies are checked out at commits, locked in
+/// `watt.lock` of the package. If `update` is true, refs
+/// are resolved again and the lockfile is rewritten.
+///
+pub fn solve(
+    cache: Utf8PathBuf,
+    pkg: Package,
+    config: &PackageConfig,
+    update: bool,
+) -> Vec<Package> {
     // Solved packages
-    let packages = resolve_packages(&cache, &mut HashMap::new(), pkg, config).to_owned();
+    let packages = resolve(cache, pkg, config, update);
     // Toposorting
     toposort(
         packages
@@ -244,3 +428,203 @@ pub fn solve(cache: Utf8PathBuf, pkg: Package, config: &PackageConfig) -> Vec<Pa
     .map(|s| (*s).clone())
     .collect()
 }
+
+/// Renders dependencies of the `pkg` as a tree
+///
+/// # Parameters
+/// - cache - `.cache` folder path
+/// - packages - solved packages
+/// - dependents - amount of dependents of the packages
+/// - pkg - package to render
+/// - prefix - prefix of the package dependencies lines
+/// - rendered - already rendered packages
+/// - out - output
+///
+fn render_dependencies<'s>(
+    cache: &Utf8PathBuf,
+    packages: &'s HashMap<Package, Vec<Package>>,
+    dependents: &HashMap<&Package, usize>,
+    pkg: &'s Package,
+    prefix: &str,
+    rendered: &mut HashSet<&'s Package>,
+    out: &mut String,
+) {
+    let dependencies = match packages.get(pkg) {
+        Some(dependencies) => dependencies,
+        None => return,
+    };
+    for (i, dependency) in dependencies.iter().enumerate() {
+        let last = i + 1 == dependencies.len();
+        // Git dependencies are downloaded to the cache
+        let kind = if dependency.path.starts_with(cache) {
+            "git"
+        } else {
+            "local"
+        };
+        // Package, that is reachable via multiple paths
+        let shared = dependents.get(&dependency).copied().unwrap_or(0) > 1;
+        out.push_str(&format!(
+            "{prefix}{} {} [{kind}]{}\n",
+            if last { "└──" } else { "├──" },
+            dependency.name,
+            if shared { " (*)" } else { "" }
+        ));
+        // Dependencies of the shared package are rendered once
+        if rendered.insert(dependency) {
+            render_dependencies(
+                cache,
+                packages,
+                dependents,
+                dependency,
+                &format!("{prefix}{}", if last { "    " } else { "│   " }),
+                rendered,
+                out,
+            );
+        }
+    }
+}
+
+/// Resolves dependencies and renders them as a tree
+///
+/// Packages, that are reachable via multiple paths,
+/// are marked with `(*)`, their dependencies are
+/// rendered only once.
+///
+/// # Notes
+/// Dependencies are toposorted before rendering, so
+/// cycle in dependencies is reported as an error.
+///
+pub fn render_tree(
+    cache: Utf8PathBuf,
+    pkg: Package,
+    config: &PackageConfig,
+    update: bool,
+) -> String {
+    // Solved packages
+    let packages = resolve(cache.clone(), pkg.clone(), config, update);
+    // Checking for cycles
+    toposort(
+        packages
+            .iter()
+            .map(|(k, v)| (k, v.iter().collect()))
+            .collect::<HashMap<&Package, Vec<&Package>>>(),
+    );
+    // Counting dependents
+    let mut dependents: HashMap<&Package, usize> = HashMap::new();
+    for dependencies in packages.values() {
+        for dependency in dependencies {
+            *dependents.entry(dependency).or_default() += 1;
+        }
+    }
+    // Rendering
+    let mut out = format!("{}\n", pkg.name);
+    render_dependencies(
+        &cache,
+        &packages,
+        &dependents,
+        &pkg,
+        "",
+        &mut HashSet::from([&pkg]),
+        &mut out,
+    );
+    out
+}
+
+/// Prints dependencies tree
+/// of the project at `path`
+pub fn print_tree(path: &Utf8PathBuf, update: bool) {
+    // Project config
+    let config = config::retrieve_config(path);
+    // Rendering tree
+    let tree = render_tree(
+        cache_path(path),
+        Package {
+            name: path_to_pkg_name(path),
+            path: path.clone(),
+        },
+        &config.pkg,
+        update,
+    );
+    print!("{tree}");
+}
+
+/// Adds dependency to the project at `path`,
+/// downloading it to cache, if it's a git dependency.
+///
+/// `url` is treated as git url, if it could be parsed as url,
+/// otherwise it's treated as local path.
+///
+/// Inside of the workspace, `path` must be
+/// a member package, not the workspace root.
+///
+pub fn add(path: &Utf8PathBuf, url: String) {
+    // Project config
+    let mut config = config::retrieve_config(path);
+    // Dependency
+    let dependency = match Url::parse(&url) {
+        Ok(_) => PackageDependency::Git(url.clone()),
+        Err(_) => PackageDependency::Local { path: url.clone() },
+    };
+    // Checking dependency is not already added
+    if config.pkg.dependencies.contains(&dependency) {
+        bail!(PackageError::DependencyIsAlreadyAdded { url })
+    }
+    // Retrieving dependency package
+    let pkg = match &dependency {
+        PackageDependency::Local { path: dependency } => {
+            let path = local_path(path, dependency);
+            Package {
+                name: path_to_pkg_name(&path),
+                path,
+            }
+        }
+        PackageDependency::Git(url) => download(url, cache_path(path)),
+    };
+    let pkg_config = config::retrieve_config(&pkg.path);
+    // Checking it's an `lib` pkg
+    if let PackageType::App = pkg_config.pkg.pkg {
+        bail!(PackageError::UseOfAppPackageAsDependency {
+            name: pkg.name,
+            path: pkg.path
+        })
+    }
+    // Saving config
+    config.pkg.dependencies.push(dependency);
+    config::save(path, &config);
+    println!(
+        "{} Added: {}",
+        style("[✓]").bold().green(),
+        pkg_config.pkg.name
+    );
+}
+
+/// Removes dependency from the project at `path`
+///
+/// Locked commit of the dependency is removed from `watt.lock`
+/// too, locked commits of its own dependencies are pruned
+/// on the next resolution.
+///
+pub fn remove(path: &Utf8PathBuf, url: String) {
+    // Project config
+    let mut config = config::retrieve_config(path);
+    // Removing dependency
+    let amount = config.pkg.dependencies.len();
+    config
+        .pkg
+        .dependencies
+        .retain(|dependency| match dependency {
+            PackageDependency::Local { path } => path != &url,
+            PackageDependency::Git(git) => git != &url,
+        });
+    if config.pkg.dependencies.len() == amount {
+        bail!(PackageError::DependencyIsNotFound { url })
+    }
+    // Saving config
+    config::save(path, &config);
+    // Pruning locked commit
+    let mut locked = lock::read(path);
+    if locked.remove(&url) {
+        lock::save(path, locked);
+    }
+    println!("{} Removed: {url}", style("[✓]").bold().green());
+}
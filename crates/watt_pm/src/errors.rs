@@ -9,7 +9,10 @@ use thiserror::Error;
 pub enum PackageError {
     #[error("failed to parse `watt.toml` at `{path}`\n\n{reason}")]
     #[diagnostic(code(pkg::failed_to_parse_config))]
-    FailedToParseConfig { path: Utf8PathBuf, reason: toml::de::Error },
+    FailedToParseConfig {
+        path: Utf8PathBuf,
+        reason: toml::de::Error,
+    },
     #[error("failed to find `watt.toml` at `{path}`")]
     #[diagnostic(code(pkg::failed_to_find_config))]
     FailedToFindConfig { path: Utf8PathBuf },
@@ -56,6 +59,12 @@ pub enum PackageError {
     #[error("failed to run project using {rt:?}. error: {error}")]
     #[diagnostic(code(pkg::failed_to_run_project))]
     FailedToRunProject { rt: JsRuntime, error: String },
+    #[error("{rt:?} runtime could not run the project directly.")]
+    #[diagnostic(
+        code(pkg::runtime_could_not_run),
+        help("use `deno`, `node` or `bun` runtime to run the project.")
+    )]
+    RuntimeCouldNotRun { rt: JsRuntime },
     #[error("no main package with path {path} found.")]
     #[diagnostic(
         code(compile::no_main_package_found),
@@ -69,16 +78,80 @@ pub enum PackageError {
     #[error("no main function found in module \"{module}\" marked as main.")]
     #[diagnostic(code(pkg::no_main_function_found), help("define a main function."))]
     NoMainFnFound { module: String },
-    #[error("no main module specified in config {path}.")]
+    #[error("main module \"{main}\" of package \"{name}\" is not found.")]
     #[diagnostic(
-        code(pkg::no_main_module_specified),
-        help("please, specify the module in config.")
+        code(pkg::main_not_found),
+        help("check that `main` in `watt.toml` points to an existing `.wt` file.")
     )]
-    NoMainModuleFoundSpecified { path: Utf8PathBuf },
+    MainNotFound { name: String, main: String },
+    #[error("`watt.toml` at `{path}` is a workspace root, not a package.")]
+    #[diagnostic(
+        code(pkg::workspace_root_is_not_a_package),
+        help("run this command inside of the workspace member.")
+    )]
+    WorkspaceRootIsNotAPackage { path: Utf8PathBuf },
+    #[error("no compiled project found at {path}.")]
+    #[diagnostic(
+        code(pkg::no_compiled_project_found),
+        help("run without `--no-compile` to compile the project first.")
+    )]
+    NoCompiledProjectFound { path: Utf8PathBuf },
     #[error("failed to get project name from path {path}.")]
     #[diagnostic(code(pkg::failed_to_get_project_name_from_path))]
     FailedToGetProjectNameFromPath { path: Utf8PathBuf },
     #[error("could not use package \"{name}\" with package type \"app\" as dependency.")]
     #[diagnostic(code(pkg::use_of_app_package_as_dependency))]
     UseOfAppPackageAsDependency { name: String, path: Utf8PathBuf },
+    #[error("dependency \"{url}\" is already added.")]
+    #[diagnostic(code(pkg::dependency_is_already_added))]
+    DependencyIsAlreadyAdded { url: String },
+    #[error("dependency \"{url}\" is not found in `watt.toml`.")]
+    #[diagnostic(
+        code(pkg::dependency_is_not_found),
+        help("dependency should be specified the same way, as it was added.")
+    )]
+    DependencyIsNotFound { url: String },
+    #[error("failed to parse `watt.lock` at `{path}`\n\n{reason}")]
+    #[diagnostic(
+        code(pkg::failed_to_parse_lockfile),
+        help("remove `watt.lock` or run with `--update` to resolve dependencies again.")
+    )]
+    FailedToParseLockfile {
+        path: Utf8PathBuf,
+        reason: toml::de::Error,
+    },
+    #[error("failed to serialize lockfile located at `{path}`.")]
+    #[diagnostic(
+        code(pkg::failed_to_serialize_lockfile),
+        help("please, file an issue on github."),
+        url("https://github.com/watt-rs/watt")
+    )]
+    FailedToSerializeLockfile { path: Utf8PathBuf },
+    #[error("commit \"{commit}\" of \"{url}\" locked in `watt.lock` is not found.")]
+    #[diagnostic(
+        code(pkg::locked_commit_not_found),
+        help("run with `--update` to resolve dependencies again.")
+    )]
+    LockedCommitNotFound { url: String, commit: String },
+    #[error("failed to checkout repository \"{url}\". error: {error}")]
+    #[diagnostic(code(pkg::failed_to_checkout_repo))]
+    FailedToCheckoutRepo { url: String, error: String },
+    #[error("failed to remove directory {path}. error: {error}")]
+    #[diagnostic(code(pkg::failed_to_remove_directory))]
+    FailedToRemoveDirectory { path: Utf8PathBuf, error: String },
+    #[error("script \"{name}\" is not found in `watt.toml`.")]
+    #[diagnostic(
+        code(pkg::script_not_found),
+        help("scripts are specified in the `[scripts]` table.")
+    )]
+    ScriptNotFound { name: String },
+    #[error("failed to run script \"{name}\". error: {error}")]
+    #[diagnostic(code(pkg::failed_to_run_script))]
+    FailedToRunScript { name: String, error: String },
+    #[error("failed to watch {path} for changes. error: {error}")]
+    #[diagnostic(code(pkg::failed_to_watch))]
+    FailedToWatch { path: Utf8PathBuf, error: String },
+    #[error("found {count} unformatted file(s).")]
+    #[diagnostic(code(pkg::unformatted_files), help("run `watt fmt` to format them."))]
+    UnformattedFiles { count: usize },
 }
@@ -0,0 +1,36 @@
+/// Imports
+use crate::errors::PackageError;
+use camino::Utf8PathBuf;
+use console::style;
+use watt_common::bail;
+use watt_compile::io;
+
+/// Formats `.wt` sources of the project.
+///
+/// # Notes
+/// If `check` is set, files aren't rewritten. Instead,
+/// unformatted files are reported, and the error is raised,
+/// if any of them is found.
+///
+pub fn format(path: &Utf8PathBuf, check: bool) {
+    let mut unformatted = 0;
+    for file in io::collect_sources(path) {
+        let code = file.read();
+        let formatted = watt_fmt::format(file.path().as_str(), code.clone());
+        if formatted == code {
+            continue;
+        }
+        if check {
+            println!("{} Unformatted: {}", style("[!]").bold().red(), file.path());
+        } else {
+            io::write(file.path(), &formatted);
+            println!("{} Formatted: {}", style("[✓]").bold().green(), file.path());
+        }
+        unformatted += 1;
+    }
+    if unformatted == 0 {
+        println!("{} All files are formatted.", style("[✓]").bold().green());
+    } else if check {
+        bail!(PackageError::UnformattedFiles { count: unformatted })
+    }
+}
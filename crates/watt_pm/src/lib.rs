@@ -3,10 +3,15 @@
 #![allow(unused_assignments)]
 
 // Modules
+pub mod clean;
 pub mod compile;
 pub mod config;
 pub mod dependencies;
 mod errors;
+pub mod format;
 pub mod generate;
+pub mod lock;
 pub mod runtime;
+pub mod scripts;
 pub mod url;
+pub mod watch;
@@ -0,0 +1,101 @@
+/// Imports
+use crate::errors::PackageError;
+use camino::Utf8PathBuf;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use watt_common::bail;
+use watt_compile::io;
+
+/// Locked git dependency
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct LockedPackage {
+    /// Package name
+    pub name: String,
+    /// Repository url
+    pub url: String,
+    /// Resolved commit hash
+    pub commit: String,
+}
+
+/// watt.lock
+///
+/// Records exact commits of the git dependencies,
+/// so builds of the package are reproducible.
+///
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Lockfile {
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedPackage>,
+}
+
+/// Implementation
+impl Lockfile {
+    /// Finds locked commit of the git dependency by url
+    pub fn commit(&self, url: &str) -> Option<&str> {
+        self.packages
+            .iter()
+            .find(|package| package.url == url)
+            .map(|package| package.commit.as_str())
+    }
+
+    /// Records resolved commit of the git dependency
+    pub fn record(&mut self, name: &str, url: &str, commit: String) {
+        if self.commit(url).is_none() {
+            self.packages.push(LockedPackage {
+                name: name.to_string(),
+                url: url.to_string(),
+                commit,
+            });
+        }
+    }
+
+    /// Removes locked commit of the git dependency by url,
+    /// returns `true` if the dependency was locked
+    pub fn remove(&mut self, url: &str) -> bool {
+        let amount = self.packages.len();
+        self.packages.retain(|package| package.url != url);
+        self.packages.len() != amount
+    }
+}
+
+/// Returns `watt.lock` path of the package at `path`
+pub fn lock_path(path: &Utf8PathBuf) -> Utf8PathBuf {
+    path.join("watt.lock")
+}
+
+/// Reads lockfile of the package at `path`,
+/// returns empty lockfile, if it doesn't exist
+pub fn read(path: &Utf8PathBuf) -> Lockfile {
+    let lock_path = lock_path(path);
+    match fs::read_to_string(&lock_path) {
+        Ok(text) => match toml::from_str(&text) {
+            Ok(lock) => lock,
+            Err(e) => bail!(PackageError::FailedToParseLockfile {
+                path: lock_path,
+                reason: e
+            }),
+        },
+        Err(_) => Lockfile::default(),
+    }
+}
+
+/// Saves lockfile
+/// into `watt.lock` file in `path`
+///
+/// # Notes
+/// Packages are sorted by url, so lockfile is stable.
+/// Lockfile without packages is written only,
+/// if it already exists.
+///
+pub fn save(path: &Utf8PathBuf, mut lock: Lockfile) {
+    let lock_path = lock_path(path);
+    if lock.packages.is_empty() && !lock_path.exists() {
+        return;
+    }
+    lock.packages.sort_by(|a, b| a.url.cmp(&b.url));
+    let serialized = match toml::to_string(&lock) {
+        Ok(text) => text,
+        Err(_) => bail!(PackageError::FailedToSerializeLockfile { path: lock_path }),
+    };
+    io::write(&lock_path, &serialized);
+}
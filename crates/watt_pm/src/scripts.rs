@@ -0,0 +1,73 @@
+/// Imports
+use crate::{config, errors::PackageError};
+use camino::Utf8PathBuf;
+use console::style;
+use std::process::Command;
+use watt_common::bail;
+
+/// Prepares shell command, that runs `script`
+fn shell(script: &str) -> Command {
+    if cfg!(windows) {
+        // `cmd /C $script`
+        let mut command = Command::new("cmd");
+        command.args(["/C", script]);
+        command
+    } else {
+        // `sh -c $script`
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+}
+
+/// Runs script in the package directory,
+/// returns exit code of the shell process
+fn run_script(path: &Utf8PathBuf, name: &str, script: &str) -> i32 {
+    println!("{} {name}: {script}", style("[▶]").bold().magenta());
+    // Running, stdout and stderr are
+    // inherited from the current process
+    let mut command = shell(script);
+    command.current_dir(path);
+    match command.status() {
+        // Process could be terminated by a signal,
+        // so there is no exit code
+        Ok(status) => status.code().unwrap_or(1),
+        Err(error) => bail!(PackageError::FailedToRunScript {
+            name: name.to_string(),
+            error: error.to_string()
+        }),
+    }
+}
+
+/// Runs script `name` from the `[scripts]` table
+/// of the package at `path`, returns exit code.
+///
+/// # Notes
+/// `PRE_$name` and `POST_$name` scripts, if specified,
+/// are ran before and after the script. Running stops
+/// at the first script, that exits with non-zero code.
+///
+pub fn run(path: &Utf8PathBuf, name: &str) -> i32 {
+    let config = config::retrieve_config(path);
+    // Checking script existence
+    if !config.scripts.contains_key(name) {
+        bail!(PackageError::ScriptNotFound {
+            name: name.to_string()
+        })
+    }
+    // Running hooks and script
+    let names = [
+        format!("PRE_{name}"),
+        name.to_string(),
+        format!("POST_{name}"),
+    ];
+    for name in &names {
+        if let Some(script) = config.scripts.get(name) {
+            let code = run_script(path, name, script);
+            if code != 0 {
+                return code;
+            }
+        }
+    }
+    0
+}
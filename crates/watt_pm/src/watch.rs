@@ -0,0 +1,134 @@
+/// Imports
+use crate::{compile, errors::PackageError};
+use camino::{Utf8Path, Utf8PathBuf};
+use console::style;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::mpsc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tracing::info;
+use watt_common::bail;
+
+/// Debounce window of the file system events
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Checks that changed file affects the build:
+/// sources, `watt.toml` or `watt.lock`.
+///
+/// Files in the `target` and `.cache`
+/// directories are ignored, since they're
+/// written by the build itself.
+///
+fn is_relevant(root: &Utf8Path, path: &Path) -> bool {
+    let path = match Utf8Path::from_path(path) {
+        Some(path) => path,
+        None => return false,
+    };
+    // Ignoring generated and downloaded files
+    let ignored = path
+        .strip_prefix(root)
+        .ok()
+        .and_then(|relative| relative.components().next())
+        .is_some_and(|first| first.as_str() == "target" || first.as_str() == ".cache");
+    if ignored {
+        return false;
+    }
+    path.extension() == Some("wt") || matches!(path.file_name(), Some("watt.toml" | "watt.lock"))
+}
+
+/// Formats current UTC time as `HH:MM:SS`
+fn timestamp() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+        % 86400;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Rebuilds project
+///
+/// # Notes
+/// Compile errors are raised as panics and reported by the
+/// panic hook, so they're caught here to keep watching.
+///
+fn rebuild(path: &Utf8PathBuf, dce: bool, update: bool) {
+    println!(
+        "{} Rebuilding...",
+        style(format!("[{}]", timestamp())).bold().dim()
+    );
+    let start = Instant::now();
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+        compile::compile(path.clone(), dce, update)
+    })) {
+        Ok(_) => println!(
+            "{} Done in {}ms",
+            style("[✓]").bold().green(),
+            start.elapsed().as_millis()
+        ),
+        Err(_) => println!(
+            "{} Build failed, waiting for changes...",
+            style("[✗]").bold().red()
+        ),
+    }
+}
+
+/// Builds project at `path` and rebuilds it on each change
+/// of the sources, `watt.toml` or `watt.lock`
+///
+/// Events are debounced, so a burst of changes,
+/// like saving several files at once, triggers one rebuild.
+/// If `update` is true, git dependencies are resolved
+/// again only for the first build.
+///
+pub fn watch(path: Utf8PathBuf, dce: bool, update: bool) {
+    // Watching package directory
+    let root = path.canonicalize_utf8().unwrap_or(path.clone());
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(watcher) => watcher,
+        Err(e) => bail!(PackageError::FailedToWatch {
+            path,
+            error: e.to_string()
+        }),
+    };
+    if let Err(e) = watcher.watch(root.as_std_path(), RecursiveMode::Recursive) {
+        bail!(PackageError::FailedToWatch {
+            path,
+            error: e.to_string()
+        })
+    }
+    // First build
+    rebuild(&path, dce, update);
+    println!(
+        "{} Watching {path} for changes...",
+        style("[👀]").bold().cyan()
+    );
+    loop {
+        // Waiting for the relevant change
+        match rx.recv() {
+            Ok(Ok(event))
+                if matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) && event.paths.iter().any(|path| is_relevant(&root, path)) =>
+            {
+                info!("Found change of {:?}.", event.paths);
+            }
+            Ok(_) => continue,
+            // Watcher is dropped
+            Err(_) => break,
+        }
+        // Skipping events until the changes settle
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+        rebuild(&path, dce, false);
+    }
+}
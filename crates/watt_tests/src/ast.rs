@@ -13,3 +13,169 @@ fn main() {
         "#
     )
 }
+
+#[test]
+fn shift_operators() {
+    assert_ast!(
+        r#"
+fn main() {
+    let a = 1 << 4 + 1;
+    let b = 256 >> 2 < a;
+}
+        "#
+    )
+}
+
+#[test]
+fn optional_chain() {
+    assert_ast!(
+        r#"
+fn main() {
+    let a = b?.c(1, 2)?.d.e;
+}
+        "#
+    )
+}
+
+#[test]
+fn type_alias() {
+    assert_ast!(
+        r#"
+pub type Pair = Box[int]
+        "#
+    )
+}
+
+#[test]
+fn compound_assignment() {
+    assert_ast!(
+        r#"
+fn main() {
+    x += 2;
+}
+        "#
+    )
+}
+
+#[test]
+fn compound_assignment_mod() {
+    assert_ast!(
+        r#"
+fn main() {
+    x %= 2;
+}
+        "#
+    )
+}
+
+#[test]
+fn pattern_guard() {
+    assert_ast!(
+        r#"
+fn main() {
+    match a {
+        n if n > 0 -> n
+        _ -> 0
+    }
+}
+        "#
+    )
+}
+
+#[test]
+fn heredoc() {
+    assert_ast!(
+        r#"
+extern fn log(text: string) = <<~JS
+    console.log(text);
+JS
+
+fn main() {
+    log(<<~TEXT
+        a << b
+    TEXT
+    );
+}
+        "#
+    )
+}
+
+#[test]
+fn doc_comment() {
+    assert_ast!(
+        r#"
+/// Logs text.
+extern fn log(text: string) = "console.log(text)"
+
+/// Entry point.
+///
+/// Logs greeting.
+fn main() {
+    /// Ignored.
+    log("hello");
+}
+        "#
+    )
+}
+
+#[test]
+fn module_doc_comment() {
+    assert_ast!(
+        r#"
+//! Logging utilities.
+//!
+//! Logs to the console.
+
+/// Logs text.
+extern fn log(text: string) = "console.log(text)"
+        "#
+    )
+}
+
+#[test]
+fn try_catch() {
+    assert_ast!(
+        r#"
+fn main() {
+    let a = try {
+        throw "failed";
+    } catch e {
+        e
+    };
+}
+        "#
+    )
+}
+
+#[test]
+fn try_operator() {
+    assert_ast!(
+        r#"
+fn main() {
+    let a = parse("1")?;
+}
+        "#
+    )
+}
+
+// note: will report errors.
+#[test]
+fn two_syntax_errors() {
+    assert_ast!(
+        r#"
+fn first() {
+    let a = ;
+}
+
+fn second(): int {
+    1
+}
+
+type Point {
+    x int
+}
+
+fn third(): int = 3
+        "#
+    )
+}
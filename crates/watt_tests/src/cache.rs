@@ -0,0 +1,50 @@
+// Imports
+use camino::Utf8PathBuf;
+use ecow::EcoString;
+use watt_compile::cache::ModuleCache;
+
+/// Checks module freshness with the new cache,
+/// storing its hash, like the compilation does
+fn check(cache: &Utf8PathBuf, source: &str, generated: &Utf8PathBuf) -> bool {
+    let module = EcoString::from("app/main");
+    let mut modules = ModuleCache::new(cache, true);
+    let fresh = modules.is_fresh(&module, source, [], generated);
+    if !fresh {
+        std::fs::write(generated, source).unwrap();
+        modules.store(&module);
+    }
+    fresh
+}
+
+#[test]
+fn unchanged_module_is_skipped() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+    let (cache, generated) = (root.join(".cache"), root.join("main.js"));
+
+    assert!(!check(&cache, "fn main() {}", &generated));
+    assert!(check(&cache, "fn main() {}", &generated));
+}
+
+#[test]
+fn changed_module_is_rebuilt() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+    let (cache, generated) = (root.join(".cache"), root.join("main.js"));
+
+    assert!(!check(&cache, "fn main() {}", &generated));
+    assert!(!check(&cache, "fn main() { 1; }", &generated));
+    assert!(check(&cache, "fn main() { 1; }", &generated));
+}
+
+#[test]
+fn deleted_output_is_rebuilt() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+    let (cache, generated) = (root.join(".cache"), root.join("main.js"));
+
+    assert!(!check(&cache, "fn main() {}", &generated));
+    std::fs::remove_file(&generated).unwrap();
+    assert!(!check(&cache, "fn main() {}", &generated));
+    assert!(check(&cache, "fn main() {}", &generated));
+}
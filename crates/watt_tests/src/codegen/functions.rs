@@ -17,3 +17,924 @@ fn main() {
     "#
     )
 }
+
+#[test]
+fn alias_in_signature() {
+    assert_js!(
+        r#"
+type Id = int
+
+fn next(id: Id): Id {
+    id + 1
+}
+
+fn main() {
+    let a: int = next(3);
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn recursive_alias() {
+    assert_js!(
+        r#"
+type A = A
+
+fn main() {
+}
+    "#
+    )
+}
+
+#[test]
+fn generic_fn_instantiation() {
+    assert_js!(
+        r#"
+type Pair[A, B] {
+    first: A,
+    second: B
+}
+
+fn pair[A, B](a: A, b: B): Pair[A, B] = Pair(a, b)
+
+fn main() {
+    let p1: Pair[int, string] = pair(1, "one");
+    let p2: Pair[string, bool] = pair("two", true);
+    let sum = p1.first + 1;
+    let flag = p2.second && true;
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn generic_fn_instantiation_mismatch() {
+    assert_js!(
+        r#"
+fn first[A, B](a: A, b: B): A = a
+
+fn main() {
+    let a = first(1, "one");
+    let b: string = first(2, true);
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn infinite_type() {
+    assert_js!(
+        r#"
+type Box[T] {
+    value: T
+}
+
+fn same[T](a: T, b: T): T = a
+
+fn main() {
+    let a = todo as "later";
+    let b = same(a, Box(a));
+}
+        "#
+    )
+}
+
+#[test]
+fn overloaded_extern_fn() {
+    assert_js!(
+        r#"
+extern fn println(text: string) = "console.log(text);"
+extern fn println(number: int) = "console.log(number);"
+
+fn main() {
+    println("hello");
+    println(3);
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn overloaded_extern_fn_no_matching() {
+    assert_js!(
+        r#"
+extern fn println(text: string) = "console.log(text);"
+extern fn println(number: int) = "console.log(number);"
+
+fn main() {
+    println(3.5);
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn overloaded_extern_fn_ambiguous() {
+    assert_js!(
+        r#"
+extern fn show[T](value: T) = "console.log(value);"
+extern fn show(number: int) = "console.log(number);"
+
+fn main() {
+    show(3);
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn overloaded_regular_fn() {
+    assert_js!(
+        r#"
+extern fn println(text: string) = "console.log(text);"
+extern fn println(number: int) = "console.log(number);"
+
+fn println() {
+}
+    "#
+    )
+}
+
+#[test]
+fn overloaded_extern_fn_public() {
+    assert_js!(
+        r#"
+pub extern fn show(text: string) = "console.log(text);"
+extern fn show(number: int) = "console.log(number);"
+pub extern fn show(number: float) = "console.log(number);"
+    "#
+    )
+}
+
+#[test]
+fn overloaded_extern_fn_imported() {
+    assert_js!(
+        r#"
+use dep for show
+
+pub fn main() {
+    show(1);
+    show(2.0);
+}
+    "#,
+        dependency = r#"
+pub type Meters = float
+
+pub extern fn show(number: int) = "console.log(number);"
+pub extern fn show(distance: Meters) = "console.log(distance);"
+    "#
+    )
+}
+
+#[test]
+fn overloaded_extern_fn_module_access() {
+    assert_js!(
+        r#"
+use dep as d
+
+pub fn main() {
+    d.show(2.0);
+}
+    "#,
+        dependency = r#"
+extern fn show(text: string) = "console.log(text);"
+pub extern fn show(number: int) = "console.log(number);"
+pub extern fn show(number: float) = "console.log(number);"
+    "#
+    )
+}
+
+#[test]
+fn overloaded_extern_fn_unresolved_arg() {
+    assert_js!(
+        r#"
+extern fn show(number: int) = "console.log(number);"
+extern fn show(a: int, b: int) = "console.log(a, b);"
+
+pub fn main() {
+    show(todo as "number");
+}
+    "#
+    )
+}
+
+#[test]
+fn try_catch() {
+    assert_js!(
+        r#"
+type Failure {
+    reason: string
+}
+
+fn check(value: int): int {
+    if value < 0 {
+        throw Failure("negative");
+    }
+    value
+}
+
+fn main() {
+    let a = try {
+        check(1) + check(-1)
+    } catch e: Failure {
+        0
+    };
+}
+        "#
+    )
+}
+
+#[test]
+fn try_catch_string_by_default() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = try {
+        throw "failed";
+    } catch e {
+        e <> "!"
+    };
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn try_catch_default_binding_field() {
+    assert_js!(
+        r#"
+type Failure {
+    reason: string
+}
+
+fn main() {
+    let a = try {
+        throw Failure("failed");
+    } catch e {
+        e.reason
+    };
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn try_catch_invalid_type() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = try {
+        0
+    } catch e: int {
+        e
+    };
+}
+        "#
+    )
+}
+
+#[test]
+fn try_catch_rethrow() {
+    assert_js!(
+        r#"
+fn check(value: int): int {
+    if value < 0 {
+        throw "negative";
+    }
+    value
+}
+
+fn main() {
+    let a = try {
+        check(-1)
+    } catch e {
+        throw e;
+    };
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn throw_int() {
+    assert_js!(
+        r#"
+fn main() {
+    throw 1;
+}
+        "#
+    )
+}
+
+#[test]
+fn unreachable_after_throw() {
+    assert_js!(
+        r#"
+type Failure {
+    reason: string
+}
+
+fn fail(): int {
+    throw Failure("always");
+    let a = 1;
+    a + 1
+}
+        "#
+    )
+}
+
+#[test]
+fn unreachable_after_diverging_if() {
+    assert_js!(
+        r#"
+fn check(value: int): int {
+    if value < 0 {
+        panic as "negative";
+    } else {
+        todo as "positive";
+    }
+    value
+}
+
+fn main() {
+    if check(1) > 0 {
+        panic as "positive";
+    }
+    let b = 2;
+}
+        "#
+    )
+}
+
+#[test]
+fn diverging_placeholder_branches() {
+    assert_js!(
+        r#"
+fn check(value: int): int {
+    if value < 0 {
+        panic as "negative";
+    } else {
+        todo as "positive";
+    }
+}
+
+fn describe(value: int): string {
+    match value {
+        0 -> "zero"
+        _ -> panic as "non-zero"
+    }
+}
+        "#
+    )
+}
+
+#[test]
+fn reachable_after_one_branch_if() {
+    assert_js!(
+        r#"
+fn check(value: int): int {
+    if value < 0 {
+        panic as "negative";
+    }
+    value
+}
+        "#
+    )
+}
+
+#[test]
+fn try_operator() {
+    assert_js!(
+        r#"
+enum Result[V, E] {
+    Ok(value: V),
+    Error(error: E)
+}
+
+fn parse(text: string): Result[int, string] {
+    if text == "" {
+        Result.Error("empty")
+    } else {
+        Result.Ok(1)
+    }
+}
+
+fn sum(a: string, b: string): Result[int, string] {
+    Result.Ok(parse(a)? + parse(b)?)
+}
+        "#
+    )
+}
+
+#[test]
+fn try_operator_other_payload() {
+    assert_js!(
+        r#"
+enum Result[V, E] {
+    Ok(parsed: V),
+    Error(error: E)
+}
+
+fn parse(text: string): Result[int, string] {
+    if text == "" {
+        Result.Error("empty")
+    } else {
+        Result.Ok(1)
+    }
+}
+
+fn positive(text: string): Result[bool, string] {
+    Result.Ok(parse(text)? > 0)
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn try_operator_other_error() {
+    assert_js!(
+        r#"
+enum Result[V, E] {
+    Ok(value: V),
+    Error(error: E)
+}
+
+fn parse(text: string): Result[int, string] {
+    Result.Ok(1)
+}
+
+fn positive(text: string): Result[bool, int] {
+    Result.Ok(parse(text)? > 0)
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn try_non_result() {
+    assert_js!(
+        r#"
+fn parse(text: string): int {
+    1
+}
+
+fn main() {
+    let a = parse("1")?;
+}
+        "#
+    )
+}
+
+#[test]
+fn try_optional() {
+    assert_js!(
+        r#"
+extern fn find(key: string): Optional[int] = "return null;"
+
+fn sum(a: string, b: string): Optional[int] {
+    let result: Optional[int] = find(a)? + find(b)?;
+    result
+}
+        "#
+    )
+}
+
+#[test]
+fn try_optional_enum() {
+    assert_js!(
+        r#"
+enum Shape {
+    Circle(radius: float),
+    Square(side: float)
+}
+
+extern fn find(key: string): Optional[Shape] = "return null;"
+
+fn first(a: string, b: string): Optional[Shape] {
+    let shape = find(a)?;
+    find(b)?;
+    shape
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn try_optional_ambiguous() {
+    assert_js!(
+        r#"
+enum Result[V, E] {
+    Ok(value: V),
+    Error(error: E)
+}
+
+extern fn find(key: string): Optional[Result[int, string]] = "return null;"
+
+fn main(): Optional[int] {
+    let a = find("a")?;
+    todo as "unwrap"
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn misspelled_function() {
+    assert_js!(
+        r#"
+fn greet(name: string): string {
+    "hello, " <> name
+}
+
+fn main() {
+    let a = gret("watt");
+}
+        "#
+    )
+}
+
+#[test]
+fn await_extern_fn() {
+    assert_js!(
+        r#"
+extern fn fetch_text(url: string): string = "return fetch(url).then((r) => r.text());"
+
+fn load(url: string): int {
+    let text = await fetch_text(url);
+    if text == "" {
+        0
+    } else {
+        await fetch_text(text);
+        1
+    }
+}
+
+fn main() {
+    let callback = fn() {
+        await load("mammoth");
+    };
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn await_in_guard() {
+    assert_js!(
+        r#"
+extern fn ready(): bool = "return Promise.resolve(true);"
+
+fn main() {
+    let a = match 3 {
+        _ if await ready() -> 1
+        _ -> 2
+    };
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn await_missing_on_async_fn() {
+    assert_js!(
+        r#"
+extern fn fetch_text(url: string): string = "return fetch(url).then((r) => r.text());"
+
+fn main() {
+    let length = load("mammoth") + 1;
+}
+
+fn load(url: string): int {
+    let text = await fetch_text(url);
+    1
+}
+    "#
+    )
+}
+
+#[test]
+fn dce_unused_private_fn() {
+    assert_js!(
+        r#"
+fn square(x: int): int {
+    x * x
+}
+
+fn unused(x: int): int {
+    square(x) + 1
+}
+
+fn twice(f: fn(int): int, x: int): int {
+    f(f(x))
+}
+
+pub fn quad(x: int): int {
+    twice(square, x)
+}
+
+fn main() {
+    let a = quad(2);
+}
+    "#,
+        dce = true
+    )
+}
+
+#[test]
+fn const_fn() {
+    assert_js!(
+        r#"
+const fn square(x: int): int = x * x
+
+pub const fn clamp_square(x: int, max: int): int {
+    let squared = square(x);
+    if squared > max {
+        max
+    } else {
+        squared
+    }
+}
+
+fn main() {
+    let a = clamp_square(3, 5);
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn const_fn_non_const_call() {
+    assert_js!(
+        r#"
+fn square(x: int): int = x * x
+
+const fn twice_square(x: int): int = square(x) * 2
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn const_fn_inferred_non_const_call() {
+    assert_js!(
+        r#"
+const fn twice_square(x: int): int = square(x) * 2
+
+fn square(x: int) {
+    log("squared");
+    x * x
+}
+
+fn log(text: string) = text
+    "#
+    )
+}
+
+#[test]
+fn const_fn_in_const() {
+    assert_js!(
+        r#"
+const fn square(x: int): int = x * x
+
+const fn clamp_square(x: int, max: int): int {
+    let squared = square(x);
+    if squared > max {
+        max
+    } else {
+        squared
+    }
+}
+
+pub const NINE: int = square(3)
+pub const CLAMPED: int = clamp_square(NINE, 50)
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn const_fn_in_const_non_const_call() {
+    assert_js!(
+        r#"
+fn square(x: int): int = x * x
+
+pub const NINE: int = square(3)
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn const_fn_loop() {
+    assert_js!(
+        r#"
+const fn sum_to(n: int): int {
+    let sum = 0;
+    for i in 0..n {
+        sum = sum + i;
+    }
+    sum
+}
+    "#
+    )
+}
+
+#[test]
+fn deprecated_fn() {
+    assert_js!(
+        r#"
+#[deprecated("use `sum` instead")]
+pub fn add(a: int, b: int): int = a + b
+
+#[deprecated]
+pub type Point {
+    x: int,
+    y: int
+}
+
+pub fn sum(a: int, b: int): int = a + b
+    "#
+    )
+}
+
+#[test]
+fn deprecated_fn_call() {
+    assert_js!(
+        r#"
+#[deprecated("use `sum` instead")]
+fn add(a: int, b: int): int = a + b
+
+#[deprecated]
+type Point {
+    x: int,
+    y: int
+}
+
+fn sum(a: int, b: int): int = a + b
+
+fn main() {
+    let a = add(1, 2);
+    let b = sum(1, 2);
+    let p = Point(a, b);
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn unknown_attribute() {
+    assert_js!(
+        r#"
+#[inline]
+fn add(a: int, b: int): int = a + b
+    "#
+    )
+}
+
+#[test]
+fn return_type_mismatch() {
+    assert_js!(
+        r#"
+fn name(): int {
+    "watt"
+}
+    "#
+    )
+}
+
+#[test]
+fn return_type_inferred() {
+    assert_js!(
+        r#"
+fn answer() {
+    42
+}
+
+pub fn main(): int {
+    answer() + 1
+}
+    "#
+    )
+}
+
+#[test]
+fn return_type_inferred_generic() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = id(1) + 1;
+    let b = id("a") <> "b";
+}
+
+fn id[T](value: T) {
+    value
+}
+
+fn twice(value: int) {
+    id(value) * 2
+}
+    "#
+    )
+}
+
+#[test]
+fn return_type_mismatch_in_branch() {
+    assert_js!(
+        r#"
+fn check(flag: bool): int {
+    if flag {
+        1
+    } else {
+        "one"
+    }
+}
+    "#
+    )
+}
+
+#[test]
+fn missing_return() {
+    assert_js!(
+        r#"
+fn check(flag: bool): int {
+    let a = 1;
+}
+    "#
+    )
+}
+
+#[test]
+fn return_type_mismatch_in_case() {
+    assert_js!(
+        r#"
+fn check(flag: bool): int {
+    match flag {
+        true -> 1
+        false -> "one"
+    }
+}
+    "#
+    )
+}
+
+#[test]
+fn never_unifies_with_branch() {
+    assert_js!(
+        r#"
+fn answer(flag: bool): int {
+    let x = if flag {
+        42
+    } else {
+        todo as "answer"
+    };
+    x + 1
+}
+    "#
+    )
+}
+
+#[test]
+fn todo_in_binary_op() {
+    assert_js!(
+        r#"
+fn answer(): int {
+    let a = todo as "answer";
+    a + 1
+}
+    "#
+    )
+}
+
+#[test]
+fn never_returned() {
+    assert_js!(
+        r#"
+fn fail(): int {
+    throw "failed"
+}
+
+fn forever(): string {
+    loop true {
+    }
+}
+    "#
+    )
+}
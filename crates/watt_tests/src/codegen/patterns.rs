@@ -128,3 +128,40 @@ fn bool_check(b: bool): string {
         "#
     )
 }
+
+/*
+ * Match with guards
+ */
+#[test]
+fn match_with_guard() {
+    assert_js!(
+        r#"
+enum Option[T] {
+    Some(value: T),
+    None
+}
+
+fn positive(opt: Option[int]): int {
+    match opt {
+        Option.Some(value) if value > 0 -> value
+        _ -> 0
+    }
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn match_with_non_logical_guard() {
+    assert_js!(
+        r#"
+fn check_number(n: int): string {
+    match n {
+        x if x + 1 -> "one"
+        _ -> "other"
+    }
+}
+        "#
+    )
+}
@@ -22,6 +22,136 @@ fn main() {
     )
 }
 
+#[test]
+fn bitwise_shifts() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = 1 << 4;
+    let b = 256 >> 2;
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn bitwise_shift_float() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = 1.5 << 2;
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn bitwise_shift_negative() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = 1 << -2;
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn bitwise_shift_negative_const() {
+    assert_js!(
+        r#"
+const offset: int = 2 - 5
+
+fn main() {
+    let a = 256 >> offset;
+}
+        "#
+    )
+}
+
+#[test]
+fn bitwise_shift_shadowed_const() {
+    assert_js!(
+        r#"
+const offset: int = -2
+
+fn main() {
+    let offset = 2;
+    let a = 256 >> offset;
+}
+        "#
+    )
+}
+
+#[test]
+fn float_div_by_zero() {
+    assert_js!(
+        r#"
+fn main() {
+    let inf = 1.0 / 0.0;
+    let nan = 0.0 / 0.0;
+}
+        "#
+    )
+}
+
+#[test]
+fn float_overflow() {
+    assert_js!(&format!(
+        r#"
+fn main() {{
+    let inf = {}.0;
+}}
+        "#,
+        "9".repeat(400)
+    ))
+}
+
+#[test]
+fn int_division() {
+    assert_js!(
+        r#"
+fn main() {
+    let a: float = 5 / 2;
+    let b: float = 4 / 2;
+    let c: int = 5 ~/ 2;
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn int_division_is_float() {
+    assert_js!(
+        r#"
+fn main() {
+    let a: int = 4 / 2;
+}
+        "#
+    )
+}
+
+#[test]
+fn constant_folding() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = 2 + 3;
+    let b = 2 + 3 * 4;
+    let c = -(1.5 * 2.0) + 0.1;
+    let d = 1 < 2 && !false;
+    let e = "hello, " <> "world";
+    let f = 10 / 0;
+    let g = a + 1 + 2;
+}
+        "#
+    )
+}
+
 /*
  * Grouping tests
  */
@@ -37,6 +167,21 @@ fn main() {
     )
 }
 
+/*
+ * String tests
+ */
+#[test]
+fn string_escape_sequences() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = "line\nbreak\t\"quoted\" \\";
+    panic as "unreachable\n";
+}
+        "#
+    )
+}
+
 /*
  * Casting tests
  */
@@ -115,6 +260,19 @@ fn main() {
     )
 }
 
+#[test]
+fn for_loop_range_end_not_int() {
+    assert_js!(
+        r#"
+fn main() {
+    for i in 0..=3.5 {
+        let x = i;
+    }
+}
+        "#
+    )
+}
+
 #[test]
 fn simple_loop() {
     assert_js!(
@@ -134,6 +292,75 @@ fn main() {
     )
 }
 
+/*
+ * Assignments
+ */
+#[test]
+fn parallel_assignment() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = 1;
+    let b = 2;
+    a, b = b, a;
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn parallel_assignment_mismatch() {
+    assert_js!(
+        r#"
+fn main() {
+    let a = 1;
+    let b = 2;
+    a, b = b;
+}
+        "#
+    )
+}
+
+#[test]
+fn labeled_block_break() {
+    assert_js!(
+        r#"
+fn main() {
+    let found = 0;
+    'outer: {
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    if i + j + k == 4 {
+                        found = i * 100 + j * 10 + k;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+    }
+}
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn break_unknown_label() {
+    assert_js!(
+        r#"
+fn main() {
+    'outer: {
+        let f = fn() {
+            break 'outer;
+        };
+    }
+}
+        "#
+    )
+}
+
 /*
  * Boolean expressions
  */
@@ -161,3 +388,245 @@ fn test234_function1 {}
     "#
     )
 }
+
+#[test]
+fn publicity() {
+    assert_js!(
+        r#"
+pub type Point {
+    x: int,
+    y: int
+}
+
+pub enum Axis {
+    X,
+    Y
+}
+
+pub const origin: int = 0
+const offset: int = 1
+
+fn shift(value: int): int {
+    value + offset
+}
+
+pub fn shifted(point: Point): Point {
+    Point(shift(point.x), shift(point.y))
+}
+    "#
+    )
+}
+
+#[test]
+fn jsdoc() {
+    assert_js!(
+        r#"
+/// Private function isn't documented.
+fn twice(value: int): int {
+    value * 2
+}
+
+/// Applies `f` to the value twice.
+///
+/// Returns */ result.
+pub fn apply[T](value: T, f: fn(T): T): T {
+    f(f(value))
+}
+
+pub fn log(text: string, times: int) {
+    text;
+    times;
+}
+
+/// Checks value.
+pub extern fn is_ok(value: float): bool = "return value > 0"
+    "#
+    )
+}
+
+#[test]
+fn jsdoc_inferred_types() {
+    assert_js!(
+        r#"
+type Pair[T] {
+    key: T,
+    value: T
+}
+
+type Ints = Pair[int]
+
+/// Sums the pair.
+pub fn sum(pair: Ints) {
+    pair.key + pair.value
+}
+    "#
+    )
+}
+
+#[test]
+fn jsdoc_module_doc() {
+    assert_js!(
+        r#"
+//! Utilities for the numbers.
+//!
+//! Module is documented.
+
+pub fn twice(value: int): int {
+    value * 2
+}
+    "#
+    )
+}
+
+#[test]
+fn prelude_imports_arithmetic() {
+    assert_js!(
+        r#"
+fn sum(a: int, b: int): int {
+    a + b * 2
+}
+    "#
+    )
+}
+
+#[test]
+fn prelude_imports_match() {
+    assert_js!(
+        r#"
+fn describe(a: int): string {
+    match a {
+        1 -> "one"
+        _ if a > 10 -> "many"
+        _ -> "some"
+    }
+}
+    "#
+    )
+}
+
+#[test]
+fn const_bindings() {
+    assert_js!(
+        r#"
+pub fn main(): int {
+    let a = 1;
+    let b = 2;
+    b = b + a;
+    let c = 3;
+    let inc = fn() {
+        c = c + 1;
+    };
+    inc();
+    b + c
+}
+    "#
+    )
+}
+
+#[test]
+fn const_bindings_shadowed() {
+    assert_js!(
+        r#"
+pub fn main(): int {
+    let a = 1;
+    if a > 0 {
+        let a = 2;
+        a = a + 1;
+    }
+    a
+}
+    "#
+    )
+}
+
+#[test]
+fn import_shadowed_by_local() {
+    assert_js!(
+        r#"
+use dep for greet, farewell
+
+fn greet(): string {
+    "hello from local"
+}
+
+pub fn main(): string {
+    greet() <> farewell()
+}
+    "#,
+        dependency = r#"
+pub fn greet(): string {
+    "hello"
+}
+
+pub fn farewell(): string {
+    "bye"
+}
+    "#
+    )
+}
+
+#[test]
+fn import_type_alias() {
+    assert_js!(
+        r#"
+use dep for Id, Pair, first
+use dep for Key
+
+pub fn main(): Id {
+    let pair: Pair[Key] = first(1, 2);
+    pair.key
+}
+    "#,
+        dependency = r#"
+pub type Id = int
+pub type Key = int
+
+pub type Pair[T] {
+    key: T,
+    value: T
+}
+
+pub fn first(key: int, value: int): Pair[int] {
+    Pair(key, value)
+}
+    "#
+    )
+}
+
+#[test]
+fn const_expression_folding() {
+    assert_js!(
+        r#"
+pub const ONE: int = 1
+pub const TWO: int = 1 + 1
+pub const THREE: int = ONE + TWO
+pub const NAME: string = "wa" <> "tt"
+    "#
+    )
+}
+
+#[test]
+fn const_expression_call() {
+    assert_js!(
+        r#"
+fn read_input(): int {
+    1
+}
+
+const X: int = read_input()
+    "#
+    )
+}
+
+#[test]
+fn const_expression_non_const_value() {
+    assert_js!(
+        r#"
+fn read_input(): int {
+    1
+}
+
+const X: fn(): int = read_input
+    "#
+    )
+}
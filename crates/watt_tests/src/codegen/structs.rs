@@ -107,3 +107,287 @@ fn main() {
     "#
     )
 }
+
+#[test]
+fn optional_chain_field_access() {
+    assert_js!(
+        r#"
+type Mammoth {
+    iceberg: Iceberg
+}
+
+type Iceberg {
+    value: int
+}
+
+fn main() {
+    let m = Mammoth(Iceberg(3));
+    let a: int = m?.iceberg?.value;
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn optional_chain_unknown_field() {
+    assert_js!(
+        r#"
+type Iceberg {
+    value: int
+}
+
+fn main() {
+    let i = Iceberg(3);
+    let a = i?.size;
+}
+    "#
+    )
+}
+
+#[test]
+fn optional_chain_on_optional_value() {
+    assert_js!(
+        r#"
+type Mammoth {
+    iceberg: Iceberg
+}
+
+type Iceberg {
+    value: int
+}
+
+extern fn find(): Optional[Mammoth] = "return null;"
+
+fn main() {
+    let a: Optional[int] = find()?.iceberg?.value;
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn optional_chain_result_is_optional() {
+    assert_js!(
+        r#"
+type Iceberg {
+    value: int
+}
+
+extern fn find(): Optional[Iceberg] = "return null;"
+
+fn main() {
+    let a: int = find()?.value;
+}
+    "#
+    )
+}
+
+#[test]
+fn optional_from_non_optional() {
+    assert_js!(
+        r#"
+type Iceberg {
+    value: int
+}
+
+fn main() {
+    let a: Optional[int] = 3;
+    let b: Optional[Iceberg] = Iceberg(4);
+}
+    "#
+    )
+}
+
+#[test]
+fn optional_returned_from_non_optional() {
+    assert_js!(
+        r#"
+type Iceberg {
+    value: Optional[int]
+}
+
+fn find(found: bool): Optional[int] {
+    if found {
+        1
+    } else {
+        Iceberg(2).value
+    }
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn optional_generic_argument() {
+    assert_js!(
+        r#"
+type Box[T] {
+    value: T
+}
+
+fn main() {
+    let a: Box[Optional[int]] = Box(1);
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn optional_and_non_optional_branches() {
+    assert_js!(
+        r#"
+extern fn find(): Optional[int] = "return null;"
+
+fn main() {
+    let a = if true {
+        find()
+    } else {
+        1
+    };
+}
+    "#
+    )
+}
+
+#[test]
+fn nested_field_assignment() {
+    assert_js!(
+        r#"
+type Mammoth {
+    iceberg: Iceberg
+}
+
+type Iceberg {
+    value: int
+}
+
+fn main() {
+    let m = Mammoth(Iceberg(3));
+    m.iceberg.value = 4;
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn nested_field_assignment_not_a_container() {
+    assert_js!(
+        r#"
+type Iceberg {
+    value: int
+}
+
+fn main() {
+    let i = Iceberg(3);
+    i.value.size = 4;
+}
+    "#
+    )
+}
+
+#[test]
+fn struct_width_subtyping() {
+    assert_js!(
+        r#"
+type Point {
+    x: int,
+    y: int
+}
+
+type Point3 {
+    x: int,
+    y: int,
+    z: int
+}
+
+fn sum(p: Point): int = p.x + p.y
+
+fn main() {
+    let s = sum(Point3(1, 2, 3));
+}
+    "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn struct_width_subtyping_missing_field() {
+    assert_js!(
+        r#"
+type Point {
+    x: int,
+    y: int
+}
+
+type Point1 {
+    x: int
+}
+
+fn sum(p: Point): int = p.x + p.y
+
+fn main() {
+    let s = sum(Point1(1));
+}
+    "#
+    )
+}
+
+#[test]
+fn local_shadows_type() {
+    assert_js!(
+        r#"
+type Point {
+    x: int,
+    y: int
+}
+
+fn main(): int {
+    let Point = 3;
+    Point + 1
+}
+    "#
+    )
+}
+
+#[test]
+fn structs_equality() {
+    assert_js!(
+        r#"
+type Point {
+    x: int,
+    y: int
+}
+
+fn main(): bool {
+    let p1 = Point(1, 2);
+    let p2 = Point(1, 2);
+    p1 == p2
+}
+    "#
+    )
+}
+
+#[test]
+fn structs_equality_null_field() {
+    assert_js!(
+        r#"
+type User {
+    name: string,
+    email: Optional[string]
+}
+
+extern fn no_email(): Optional[string] = "return null;"
+
+fn main(): bool {
+    let a = User("mammoth", no_email());
+    let b = User("mammoth", no_email());
+    a == b
+}
+    "#
+    )
+}
@@ -0,0 +1,126 @@
+// Imports
+#[allow(unused_imports)]
+use crate::assert_fmt;
+
+#[test]
+fn format_spacing_and_indentation() {
+    assert_fmt!(
+        r#"
+use std/io as io
+fn main(){
+let a=1+2*3;let b :int=a-(4~/2);
+  if a>b&&b!=0{io.println("greater")}elif a==b{
+        io.println("equal")
+  }else{io.println("less")}
+}
+        "#
+    )
+}
+
+#[test]
+fn format_declarations() {
+    assert_fmt!(
+        r#"
+pub type Point[T]{x:T,y:T}
+enum Shape{Circle(r:float),Square(side:float),Empty}
+type Points=Point[int]
+const LIMIT:int=100
+extern fn log(value:str):()="console.log(value)"
+#[deprecated("use `area` instead")]
+pub fn square(x:int):int=x*x
+        "#
+    )
+}
+
+#[test]
+fn format_comments() {
+    assert_fmt!(
+        r#"
+// Entry point
+/// Runs the program
+fn main() {
+    // first line
+    let a = 1; // trailing
+    // before b
+
+
+    let b = 2;
+    // at the end
+}
+// end of file
+        "#
+    )
+}
+
+#[test]
+fn format_trailing_comments() {
+    assert_fmt!(
+        r#"
+use std/io for println // printing
+type Point {
+    x: int, // abscissa
+    y: int // ordinate
+}
+enum Axis { X, // horizontal
+    Y }
+fn main(a: int): int {
+    match a {
+        1 -> 2 // one
+        _ -> 3
+    } // result
+} // end of main
+        "#
+    )
+}
+
+#[test]
+fn format_module_doc_comment() {
+    assert_fmt!(
+        r#"
+//!   Program module.
+//!
+/// Runs the program
+fn main() {
+    1;
+}
+        "#
+    )
+}
+
+#[test]
+fn format_match_and_loops() {
+    assert_fmt!(
+        r#"
+enum Opt[T] { Some(value: T), None }
+fn sum(o: Opt[int], n: int): int {
+    let total = 0;
+    for i in 0..=n { total += i * 2 }
+    loop total > 100 { total -= 1; }
+    match o {
+        Opt.Some(value) if value > 0 -> value + total
+        Opt.Some(value)|Opt.None -> { total }
+        _ -> 0
+    }
+}
+        "#
+    )
+}
+
+#[test]
+fn format_expressions() {
+    assert_fmt!(
+        r#"
+fn main() {
+    let f = fn(a: int): int { a * -a };
+    let s = "tab\tquote\"";
+    let m = `first
+second`;
+    let c = a?.b(1,2)?.c;
+    let d = (1 + 2) * 3 as float;
+    let t = try { f(1) } catch e: string { panic as "failed" };
+    x.y.z *= 2 + 3;
+    todo as "later"
+}
+        "#
+    )
+}
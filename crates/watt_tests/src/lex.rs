@@ -1,6 +1,6 @@
 // Imports
 #[allow(unused_imports)]
-use crate::assert_tokens;
+use crate::{assert_tokens, utils::lex_into_tokens};
 
 #[test]
 fn escape_sequence_1() {
@@ -163,3 +163,221 @@ fn escape_sequence_16() {
         "#
     )
 }
+
+#[test]
+fn triple_quoted_string_1() {
+    assert_tokens!(
+        r#"
+"""
+    hello,
+      world!
+    """
+        "#
+    )
+}
+
+#[test]
+fn triple_quoted_string_2() {
+    assert_tokens!(
+        r#"
+"""a "quoted" \u{00C0}\n"""
+""
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn triple_quoted_string_3() {
+    assert_tokens!(
+        r#"
+"""
+    hello
+""
+        "#
+    )
+}
+
+#[test]
+fn escape_sequence_17() {
+    assert_tokens!(
+        r#"
+"\t"
+"a\tb"
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn escape_sequence_18() {
+    assert_tokens!(
+        r#"
+"\q"
+        "#
+    )
+}
+
+#[test]
+fn optional_chain() {
+    assert_tokens!(
+        r#"
+a?.b?.c()
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn optional_chain_without_dot() {
+    assert_tokens!(
+        r#"
+a?b
+        "#
+    )
+}
+
+#[test]
+fn heredoc_1() {
+    assert_tokens!(
+        r#"
+<<~JS
+    const a = "quoted";
+    console.log(`${a}\n`);
+JS
+        "#
+    )
+}
+
+#[test]
+fn heredoc_2() {
+    assert_tokens!(
+        r#"
+let page = <<~HTML
+        <div>
+            """not closed"""
+
+        </div>
+    HTML
+;
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn heredoc_3() {
+    assert_tokens!(
+        r#"
+<<~JS
+    console.log("unclosed");
+        "#
+    )
+}
+
+#[test]
+fn doc_comment() {
+    assert_tokens!(
+        r#"
+/// Adds two numbers.
+///   Indented line.
+//// Not a doc comment.
+// Not a doc comment too.
+fn add(a: int, b: int): int { a + b }
+        "#
+    )
+}
+
+#[test]
+fn module_doc_comment() {
+    assert_tokens!(
+        r#"
+//! Math utilities.
+//!
+/// Adds two numbers.
+fn add(a: int, b: int): int { a + b }
+        "#
+    )
+}
+
+#[test]
+fn labels() {
+    assert_tokens!(
+        r#"
+'outer: {
+    break 'outer
+}
+        "#
+    )
+}
+
+#[test]
+fn nested_block_comment() {
+    assert_tokens!(
+        r#"
+/* outer /* inner */ still comment */
+let a = 1 /* trailing */
+/*
+    /* multi-line */
+*/
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn unclosed_block_comment() {
+    assert_tokens!(
+        r#"
+let a = 1
+/* outer /* inner */
+let b = 2
+        "#
+    )
+}
+
+#[test]
+fn number_separators() {
+    assert_tokens!(
+        r#"
+1_000_000.5
+0xFF_FF_FF
+0b1010_1010
+0o7_55
+_1
+        "#
+    )
+}
+
+// note: will report error.
+#[test]
+fn number_double_separator() {
+    assert_tokens!(
+        r#"
+1__0
+        "#
+    )
+}
+
+#[test]
+fn number_separators_are_stripped() {
+    let separated = lex_into_tokens("1_000");
+    let plain = lex_into_tokens("1000");
+    assert_eq!(separated[0].tk_type, plain[0].tk_type);
+    assert_eq!(separated[0].value, plain[0].value);
+}
+
+#[test]
+fn byte_offsets() {
+    // `é` takes two bytes in utf-8, so byte offsets
+    // of the following tokens are shifted by one.
+    let tokens = lex_into_tokens(r#"let text = "café" + name"#);
+    let string = &tokens[3];
+    assert_eq!(string.value, "café");
+    assert_eq!(string.address.span, 12..17);
+    assert_eq!(string.address.bytes, 12..18);
+    let name = &tokens[5];
+    assert_eq!(name.value, "name");
+    assert_eq!(name.address.span, 20..24);
+    assert_eq!(name.address.bytes, 21..25);
+}
@@ -1,4 +1,10 @@
 mod ast;
+#[cfg(test)]
+mod cache;
 mod codegen;
+mod fmt;
 mod lex;
+mod lints;
+#[cfg(test)]
+mod pm;
 mod utils;
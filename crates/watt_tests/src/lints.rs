@@ -0,0 +1,213 @@
+// Imports
+#[allow(unused_imports)]
+use crate::{assert_dead_code, assert_js_keyword_shadow, assert_warnings};
+
+#[test]
+fn js_keyword_function_name() {
+    assert_js_keyword_shadow!(
+        r#"
+fn from(value: int): int {
+    value
+}
+        "#
+    )
+}
+
+#[test]
+fn js_keyword_bindings() {
+    assert_js_keyword_shadow!(
+        r#"
+type Item {
+    get: int
+}
+
+fn main() {
+    let new = 1;
+    let f = fn(of: int): int { of };
+}
+        "#
+    )
+}
+
+#[test]
+fn dead_code_unreachable_fn() {
+    assert_dead_code!(
+        r#"
+pub fn used(): int = 1
+
+pub fn unused(): int = 2
+
+fn main() {
+    let a = used();
+}
+        "#
+    )
+}
+
+#[test]
+fn dead_code_const_value_is_root() {
+    assert_dead_code!(
+        r#"
+pub const fn square(x: int): int = x * x
+
+pub const nine: int = square(3)
+
+fn main() {}
+        "#
+    )
+}
+
+#[test]
+fn dead_code_main_of_other_module() {
+    assert_dead_code!(
+        r#"
+fn main() {}
+        "#,
+        dependency = r#"
+pub fn helper(): int = 1
+
+pub fn main() {
+    let a = helper();
+}
+        "#
+    )
+}
+
+#[test]
+fn shadowing_in_nested_block() {
+    assert_warnings!(
+        r#"
+fn main() {
+    let a = 1;
+    if a > 0 {
+        let a = 2;
+    }
+}
+        "#
+    )
+}
+
+#[test]
+fn shadowing_in_sibling_scopes() {
+    assert_warnings!(
+        r#"
+fn main() {
+    let flag = true;
+    if flag {
+        let a = 1;
+    } else {
+        let a = 2;
+    }
+}
+        "#
+    )
+}
+
+#[test]
+fn shadowing_disabled() {
+    assert_warnings!(
+        r#"
+fn main() {
+    let a = 1;
+    if a > 0 {
+        let a = 2;
+    }
+}
+        "#,
+        disabled = ["shadowing"]
+    )
+}
+
+#[test]
+fn shadowed_import_by_local() {
+    assert_warnings!(
+        r#"
+use dep for value
+
+fn main() {
+    let value = 2;
+    value;
+}
+        "#,
+        dependency = r#"
+pub const value: int = 1
+        "#
+    )
+}
+
+#[test]
+fn shadowed_import_by_declaration() {
+    assert_warnings!(
+        r#"
+use dep for sum, Point
+
+fn sum(a: int, b: int): int {
+    a + b
+}
+
+type Point {
+    x: int,
+    y: int
+}
+        "#,
+        dependency = r#"
+pub fn sum(a: int, b: int): int {
+    a + b
+}
+
+pub type Point {
+    x: int
+}
+        "#
+    )
+}
+
+#[test]
+fn unused_import() {
+    assert_warnings!(
+        r#"
+use dep for one, two
+
+fn main() {
+    one();
+}
+        "#,
+        dependency = r#"
+pub fn one(): int = 1
+pub fn two(): int = 2
+        "#,
+        enabled = ["unused-imports"]
+    )
+}
+
+#[test]
+fn used_import() {
+    assert_warnings!(
+        r#"
+use dep for one, two
+
+fn main() {
+    one() + two();
+}
+        "#,
+        dependency = r#"
+pub fn one(): int = 1
+pub fn two(): int = 2
+        "#,
+        enabled = ["unused-imports"]
+    )
+}
+
+#[test]
+fn unused_import_lint_is_opt_in() {
+    assert_warnings!(
+        r#"
+use dep for one
+
+fn main(): int = 2
+        "#,
+        dependency = r#"
+pub fn one(): int = 1
+        "#
+    )
+}
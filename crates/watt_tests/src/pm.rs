@@ -0,0 +1,469 @@
+// Imports
+use camino::Utf8PathBuf;
+use git2::{Repository, Signature};
+use std::time::{Duration, SystemTime};
+use watt_pm::{
+    clean, compile,
+    config::{self, PackageDependency, PackageType},
+    dependencies::{self, Package},
+    lock,
+    runtime::JsRuntime,
+    scripts,
+};
+
+/// Commits all files of the repository,
+/// returns commit hash
+fn commit_all(repo: &Repository, message: &str) -> String {
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("watt", "watt@localhost").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        message,
+        &tree,
+        &parents,
+    )
+    .unwrap()
+    .to_string()
+}
+
+/// Solves dependencies of the package at `path`
+fn solve(path: &Utf8PathBuf, update: bool) -> Vec<Package> {
+    let config = config::retrieve_config(path);
+    dependencies::solve(
+        path.join(".cache"),
+        Package {
+            name: "app".to_string(),
+            path: path.clone(),
+        },
+        &config.pkg,
+        update,
+    )
+}
+
+#[test]
+fn lockfile_pins_git_dependency() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+
+    // Git dependency
+    let lib = root.join("lib");
+    std::fs::create_dir_all(&lib).unwrap();
+    let repo = Repository::init(&lib).unwrap();
+    config::generate(&lib, "lib", PackageType::Lib, None);
+    let first = commit_all(&repo, "first");
+
+    // Dependent package
+    let app = root.join("app");
+    std::fs::create_dir_all(&app).unwrap();
+    config::generate(&app, "app", PackageType::App, None);
+    let mut app_config = config::retrieve_config(&app);
+    let url = format!("file://{lib}");
+    app_config
+        .pkg
+        .dependencies
+        .push(PackageDependency::Git(url.clone()));
+    config::save(&app, &app_config);
+
+    // First resolution locks the commit
+    let resolved = solve(&app, false);
+    assert_eq!(lock::read(&app).commit(&url), Some(first.as_str()));
+
+    // New commit of the dependency is ignored,
+    // even if dependency is downloaded again
+    std::fs::write(lib.join("README.md"), "lib").unwrap();
+    let second = commit_all(&repo, "second");
+    std::fs::remove_dir_all(app.join(".cache")).unwrap();
+    assert_eq!(solve(&app, false), resolved);
+    assert_eq!(lock::read(&app).commit(&url), Some(first.as_str()));
+    let cached = Repository::open(app.join(".cache").join("lib")).unwrap();
+    assert_eq!(cached.head().unwrap().target().unwrap().to_string(), first);
+
+    // Update resolves refs again
+    solve(&app, true);
+    assert_eq!(lock::read(&app).commit(&url), Some(second.as_str()));
+}
+
+#[test]
+fn remove_prunes_locked_git_dependency() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+
+    // Git dependency
+    let lib = root.join("lib");
+    std::fs::create_dir_all(&lib).unwrap();
+    let repo = Repository::init(&lib).unwrap();
+    config::generate(&lib, "lib", PackageType::Lib, None);
+    let commit = commit_all(&repo, "first");
+
+    // Dependent package
+    let app = root.join("app");
+    std::fs::create_dir_all(&app).unwrap();
+    config::generate(&app, "app", PackageType::App, None);
+    let url = format!("file://{lib}");
+    dependencies::add(&app, url.clone());
+    solve(&app, false);
+    assert_eq!(lock::read(&app).commit(&url), Some(commit.as_str()));
+
+    // Removal prunes the locked commit
+    dependencies::remove(&app, url.clone());
+    assert!(config::retrieve_config(&app).pkg.dependencies.is_empty());
+    assert_eq!(lock::read(&app).commit(&url), None);
+}
+
+#[cfg(unix)]
+#[test]
+fn scripts_run_with_hooks() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+
+    // Package with scripts
+    config::generate(&app, "app", PackageType::App, None);
+    let mut app_config = config::retrieve_config(&app);
+    for (name, script) in [
+        ("PRE_build", "echo pre >> log.txt"),
+        ("build", "echo build >> log.txt"),
+        ("POST_build", "echo post >> log.txt"),
+        ("PRE_lint", "exit 3"),
+        ("lint", "echo lint >> log.txt"),
+    ] {
+        app_config
+            .scripts
+            .insert(name.to_string(), script.to_string());
+    }
+    config::save(&app, &app_config);
+
+    // Hooks are ran around the script
+    assert_eq!(scripts::run(&app, "build"), 0);
+    let log = std::fs::read_to_string(app.join("log.txt")).unwrap();
+    assert_eq!(log, "pre\nbuild\npost\n");
+
+    // Failed hook stops the script
+    assert_eq!(scripts::run(&app, "lint"), 3);
+    let log = std::fs::read_to_string(app.join("log.txt")).unwrap();
+    assert_eq!(log, "pre\nbuild\npost\n");
+}
+
+/// Package config with comments
+const COMMENTED_CONFIG: &str = r#"# Application package
+[pkg]
+pkg = "app" # package type
+name = "app"
+dependencies = [
+    "../first", # first library
+]
+
+# Lints
+[lints]
+disabled = []
+enabled = []
+"#;
+
+#[test]
+fn config_save_keeps_comments() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+    std::fs::write(app.join("watt.toml"), COMMENTED_CONFIG).unwrap();
+
+    // Saving unchanged config keeps file as is
+    let app_config = config::retrieve_config(&app);
+    config::save(&app, &app_config);
+    let text = std::fs::read_to_string(app.join("watt.toml")).unwrap();
+    assert_eq!(text, COMMENTED_CONFIG);
+}
+
+#[test]
+fn config_save_edits_in_place() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+    std::fs::write(app.join("watt.toml"), COMMENTED_CONFIG).unwrap();
+
+    // Adding dependency and script
+    let mut app_config = config::retrieve_config(&app);
+    app_config.pkg.dependencies.push(PackageDependency::Local {
+        path: "../second".to_string(),
+    });
+    app_config
+        .scripts
+        .insert("build".to_string(), "echo build".to_string());
+    config::save(&app, &app_config);
+
+    // Comments are kept, and changes are saved
+    let text = std::fs::read_to_string(app.join("watt.toml")).unwrap();
+    for comment in [
+        "# Application package",
+        "# package type",
+        "# first library",
+        "# Lints",
+    ] {
+        assert!(text.contains(comment), "missing `{comment}` in:\n{text}");
+    }
+    let app_config = config::retrieve_config(&app);
+    assert_eq!(
+        app_config.pkg.dependencies,
+        vec![
+            PackageDependency::Git("../first".to_string()),
+            PackageDependency::Local {
+                path: "../second".to_string()
+            },
+        ]
+    );
+    assert_eq!(app_config.scripts["build"], "echo build");
+}
+
+#[test]
+fn tree_of_diamond_dependencies() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+
+    // Creates package with local dependencies
+    let package = |name: &str, ty: PackageType, dependencies: &[&str]| {
+        let path = root.join(name);
+        std::fs::create_dir_all(&path).unwrap();
+        config::generate(&path, name, ty, None);
+        let mut config = config::retrieve_config(&path);
+        for dependency in dependencies {
+            config.pkg.dependencies.push(PackageDependency::Local {
+                path: format!("../{dependency}"),
+            });
+        }
+        config::save(&path, &config);
+        path
+    };
+
+    // `app` depends on `left` and `right`,
+    // both of them depend on `base`
+    package("base", PackageType::Lib, &[]);
+    package("left", PackageType::Lib, &["base"]);
+    package("right", PackageType::Lib, &["base"]);
+    let app = package("app", PackageType::App, &["left", "right"]);
+
+    let config = config::retrieve_config(&app);
+    let tree = dependencies::render_tree(
+        app.join(".cache"),
+        Package {
+            name: "app".to_string(),
+            path: app.clone(),
+        },
+        &config.pkg,
+        false,
+    );
+    let expected = [
+        "app",
+        "├── left [local]",
+        "│   └── base [local] (*)",
+        "└── right [local]",
+        "    └── base [local] (*)",
+    ];
+    assert_eq!(tree, format!("{}\n", expected.join("\n")));
+}
+
+#[test]
+fn workspace_config_parses_members() {
+    let path = Utf8PathBuf::from("workspace");
+
+    // Workspace root
+    let workspace = config::parse_workspace(&path, "[workspace]\nmembers = [\"app\", \"lib\"]\n");
+    assert_eq!(workspace.unwrap().members, vec!["app", "lib"]);
+
+    // Package is not a workspace root
+    let package = "[pkg]\npkg = \"app\"\nname = \"app\"\ndependencies = []\n";
+    assert!(config::parse_workspace(&path, package).is_none());
+}
+
+/// Creates workspace with `lib` member and
+/// two `app` members, that depend on it
+fn workspace(root: &Utf8PathBuf) {
+    std::fs::write(
+        root.join("watt.toml"),
+        "[workspace]\nmembers = [\"lib\", \"first\", \"second\"]\n",
+    )
+    .unwrap();
+
+    // Library member
+    let lib = root.join("lib");
+    std::fs::create_dir_all(lib.join("lib")).unwrap();
+    config::generate(&lib, "lib", PackageType::Lib, None);
+    std::fs::write(lib.join("lib").join("util.wt"), "pub fn value(): int = 1\n").unwrap();
+
+    // Application members
+    for name in ["first", "second"] {
+        let app = root.join(name);
+        std::fs::create_dir_all(app.join(name)).unwrap();
+        config::generate(&app, name, PackageType::App, None);
+        let mut app_config = config::retrieve_config(&app);
+        app_config.pkg.dependencies.push(PackageDependency::Local {
+            path: "../lib".to_string(),
+        });
+        config::save(&app, &app_config);
+        std::fs::write(
+            app.join(name).join("main.wt"),
+            "use lib/util for value\n\npub fn main(): int = value()\n",
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn workspace_members_resolve_each_other() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().canonicalize().unwrap()).unwrap();
+    workspace(&root);
+
+    for name in ["first", "second"] {
+        let app = root.join(name);
+
+        // Member is found inside of the workspace,
+        // and shares its `.cache`
+        let (found, _) = config::find_workspace(&app).unwrap();
+        assert_eq!(found, root);
+        assert_eq!(dependencies::cache_path(&app), root.join(".cache"));
+
+        // Sibling member is resolved as local dependency
+        let resolved = solve(&app, false);
+        assert!(
+            resolved
+                .iter()
+                .any(|pkg| pkg.name == "lib" && pkg.path == root.join("lib"))
+        );
+    }
+}
+
+#[test]
+fn workspace_members_keep_own_module_hashes() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().canonicalize().unwrap()).unwrap();
+    workspace(&root);
+    let (first, second) = (root.join("first"), root.join("second"));
+    let generated = |app: &Utf8PathBuf| {
+        std::fs::read_to_string(app.join("target").join("lib").join("util.js")).unwrap()
+    };
+
+    // Building both members
+    compile::compile(first.clone(), false, false);
+    compile::compile(second.clone(), false, false);
+
+    // Changing shared library and rebuilding only the second member,
+    // the first one must not treat its stale output as fresh
+    std::fs::write(
+        root.join("lib").join("lib").join("util.wt"),
+        "pub fn value(): int = 2\n",
+    )
+    .unwrap();
+    compile::compile(second.clone(), false, false);
+    compile::compile(first.clone(), false, false);
+    assert!(generated(&first).contains("return 2"));
+    assert!(generated(&second).contains("return 2"));
+}
+
+#[test]
+fn workspace_member_is_stale_after_dependency_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().canonicalize().unwrap()).unwrap();
+    workspace(&root);
+    let first = root.join("first");
+    let generated =
+        || std::fs::read_to_string(first.join("target").join("lib").join("util.js")).unwrap();
+
+    // Building member
+    compile::build(first.clone(), false, false);
+    assert!(generated().contains("return 1"));
+
+    // Changing sibling member source, project must be rebuilt
+    let util = root.join("lib").join("lib").join("util.wt");
+    std::fs::write(&util, "pub fn value(): int = 2\n").unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(&util)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(10))
+        .unwrap();
+    compile::build(first.clone(), false, false);
+    assert!(generated().contains("return 2"));
+}
+
+#[test]
+fn project_is_stale_after_dce_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().canonicalize().unwrap()).unwrap();
+    workspace(&root);
+    let first = root.join("first");
+    std::fs::write(
+        first.join("first").join("main.wt"),
+        "use lib/util for value\n\nfn unused(): int = 3\n\npub fn main(): int = value()\n",
+    )
+    .unwrap();
+    let generated =
+        || std::fs::read_to_string(first.join("target").join("first").join("main.js")).unwrap();
+
+    // Building member with dead code elimination
+    compile::build(first.clone(), true, false);
+    assert!(!generated().contains("unused"));
+
+    // Building without dead code elimination, project must be rebuilt
+    compile::build(first.clone(), false, false);
+    assert!(generated().contains("unused"));
+}
+
+#[test]
+fn common_runtime_run_is_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().canonicalize().unwrap()).unwrap();
+    workspace(&root);
+    let first = root.join("first");
+
+    let error = std::panic::catch_unwind(|| {
+        compile::run(first.clone(), JsRuntime::Common, false, false, false)
+    })
+    .unwrap_err();
+    let message = error.downcast_ref::<String>().unwrap();
+    assert!(message.contains("pkg::runtime_could_not_run"));
+}
+
+#[test]
+fn clean_workspace_member_keeps_shared_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = Utf8PathBuf::try_from(dir.path().canonicalize().unwrap()).unwrap();
+    workspace(&root);
+    let first = root.join("first");
+    std::fs::create_dir_all(root.join(".cache")).unwrap();
+    compile::compile(first.clone(), false, false);
+
+    // Cleaning member removes only its own data
+    clean::clean(&first, false);
+    assert!(!first.join(".cache").exists());
+    assert!(!first.join("target").exists());
+    assert!(root.join(".cache").exists());
+
+    // Cleaning workspace root removes the shared cache
+    clean::clean(&root, false);
+    assert!(!root.join(".cache").exists());
+}
+
+#[test]
+fn missing_main_module_is_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+
+    // Package, which main module doesn't exist
+    config::generate(
+        &app,
+        "app",
+        PackageType::App,
+        Some("app/missing".to_string()),
+    );
+    let app_config = config::retrieve_config(&app);
+
+    let error = std::panic::catch_unwind(|| config::retrieve_main(&app, &app_config)).unwrap_err();
+    let message = error.downcast_ref::<String>().unwrap();
+    assert!(message.contains("pkg::main_not_found"));
+    assert!(message.contains("main module \"app/missing\" of package \"app\" is not found."));
+}
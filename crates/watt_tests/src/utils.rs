@@ -3,14 +3,16 @@ use camino::Utf8PathBuf;
 use ecow::EcoString;
 use id_arena::Arena;
 use miette::NamedSource;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use watt_ast::ast;
 use watt_common::package::{DraftPackage, DraftPackageLints};
-use watt_gen::gen_module;
+use watt_compile::lints::JsKeywordShadow;
+use watt_gen::{dce::ReachabilitySet, gen_module};
 use watt_lex::{lexer::Lexer, tokens::Token};
 use watt_lint::lint::LintCx;
 use watt_parse::parser::Parser;
 use watt_typeck::{
+    check::dead::{self, DEAD_CODE_LINT},
     cx::{module::ModuleCx, package::PackageCx, root::RootCx},
     typ::cx::TyCx,
 };
@@ -18,13 +20,16 @@ use watt_typeck::{
 /// Test module name used for compilation
 const TEST_MODULE_NAME: &str = "buggy";
 
+/// Test dependency module name used for compilation
+const TEST_DEPENDENCY_NAME: &str = "dep";
+
 /// Loads watt module
 #[allow(dead_code)]
-fn load_module(code: String, draft: &DraftPackage) -> ast::Module {
+fn load_module(name: &str, code: String, draft: &DraftPackage) -> ast::Module {
     // Reading code
     let code_chars: Vec<char> = code.chars().collect();
     // Creating named source for miette
-    let named_source = Arc::new(NamedSource::<String>::new(TEST_MODULE_NAME, code));
+    let named_source = Arc::new(NamedSource::<String>::new(name, code));
     // Lexing
     let lexer = Lexer::new(&code_chars, &named_source);
     let tokens = lexer.lex();
@@ -38,19 +43,23 @@ fn load_module(code: String, draft: &DraftPackage) -> ast::Module {
     ast
 }
 
-/// Compiles watt into js
+/// Compiles watt into js,
+/// optionally with dependency module, that
+/// is analyzed first, and dead code elimination
 #[allow(dead_code)]
-pub(crate) fn generate_js(code: &str) -> String {
+pub(crate) fn generate_js(code: &str, dependency: Option<&str>, dce: bool) -> String {
     // Draft package
     let draft_package = DraftPackage {
         path: Utf8PathBuf::new(),
         lints: DraftPackageLints {
             disabled: Vec::new(),
+            enabled: Vec::new(),
         },
+        main: None,
     };
     let module_name = EcoString::from(TEST_MODULE_NAME);
     // Loaded module
-    let module = load_module(code.to_string(), &draft_package);
+    let module = load_module(TEST_MODULE_NAME, code.to_string(), &draft_package);
     // Typechecking
     let mut tcx = TyCx::default();
     let mut root_cx = RootCx {
@@ -60,10 +69,24 @@ pub(crate) fn generate_js(code: &str) -> String {
         draft: draft_package,
         root: &mut root_cx,
     };
+    if let Some(dependency) = dependency {
+        let dependency_name = EcoString::from(TEST_DEPENDENCY_NAME);
+        let dependency = load_module(
+            TEST_DEPENDENCY_NAME,
+            dependency.to_string(),
+            &package_cx.draft,
+        );
+        let analyzed =
+            ModuleCx::new(&dependency, &dependency_name, &mut tcx, &package_cx).analyze();
+        package_cx.root.insert_module(analyzed);
+    }
     let mut module_cx = ModuleCx::new(&module, &module_name, &mut tcx, &package_cx);
-    let _ = module_cx.analyze();
+    let analyzed = module_cx.analyze();
     // Generating code
-    gen_module(&module_name, &module).to_file_string().unwrap()
+    let reachable = dce.then(|| ReachabilitySet::new(&module));
+    gen_module(&module_name, &module, &analyzed.hints, reachable.as_ref())
+        .to_file_string()
+        .unwrap()
 }
 
 /// Parses watt into tokens list
@@ -89,18 +112,164 @@ pub(crate) fn parse_into_ast(code: &str) -> ast::Module {
         path: Utf8PathBuf::new(),
         lints: DraftPackageLints {
             disabled: Vec::new(),
+            enabled: Vec::new(),
         },
+        main: None,
     };
     // Loaded module
-    let module = load_module(code.to_string(), &draft_package);
-    module
+    load_module(TEST_MODULE_NAME, code.to_string(), &draft_package)
+}
+
+/// Lints watt with js keyword shadow lint
+#[allow(dead_code)]
+pub(crate) fn lint_js_keyword_shadow(code: &str) -> String {
+    // Loaded module
+    let module = parse_into_ast(code);
+    // Rendering warnings
+    JsKeywordShadow::check(&module)
+        .into_iter()
+        .map(|warning| format!("{:?}", miette::Report::from(warning)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Analyzes watt with given lints config, optionally
+/// with dependency module, that is analyzed first,
+/// returns rendered warnings
+#[allow(dead_code)]
+pub(crate) fn check_warnings(
+    code: &str,
+    dependency: Option<&str>,
+    enabled: &[&str],
+    disabled: &[&str],
+) -> String {
+    // Draft package
+    let draft_package = DraftPackage {
+        path: Utf8PathBuf::new(),
+        lints: DraftPackageLints {
+            disabled: disabled.iter().map(|lint| lint.to_string()).collect(),
+            enabled: enabled.iter().map(|lint| lint.to_string()).collect(),
+        },
+        main: None,
+    };
+    let module_name = EcoString::from(TEST_MODULE_NAME);
+    // Typechecking
+    let mut tcx = TyCx::default();
+    let mut root_cx = RootCx {
+        modules: Arena::default(),
+    };
+    let package_cx = PackageCx {
+        draft: draft_package,
+        root: &mut root_cx,
+    };
+    if let Some(dependency) = dependency {
+        let dependency_name = EcoString::from(TEST_DEPENDENCY_NAME);
+        let dependency = load_module(
+            TEST_DEPENDENCY_NAME,
+            dependency.to_string(),
+            &package_cx.draft,
+        );
+        let analyzed =
+            ModuleCx::new(&dependency, &dependency_name, &mut tcx, &package_cx).analyze();
+        package_cx.root.insert_module(analyzed);
+    }
+    // Only warnings of the module are captured
+    watt_common::errors::capture_warnings();
+    // Loaded module
+    let module = load_module(TEST_MODULE_NAME, code.to_string(), &package_cx.draft);
+    ModuleCx::new(&module, &module_name, &mut tcx, &package_cx).analyze();
+    watt_common::errors::captured_warnings().join("\n")
+}
+
+/// Checks watt for the dead code, optionally with dependency
+/// module, returns rendered warnings
+#[allow(dead_code)]
+pub(crate) fn check_dead_code(code: &str, dependency: Option<&str>) -> String {
+    // Draft package
+    let draft_package = DraftPackage {
+        path: Utf8PathBuf::new(),
+        lints: DraftPackageLints {
+            disabled: Vec::new(),
+            enabled: vec![DEAD_CODE_LINT.to_string()],
+        },
+        main: Some(TEST_MODULE_NAME.to_string()),
+    };
+    // Loaded modules
+    let mut modules = HashMap::new();
+    if let Some(dependency) = dependency {
+        let module = load_module(TEST_DEPENDENCY_NAME, dependency.to_string(), &draft_package);
+        modules.insert(EcoString::from(TEST_DEPENDENCY_NAME), module);
+    }
+    let module = load_module(TEST_MODULE_NAME, code.to_string(), &draft_package);
+    modules.insert(EcoString::from(TEST_MODULE_NAME), module);
+    // Checking for dead code
+    let mut root_cx = RootCx {
+        modules: Arena::default(),
+    };
+    let package_cx = PackageCx {
+        draft: draft_package,
+        root: &mut root_cx,
+    };
+    watt_common::errors::capture_warnings();
+    dead::check_dead_code(&package_cx, &modules);
+    watt_common::errors::captured_warnings().join("\n")
+}
+
+/// Formats watt code
+#[allow(dead_code)]
+pub(crate) fn format_source(code: &str) -> String {
+    watt_fmt::format(TEST_MODULE_NAME, code.to_string())
 }
 
 /// Asserts javascript generation result.
 #[macro_export]
 macro_rules! assert_js {
+    ($src:expr $(,)?) => {{ $crate::assert_js!($src, dce = false) }};
+    ($src:expr, dce = $dce:expr $(,)?) => {{ $crate::assert_js!($src, None, $dce) }};
+    ($src:expr, dependency = $dep:expr $(,)?) => {{ $crate::assert_js!($src, Some($dep), false) }};
+    ($src:expr, $dep:expr, $dce:expr) => {{
+        watt_common::errors::capture_warnings();
+        let compiled =
+            match std::panic::catch_unwind(|| $crate::utils::generate_js($src, $dep, $dce)) {
+                Ok(result) => result,
+                Err(err) => {
+                    let panic_str = if let Some(s) = err.downcast_ref::<&str>() {
+                        (*s).to_string()
+                    } else if let Some(s) = err.downcast_ref::<String>() {
+                        s.clone()
+                    } else {
+                        "<failed to retrieve panic message>".to_string()
+                    };
+                    format!("{}", panic_str)
+                }
+            };
+        let dependency: Option<&str> = $dep;
+        let dependency = dependency
+            .map(|dep| format!("Dependency code:\n{dep}\n\n"))
+            .unwrap_or_default();
+        let warnings = watt_common::errors::captured_warnings();
+        let warnings = if warnings.is_empty() {
+            String::new()
+        } else {
+            format!("\n\nWarnings:\n{}", warnings.join("\n"))
+        };
+        let output = format!(
+            "{dependency}Source code:\n{}\n\nGeneration result:\n{compiled}{warnings}",
+            $src
+        );
+        let re = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+        let cleaned = re.replace_all(&output, "").to_string();
+        insta::assert_snapshot!(insta::internals::AutoName, cleaned, $src);
+    }};
+}
+
+/// Asserts AST parsing result.
+#[macro_export]
+macro_rules! assert_ast {
     ($src:expr $(,)?) => {{
-        let compiled = match std::panic::catch_unwind(|| $crate::utils::generate_js($src)) {
+        let ast = match std::panic::catch_unwind(|| {
+            format!("{:#?}", $crate::utils::parse_into_ast($src))
+        }) {
             Ok(result) => result,
             Err(err) => {
                 let panic_str = if let Some(s) = err.downcast_ref::<&str>() {
@@ -113,19 +282,19 @@ macro_rules! assert_js {
                 format!("{}", panic_str)
             }
         };
-        let output = format!("Source code:\n{}\n\nGeneration result:\n{compiled}", $src);
+        let output = format!("Source code:\n{}\n\nAst:\n{ast}", $src);
         let re = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
         let cleaned = re.replace_all(&output, "").to_string();
         insta::assert_snapshot!(insta::internals::AutoName, cleaned, $src);
     }};
 }
 
-/// Asserts AST parsing result.
+/// Asserts lexing result.
 #[macro_export]
-macro_rules! assert_ast {
+macro_rules! assert_tokens {
     ($src:expr $(,)?) => {{
-        let ast = match std::panic::catch_unwind(|| {
-            format!("{:#?}", $crate::utils::parse_into_ast($src))
+        let tokens = match std::panic::catch_unwind(|| {
+            format!("{:#?}", $crate::utils::lex_into_tokens($src))
         }) {
             Ok(result) => result,
             Err(err) => {
@@ -139,19 +308,57 @@ macro_rules! assert_ast {
                 format!("{}", panic_str)
             }
         };
-        let output = format!("Source code:\n{}\n\nAst:\n{ast}", $src);
+        let output = format!("Source code:\n{}\n\nTokens:\n{tokens}", $src);
         let re = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
         let cleaned = re.replace_all(&output, "").to_string();
         insta::assert_snapshot!(insta::internals::AutoName, cleaned, $src);
     }};
 }
 
-/// Asserts lexing result.
+/// Asserts js keyword shadow lint result.
 #[macro_export]
-macro_rules! assert_tokens {
+macro_rules! assert_js_keyword_shadow {
     ($src:expr $(,)?) => {{
-        let tokens = match std::panic::catch_unwind(|| {
-            format!("{:#?}", $crate::utils::lex_into_tokens($src))
+        let warnings =
+            match std::panic::catch_unwind(|| $crate::utils::lint_js_keyword_shadow($src)) {
+                Ok(result) => result,
+                Err(err) => {
+                    let panic_str = if let Some(s) = err.downcast_ref::<&str>() {
+                        (*s).to_string()
+                    } else if let Some(s) = err.downcast_ref::<String>() {
+                        s.clone()
+                    } else {
+                        "<failed to retrieve panic message>".to_string()
+                    };
+                    format!("{}", panic_str)
+                }
+            };
+        let output = format!("Source code:\n{}\n\nWarnings:\n{warnings}", $src);
+        let re = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+        let cleaned = re.replace_all(&output, "").to_string();
+        insta::assert_snapshot!(insta::internals::AutoName, cleaned, $src);
+    }};
+}
+
+/// Asserts warnings, reported during analysis.
+#[macro_export]
+macro_rules! assert_warnings {
+    ($src:expr $(,)?) => {{ $crate::assert_warnings!($src, None, &[], &[]) }};
+    ($src:expr, enabled = [$($lint:expr),*] $(,)?) => {{
+        $crate::assert_warnings!($src, None, &[$($lint),*], &[])
+    }};
+    ($src:expr, disabled = [$($lint:expr),*] $(,)?) => {{
+        $crate::assert_warnings!($src, None, &[], &[$($lint),*])
+    }};
+    ($src:expr, dependency = $dep:expr $(,)?) => {{
+        $crate::assert_warnings!($src, Some($dep), &[], &[])
+    }};
+    ($src:expr, dependency = $dep:expr, enabled = [$($lint:expr),*] $(,)?) => {{
+        $crate::assert_warnings!($src, Some($dep), &[$($lint),*], &[])
+    }};
+    ($src:expr, $dep:expr, $enabled:expr, $disabled:expr) => {{
+        let warnings = match std::panic::catch_unwind(|| {
+            $crate::utils::check_warnings($src, $dep, $enabled, $disabled)
         }) {
             Ok(result) => result,
             Err(err) => {
@@ -165,7 +372,80 @@ macro_rules! assert_tokens {
                 format!("{}", panic_str)
             }
         };
-        let output = format!("Source code:\n{}\n\nTokens:\n{tokens}", $src);
+        let dependency: Option<&str> = $dep;
+        let dependency = dependency
+            .map(|dep| format!("Dependency code:\n{dep}\n\n"))
+            .unwrap_or_default();
+        let output = format!(
+            "{dependency}Source code:\n{}\n\nWarnings:\n{warnings}",
+            $src
+        );
+        let re = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+        let cleaned = re.replace_all(&output, "").to_string();
+        insta::assert_snapshot!(insta::internals::AutoName, cleaned, $src);
+    }};
+}
+
+/// Asserts dead code check result.
+#[macro_export]
+macro_rules! assert_dead_code {
+    ($src:expr $(,)?) => {{ $crate::assert_dead_code!($src, None) }};
+    ($src:expr, dependency = $dep:expr $(,)?) => {{ $crate::assert_dead_code!($src, Some($dep)) }};
+    ($src:expr, $dep:expr) => {{
+        let warnings = match std::panic::catch_unwind(|| $crate::utils::check_dead_code($src, $dep))
+        {
+            Ok(result) => result,
+            Err(err) => {
+                let panic_str = if let Some(s) = err.downcast_ref::<&str>() {
+                    (*s).to_string()
+                } else if let Some(s) = err.downcast_ref::<String>() {
+                    s.clone()
+                } else {
+                    "<failed to retrieve panic message>".to_string()
+                };
+                format!("{}", panic_str)
+            }
+        };
+        let dependency: Option<&str> = $dep;
+        let dependency = dependency
+            .map(|dep| format!("Dependency code:\n{dep}\n\n"))
+            .unwrap_or_default();
+        let output = format!(
+            "{dependency}Source code:\n{}\n\nWarnings:\n{warnings}",
+            $src
+        );
+        let re = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+        let cleaned = re.replace_all(&output, "").to_string();
+        insta::assert_snapshot!(insta::internals::AutoName, cleaned, $src);
+    }};
+}
+
+/// Asserts formatting result,
+/// and that formatting is idempotent.
+#[macro_export]
+macro_rules! assert_fmt {
+    ($src:expr $(,)?) => {{
+        let formatted = match std::panic::catch_unwind(|| $crate::utils::format_source($src)) {
+            Ok(result) => {
+                assert_eq!(
+                    $crate::utils::format_source(&result),
+                    result,
+                    "formatting is not idempotent"
+                );
+                result
+            }
+            Err(err) => {
+                let panic_str = if let Some(s) = err.downcast_ref::<&str>() {
+                    (*s).to_string()
+                } else if let Some(s) = err.downcast_ref::<String>() {
+                    s.clone()
+                } else {
+                    "<failed to retrieve panic message>".to_string()
+                };
+                format!("{}", panic_str)
+            }
+        };
+        let output = format!("Source code:\n{}\n\nFormatted:\n{formatted}", $src);
         let re = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
         let cleaned = re.replace_all(&output, "").to_string();
         insta::assert_snapshot!(insta::internals::AutoName, cleaned, $src);
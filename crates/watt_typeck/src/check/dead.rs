@@ -0,0 +1,304 @@
+/// Imports
+use crate::{cx::package::PackageCx, warnings::TypeckWarning};
+use ecow::EcoString;
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+};
+use watt_ast::ast::{
+    Block, ChainAccess, Declaration, Either, ElseBranch, Expression, FnDeclaration, Module,
+    Pattern, Publicity, Range, Statement, UseKind,
+};
+use watt_common::warn;
+
+/// Name of the opt-in lint, that enables dead code check
+pub const DEAD_CODE_LINT: &str = "dead-code";
+
+/// Function key, represented as
+/// module name and function name
+type FnKey = (EcoString, EcoString);
+
+/// Collects functions referenced from the module code.
+///
+/// Every reference to the function counts,
+/// not only calls. So, functions passed as values
+/// are considered potentially live.
+///
+struct RefsCollector<'m> {
+    /// Current module name
+    module_name: &'m EcoString,
+    /// Current module
+    module: &'m Module,
+    /// Referenced functions
+    refs: HashSet<FnKey>,
+}
+
+/// Implementation
+impl<'m> RefsCollector<'m> {
+    /// Creates new collector
+    fn new(module_name: &'m EcoString, module: &'m Module) -> Self {
+        Self {
+            module_name,
+            module,
+            refs: HashSet::new(),
+        }
+    }
+
+    /// Adds reference to the function by its name,
+    /// name could be defined in the module or imported with `use ... for`
+    fn reference(&mut self, name: &EcoString) {
+        self.refs.insert((self.module_name.clone(), name.clone()));
+        for dependency in &self.module.dependencies {
+            if matches!(&dependency.kind, UseKind::ForNames(names) if names.contains(name)) {
+                self.refs
+                    .insert((dependency.path.module.clone(), name.clone()));
+            }
+        }
+    }
+
+    /// Adds reference to the function of the module, imported as `alias`
+    fn reference_in(&mut self, alias: &EcoString, name: &EcoString) {
+        for dependency in &self.module.dependencies {
+            if matches!(&dependency.kind, UseKind::AsName(as_name) if as_name == alias) {
+                self.refs
+                    .insert((dependency.path.module.clone(), name.clone()));
+            }
+        }
+    }
+
+    /// Collects references from the block or expression body
+    fn collect_body<E: Borrow<Expression>>(&mut self, body: &Either<Block, E>) {
+        match body {
+            Either::Left(block) => self.collect_block(block),
+            Either::Right(expr) => self.collect_expr(expr.borrow()),
+        }
+    }
+
+    /// Collects references from the block
+    fn collect_block(&mut self, block: &Block) {
+        for stmt in &block.body {
+            self.collect_stmt(stmt);
+        }
+    }
+
+    /// Collects references from the statement
+    fn collect_stmt(&mut self, stmt: &Statement) {
+        match stmt {
+            Statement::Labeled { body, .. } => self.collect_block(body),
+            Statement::VarDef { value, .. } => self.collect_expr(value),
+            Statement::VarAssign { what, value, .. } => {
+                self.collect_expr(what);
+                self.collect_expr(value);
+            }
+            Statement::ParallelAssign { what, value, .. } => {
+                for expr in what.iter().chain(value) {
+                    self.collect_expr(expr);
+                }
+            }
+            Statement::Expr(expr) | Statement::Semi(expr) => self.collect_expr(expr),
+            Statement::Throw { value, .. } => self.collect_expr(value),
+            Statement::Loop { logical, body, .. } => {
+                self.collect_expr(logical);
+                self.collect_body(body);
+            }
+            Statement::For { range, body, .. } => {
+                let (from, to) = match range.as_ref() {
+                    Range::ExcludeLast { from, to, .. } => (from, to),
+                    Range::IncludeLast { from, to, .. } => (from, to),
+                };
+                self.collect_expr(from);
+                self.collect_expr(to);
+                self.collect_body(body);
+            }
+        }
+    }
+
+    /// Collects references from the pattern guards
+    fn collect_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Or(pat1, pat2) => {
+                self.collect_pattern(pat1);
+                self.collect_pattern(pat2);
+            }
+            Pattern::Guard {
+                pattern, condition, ..
+            } => {
+                self.collect_pattern(pattern);
+                self.collect_expr(condition);
+            }
+            _ => {}
+        }
+    }
+
+    /// Collects references from the expression
+    fn collect_expr(&mut self, expr: &Expression) {
+        match expr {
+            Expression::PrefixVar { name, .. } => self.reference(name),
+            Expression::SuffixVar {
+                container, name, ..
+            } => {
+                if let Expression::PrefixVar { name: alias, .. } = container.as_ref() {
+                    self.reference_in(alias, name);
+                }
+                self.collect_expr(container);
+            }
+            Expression::Bin { left, right, .. } => {
+                self.collect_expr(left);
+                self.collect_expr(right);
+            }
+            Expression::As { value, .. }
+            | Expression::Unary { value, .. }
+            | Expression::Try { value, .. }
+            | Expression::Await { value, .. } => self.collect_expr(value),
+            Expression::Paren { expr, .. } => self.collect_expr(expr),
+            Expression::If {
+                logical,
+                body,
+                else_branches,
+                ..
+            } => {
+                self.collect_expr(logical);
+                self.collect_body(body);
+                for branch in else_branches {
+                    match branch {
+                        ElseBranch::Elif { logical, body, .. } => {
+                            self.collect_expr(logical);
+                            self.collect_body(body);
+                        }
+                        ElseBranch::Else { body, .. } => self.collect_body(body),
+                    }
+                }
+            }
+            Expression::Call { what, args, .. } => {
+                self.collect_expr(what);
+                for arg in args {
+                    self.collect_expr(arg);
+                }
+            }
+            Expression::OptionalChain { base, access, .. } => {
+                self.collect_expr(base);
+                if let ChainAccess::Call(_, args) = access {
+                    for arg in args {
+                        self.collect_expr(arg);
+                    }
+                }
+            }
+            Expression::Function { body, .. } => self.collect_body(body),
+            Expression::TryCatch { body, handler, .. } => {
+                self.collect_block(body);
+                self.collect_block(handler);
+            }
+            Expression::Match { value, cases, .. } => {
+                self.collect_expr(value);
+                for case in cases {
+                    self.collect_pattern(&case.pattern);
+                    self.collect_body(&case.body);
+                }
+            }
+            Expression::Int { .. }
+            | Expression::Float { .. }
+            | Expression::String { .. }
+            | Expression::Bool { .. }
+            | Expression::Todo { .. }
+            | Expression::Panic { .. }
+            | Expression::Break { .. } => {}
+        }
+    }
+}
+
+/// Reports `pub fn` functions, that are never reached from `main`.
+///
+/// Builds call graph of the package functions, and runs
+/// reachability analysis from the `main` function of the main
+/// module and from the constant values, that are evaluated
+/// on the module load. Package without main module is a library,
+/// so its public functions are the exported api and aren't reported.
+///
+/// # Notes
+/// Check is opt-in and does nothing,
+/// unless `dead-code` lint is enabled.
+///
+pub fn check_dead_code(pkg: &PackageCx, modules: &HashMap<EcoString, Module>) {
+    if !pkg
+        .draft
+        .lints
+        .enabled
+        .iter()
+        .any(|lint| lint == DEAD_CODE_LINT)
+    {
+        return;
+    }
+    let main = match &pkg.draft.main {
+        Some(main) => EcoString::from(main.as_str()),
+        None => return,
+    };
+
+    // Building call graph
+    let mut graph: HashMap<FnKey, (&FnDeclaration, HashSet<FnKey>)> = HashMap::new();
+    let mut stack: Vec<FnKey> = vec![(main, EcoString::from("main"))];
+    for (module_name, module) in modules {
+        for decl in &module.declarations {
+            match decl {
+                Declaration::Fn(
+                    decl @ (FnDeclaration::Function { name, body, .. }
+                    | FnDeclaration::ConstFunction { name, body, .. }),
+                ) => {
+                    let mut collector = RefsCollector::new(module_name, module);
+                    collector.collect_body(body);
+                    graph.insert((module_name.clone(), name.clone()), (decl, collector.refs));
+                }
+                // Constant values are roots, since
+                // they're evaluated on the module load
+                Declaration::Const(decl) => {
+                    let mut collector = RefsCollector::new(module_name, module);
+                    collector.collect_expr(&decl.value);
+                    stack.extend(collector.refs);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Reachability analysis
+    let mut reached: HashSet<FnKey> = HashSet::new();
+    while let Some(key) = stack.pop() {
+        if !reached.insert(key.clone()) {
+            continue;
+        }
+        if let Some((_, refs)) = graph.get(&key) {
+            stack.extend(refs.iter().filter(|r| !reached.contains(*r)).cloned());
+        }
+    }
+
+    // Reporting dead functions, sorted by module and name
+    let mut dead: Vec<(&FnKey, &FnDeclaration)> = graph
+        .iter()
+        .filter(|(key, _)| !reached.contains(*key))
+        .map(|(key, (decl, _))| (key, *decl))
+        .collect();
+    dead.sort_by_key(|(key, _)| *key);
+    for (_, decl) in dead {
+        if let FnDeclaration::Function {
+            location,
+            publicity: Publicity::Public,
+            name,
+            ..
+        }
+        | FnDeclaration::ConstFunction {
+            location,
+            publicity: Publicity::Public,
+            name,
+            ..
+        } = decl
+        {
+            warn!(
+                pkg,
+                TypeckWarning::DeadFunction {
+                    src: location.source.clone(),
+                    span: location.span.clone().into(),
+                    name: name.clone()
+                }
+            );
+        }
+    }
+}
@@ -1,10 +1,11 @@
 /// Imports
 use crate::cx::module::ModuleCx;
 use crate::typ::def::ModuleDef;
-use crate::typ::typ::{Function, Parameter, Typ, WithPublicity};
+use crate::typ::typ::{Deprecation, Function, Parameter, Typ, WithPublicity};
 use ecow::EcoString;
+use id_arena::Id;
 use watt_ast::ast;
-use watt_ast::ast::{FnDeclaration, Publicity, TypePath};
+use watt_ast::ast::{Attribute, Declaration, FnDeclaration, Publicity, TypePath};
 use watt_common::address::Address;
 
 /// Performs the “early” pass of module analysis.
@@ -20,21 +21,17 @@ use watt_common::address::Address;
 /// Only namespace entry creation happens.
 ///
 impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
-    /// Registers a function or extern function
-    /// symbol in the module before its body is analyzed.
-    ///
-    /// Everything except function body will be analyzed.
-    /// [`late_analyze_function_decl`] performs full semantic analysis.
+    /// Infers a function signature and inserts the
+    /// function into the type context.
     ///
-    pub(crate) fn early_define_fn(
+    fn early_infer_fn(
         &mut self,
         location: Address,
-        publicity: Publicity,
         generics: Vec<EcoString>,
         params: Vec<ast::Parameter>,
         typ: Option<TypePath>,
         name: EcoString,
-    ) {
+    ) -> Id<Function> {
         // Pushing generics
         let generics = self.icx.generics.push_scope(generics);
         // Generating function
@@ -51,10 +48,32 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 })
                 .collect(),
             ret: typ.map_or(Typ::Unit, |it| self.infer_type_annotation(it)),
+            is_const: false,
+            is_async: false,
+            deprecated: None,
         };
         let id = self.icx.tcx.insert_function(function);
         // Popping generics
         self.icx.generics.pop_scope();
+        id
+    }
+
+    /// Registers a function or extern function
+    /// symbol in the module before its body is analyzed.
+    ///
+    /// Everything except function body will be analyzed.
+    /// [`late_analyze_function_decl`] performs full semantic analysis.
+    ///
+    pub(crate) fn early_define_fn(
+        &mut self,
+        location: Address,
+        publicity: Publicity,
+        generics: Vec<EcoString>,
+        params: Vec<ast::Parameter>,
+        typ: Option<TypePath>,
+        name: EcoString,
+    ) -> Id<Function> {
+        let id = self.early_infer_fn(location.clone(), generics, params, typ, name.clone());
         // Defining function
         self.resolver.define_module(
             &location,
@@ -64,6 +83,52 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 value: id,
             }),
         );
+        id
+    }
+
+    /// Registers an extern function as the overload of
+    /// the overload set with the same name.
+    ///
+    /// Used only for extern functions, that share
+    /// the same name with other extern functions.
+    ///
+    pub(crate) fn early_define_overloaded_fn(
+        &mut self,
+        location: Address,
+        publicity: Publicity,
+        generics: Vec<EcoString>,
+        params: Vec<ast::Parameter>,
+        typ: Option<TypePath>,
+        name: EcoString,
+    ) -> Id<Function> {
+        let id = self.early_infer_fn(location.clone(), generics, params, typ, name.clone());
+        // Defining overload
+        self.resolver.define_overload(
+            &location,
+            &name,
+            WithPublicity {
+                publicity,
+                value: id,
+            },
+        );
+        id
+    }
+
+    /// Checks that extern function with the given name
+    /// is declared more than once in the module.
+    ///
+    fn is_overloaded_extern(&self, name: &EcoString) -> bool {
+        self.module
+            .declarations
+            .iter()
+            .filter(|decl| {
+                matches!(
+                    decl,
+                    Declaration::Fn(FnDeclaration::ExternFunction { name: n, .. }) if n == name
+                )
+            })
+            .count()
+            > 1
     }
 
     /// Dispatches early-phase analysis for any kind of function declaration.
@@ -74,7 +139,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// “late” semantic analysis runs.
     ///
     pub(crate) fn early_analyze_fn_decl(&mut self, declaration: &FnDeclaration) {
-        match declaration.clone() {
+        let (id, attributes) = match declaration.clone() {
             FnDeclaration::ExternFunction {
                 location,
                 name,
@@ -82,6 +147,20 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 generics,
                 params,
                 typ,
+                attributes,
+                ..
+            } if self.is_overloaded_extern(&name) => (
+                self.early_define_overloaded_fn(location, publicity, generics, params, typ, name),
+                attributes,
+            ),
+            FnDeclaration::ExternFunction {
+                location,
+                name,
+                publicity,
+                generics,
+                params,
+                typ,
+                attributes,
                 ..
             }
             | FnDeclaration::Function {
@@ -91,8 +170,37 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 generics,
                 params,
                 typ,
+                attributes,
+                ..
+            }
+            | FnDeclaration::ConstFunction {
+                location,
+                publicity,
+                name,
+                generics,
+                params,
+                typ,
+                attributes,
                 ..
-            } => self.early_define_fn(location, publicity, generics, params, typ, name),
+            } => (
+                self.early_define_fn(location, publicity, generics, params, typ, name),
+                attributes,
+            ),
+        };
+        // Return type of the function without annotation is a
+        // placeholder, until its body is inferred on the first use
+        // or during the late analysis
+        if let FnDeclaration::Function { typ: None, .. }
+        | FnDeclaration::ConstFunction { typ: None, .. } = declaration
+        {
+            let ret = Typ::Var(self.icx.fresh());
+            self.icx.tcx.function_mut(id).ret = ret;
+            self.unsolved.insert(id, declaration.clone());
         }
+        // Marking const and deprecated functions
+        let function = self.icx.tcx.function_mut(id);
+        function.is_const = matches!(declaration, FnDeclaration::ConstFunction { .. });
+        function.deprecated =
+            Attribute::deprecation(&attributes).map(|message| Deprecation { message });
     }
 }
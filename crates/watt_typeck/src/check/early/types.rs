@@ -1,9 +1,9 @@
 /// Imports
 use crate::cx::module::ModuleCx;
 use crate::typ::def::{ModuleDef, TypeDef};
-use crate::typ::typ::{Enum, Struct, WithPublicity};
+use crate::typ::typ::{Alias, AliasState, Deprecation, Enum, Struct, WithPublicity};
 use ecow::EcoString;
-use watt_ast::ast::{Publicity, TypeDeclaration};
+use watt_ast::ast::{Attribute, Publicity, TypeDeclaration, TypePath};
 use watt_common::address::Address;
 
 /// Implementation of the type declarations early analyse.
@@ -30,6 +30,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         publicity: Publicity,
         generics: Vec<EcoString>,
         name: EcoString,
+        deprecated: Option<Deprecation>,
     ) {
         // Pushing generics
         let generics = self.icx.generics.push_scope(generics);
@@ -40,6 +41,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             name: name.clone(),
             generics,
             fields: Vec::new(),
+            deprecated,
         };
         let id = self.icx.tcx.insert_struct(struct_);
         // Popping generics
@@ -71,6 +73,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         publicity: Publicity,
         generics: Vec<EcoString>,
         name: EcoString,
+        deprecated: Option<Deprecation>,
     ) {
         // Pushing generics
         let generics = self.icx.generics.push_scope(generics);
@@ -81,6 +84,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             name: name.clone(),
             generics,
             variants: Vec::new(),
+            deprecated,
         };
         let id = self.icx.tcx.insert_enum(enum_);
         // Popping generics
@@ -96,6 +100,38 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         );
     }
 
+    /// Registers a type alias name in the module before its target is resolved.
+    ///
+    /// The alias is inserted in the `Unresolved` state, its target
+    /// will be resolved lazily on the first usage, or during
+    /// the aliases resolution stage of the pipeline.
+    ///
+    pub(crate) fn early_define_alias(
+        &mut self,
+        location: Address,
+        publicity: Publicity,
+        name: EcoString,
+        target: TypePath,
+    ) {
+        // Generating alias
+        let alias = Alias {
+            location: location.clone(),
+            name: name.clone(),
+            target,
+            state: AliasState::Unresolved,
+        };
+        let id = self.icx.tcx.insert_alias(alias);
+        // Defining alias
+        self.resolver.define_module(
+            &location,
+            &name,
+            ModuleDef::Type(WithPublicity {
+                publicity,
+                value: TypeDef::Alias(id),
+            }),
+        );
+    }
+
     /// Dispatches early-phase definition for any kind of type declaration.
     ///
     /// Each declaration type is handled by the corresponding `early_define_*`
@@ -110,15 +146,32 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 name,
                 publicity,
                 generics,
+                attributes,
                 ..
-            } => self.early_define_struct(location, publicity, generics, name),
+            } => {
+                let deprecated =
+                    Attribute::deprecation(&attributes).map(|message| Deprecation { message });
+                self.early_define_struct(location, publicity, generics, name, deprecated)
+            }
             TypeDeclaration::Enum {
                 location,
                 name,
                 publicity,
                 generics,
+                attributes,
+                ..
+            } => {
+                let deprecated =
+                    Attribute::deprecation(&attributes).map(|message| Deprecation { message });
+                self.early_define_enum(location, publicity, generics, name, deprecated)
+            }
+            TypeDeclaration::Alias {
+                location,
+                name,
+                publicity,
+                target,
                 ..
-            } => self.early_define_enum(location, publicity, generics, name),
+            } => self.early_define_alias(location, publicity, name, target),
         }
     }
 }
@@ -11,15 +11,19 @@ use crate::{
     typ::{
         def::{ModuleDef, TypeDef},
         res::Res,
-        typ::{Function, GenericArgs, Parameter, PreludeType, Typ},
+        typ::{Deprecation, Field, Function, GenericArgs, Parameter, PreludeType, Typ},
     },
     warnings::TypeckWarning,
 };
 use ecow::EcoString;
+use id_arena::Id;
 use indexmap::IndexMap;
-use watt_ast::ast::{
-    self, BinaryOp, Block, Case, Either, ElseBranch, Expression, Pattern, Publicity, TypePath,
-    UnaryOp,
+use watt_ast::{
+    ast::{
+        self, BinaryOp, Block, Case, ChainAccess, Either, ElseBranch, Expression, Pattern,
+        Publicity, TypePath, UnaryOp,
+    },
+    hints::{CatchKind, TryKind},
 };
 use watt_common::{address::Address, bail, skip, warn};
 
@@ -122,6 +126,126 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         }
     }
 
+    /// Infers the type of division expression.
+    ///
+    /// This function:
+    /// - Checks that both the left and right operands are numeric.
+    /// - Produces `Typ::Float`, or emits a `TypeckError::InvalidBinaryOp`.
+    ///
+    /// # Parameters
+    /// - `location`: Source code address of the binary operator.
+    /// - `left`: Left-hand side type.
+    /// - `op`: Binary operator used for the diagnostics.
+    /// - `right`: Right-hand side type.
+    ///
+    /// # Returns
+    /// - `Typ::Float`
+    ///
+    /// # Notes
+    /// Division of two ints is `Float` too, because it could have a
+    /// fractional part. Integer division is performed by `~/` operator.
+    ///
+    fn infer_binary_division(
+        &mut self,
+        location: Address,
+        left: Typ,
+        op: BinaryOp,
+        right: Typ,
+    ) -> Typ {
+        // Checking operands are numeric
+        self.infer_binary_arithmetical(location, left, op, right);
+        Typ::Prelude(PreludeType::Float)
+    }
+
+    /// Infers the type of shift and integer division expression.
+    ///
+    /// This function:
+    /// - Checks that both the left and right operands are `Typ::Int`.
+    /// - Checks that the known shift amount isn't negative.
+    /// - Produces the resulting type, or emits a `TypeckError::InvalidBinaryOp`.
+    ///
+    /// # Parameters
+    /// - `location`: Source code address of the binary operator.
+    /// - `left`: Left-hand side type.
+    /// - `op`: Binary operator used for the diagnostics.
+    /// - `right`: Right-hand side type.
+    /// - `amount`: Value of the right-hand side,
+    ///   if it's a shift by the known amount.
+    ///
+    /// # Returns
+    /// - `Typ::Int`
+    ///
+    /// # Errors
+    /// - [`NegativeShift`]: if the known shift amount is negative.
+    ///
+    fn infer_binary_shift(
+        &mut self,
+        location: Address,
+        left: Typ,
+        op: BinaryOp,
+        right: Typ,
+        amount: Option<i64>,
+    ) -> Typ {
+        // Checking prelude types
+        match (&left, &right) {
+            (Typ::Prelude(PreludeType::Int), Typ::Prelude(PreludeType::Int)) => {
+                if let Some(amount) = amount
+                    && amount < 0
+                {
+                    bail!(TypeckError::NegativeShift {
+                        src: self.module.source.clone(),
+                        span: location.span.into(),
+                        amount
+                    })
+                }
+                Typ::Prelude(PreludeType::Int)
+            }
+            _ => bail!(TypeckError::InvalidBinaryOp {
+                src: self.module.source.clone(),
+                span: location.span.into(),
+                a: left.pretty(&mut self.icx),
+                b: right.pretty(&mut self.icx),
+                op
+            }),
+        }
+    }
+
+    /// Evaluates integer expression, that is known without
+    /// evaluation of the calls: literals, module constants,
+    /// negation, addition, subtraction and multiplication.
+    ///
+    /// Returns `None` if the value isn't known, or overflows.
+    ///
+    pub(crate) fn eval_const_int(&mut self, expr: &Expression) -> Option<i64> {
+        match expr {
+            Expression::Int { value, .. } => value.parse().ok(),
+            Expression::PrefixVar { location, name } => {
+                match self.resolver.resolve(location, name) {
+                    Res::Const(_) => self.const_ints.get(name).copied(),
+                    _ => None,
+                }
+            }
+            Expression::Paren { expr, .. } => self.eval_const_int(expr),
+            Expression::Unary {
+                op: UnaryOp::Neg,
+                value,
+                ..
+            } => self.eval_const_int(value)?.checked_neg(),
+            Expression::Bin {
+                op, left, right, ..
+            } => {
+                let (a, b) = (self.eval_const_int(left)?, self.eval_const_int(right)?);
+                match op {
+                    BinaryOp::Add => a.checked_add(b),
+                    BinaryOp::Sub => a.checked_sub(b),
+                    BinaryOp::Mul => a.checked_mul(b),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// Infers the type of logical expression.
     ///
     /// This function:
@@ -234,9 +358,12 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// - [`InvalidBinaryOp`]: when operand types do not match operator requirements.
     ///
     /// # Notes
+    /// Operand of unknown type is inferred as the prelude type of the other operand.
+    ///
     /// This function handles:
     /// - String concatenation (`<>`)
     /// - Arithmetic operators (`+`, `-`, `*`, `/`, `%`, `&`, `|`)
+    /// - Shift operators (`<<`, `>>`)
     /// - Logical operators (`&&`, `||`, `^`)
     /// - Comparison operators (`<`, `<=`, `>`, `>=`)
     /// - Equality (`==`, `!=`)
@@ -248,10 +375,30 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         left: Expression,
         right: Expression,
     ) -> Typ {
+        // Known shift amount, checked after the operand types
+        let amount = match op {
+            BinaryOp::Shl | BinaryOp::Shr => self.eval_const_int(&right),
+            _ => None,
+        };
+
         // Inferencing left and right types
         let left = self.infer_expr(left);
         let right = self.infer_expr(right);
 
+        // Operand of unknown type, e.g. `todo`
+        // placeholder, has type of the other operand
+        let (left, right) = match (left, right) {
+            (Typ::Var(id), right @ Typ::Prelude(_)) => {
+                self.icx.substitute(id, right.clone());
+                (right.clone(), right)
+            }
+            (left @ Typ::Prelude(_), Typ::Var(id)) => {
+                self.icx.substitute(id, left.clone());
+                (left.clone(), left)
+            }
+            operands => operands,
+        };
+
         // Matching operator
         match op {
             // Concat
@@ -260,10 +407,15 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             BinaryOp::Add
             | BinaryOp::Sub
             | BinaryOp::Mul
-            | BinaryOp::Div
             | BinaryOp::BitwiseAnd
             | BinaryOp::BitwiseOr
             | BinaryOp::Mod => self.infer_binary_arithmetical(location, left, op, right),
+            // Division
+            BinaryOp::Div => self.infer_binary_division(location, left, op, right),
+            // Shift and integer division
+            BinaryOp::Shl | BinaryOp::Shr | BinaryOp::IntDiv => {
+                self.infer_binary_shift(location, left, op, right, amount)
+            }
             // Logical
             BinaryOp::Xor | BinaryOp::And | BinaryOp::Or => {
                 self.infer_binary_logical(location, left, op, right)
@@ -405,8 +557,14 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// # Errors
     /// Emitted indirectly through `resolver.resolve` when a symbol is not found.
     ///
-    fn infer_get(&self, location: Address, name: EcoString) -> Res {
-        self.resolver.resolve(&location, &name)
+    fn infer_get(&mut self, location: Address, name: EcoString) -> Res {
+        let resolved = self.resolver.resolve(&location, &name);
+        // Function without return type annotation
+        // is inferred on its first use
+        if let Res::Value(Typ::Function(id, _)) = resolved {
+            self.infer_fn_on_use(id);
+        }
+        resolved
     }
 
     /// Resolves a field access on a module (e.g. `Module.field`).
@@ -419,6 +577,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     ///     - `Type`  → `Res::Custom`
     ///     - `Const` → `Res::Value`
     ///     - `Function` → `Res::Value` containing a function type.
+    ///     - `OverloadedFn` → `Res::Overloaded` with public overloads.
     ///
     /// # Parameters
     /// - `field_module`: Name of the module.
@@ -484,6 +643,24 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                             }),
                         }
                     }
+                    ModuleDef::OverloadedFn(fns) => {
+                        // Only public overloads are visible,
+                        // indices of the overloads are kept
+                        let public: Vec<_> = fns
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, f)| f.publicity == Publicity::Public)
+                            .map(|(idx, f)| (idx, f.value))
+                            .collect();
+                        if public.is_empty() {
+                            bail!(TypeckError::ModuleFieldIsPrivate {
+                                src: self.module.source.clone(),
+                                span: field_location.span.into(),
+                                name: field_name
+                            })
+                        }
+                        Res::Overloaded(public)
+                    }
                 },
                 // Else, raising `module field is not defined`
                 None => bail!(TypeckError::ModuleFieldIsNotDefined {
@@ -609,6 +786,22 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     ) -> Res {
         // Inferring container
         let container_inferred = self.infer_resolution(container);
+        self.infer_field_access_in(field_location, container_inferred, field_name)
+    }
+
+    /// Infers field access in the already resolved container.
+    ///
+    /// # Parameters
+    /// - `field_location`: Location of the field access.
+    /// - `container_inferred`: Resolved left-hand side of `.`.
+    /// - `field_name`: Requested field.
+    ///
+    fn infer_field_access_in(
+        &mut self,
+        field_location: Address,
+        container_inferred: Res,
+        field_name: EcoString,
+    ) -> Res {
         match &container_inferred {
             // Module field access
             Res::Module(name) => {
@@ -657,6 +850,112 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         }
     }
 
+    /// Infers the type of function call.
+    ///
+    /// The function signature is instantiated with the given
+    /// generic arguments and each argument is checked to be
+    /// a subtype of the corresponding parameter.
+    ///
+    /// Returns a `Res::Value` with the instantiated return type.
+    ///
+    fn infer_function_call(
+        &mut self,
+        location: Address,
+        id: Id<Function>,
+        generic_args: GenericArgs,
+        args: Vec<(Address, Typ)>,
+    ) -> Res {
+        let function = self.icx.tcx.function(id);
+        let generics = function.generics.clone();
+        self.ensure_arity(location, function.params.len(), args.len());
+
+        let instantiated = Typ::Function(
+            id,
+            self.icx
+                .mk_fresh_generics_m(&generics, generic_args.subtitutions),
+        );
+
+        instantiated
+            .params(&mut self.icx)
+            .into_iter()
+            .zip(args)
+            .for_each(|(p, a)| {
+                coercion::coerce(
+                    &mut self.icx,
+                    Cause::FunctionArgument(&a.0),
+                    Coercion::Subtype(a.1, p.typ),
+                );
+            });
+
+        Res::Value(instantiated.ret(&mut self.icx))
+    }
+
+    /// Selects the overload of the extern function by arguments types.
+    ///
+    /// Overload matches, if its arity is equal to the arguments count
+    /// and each parameter type unifies with the argument type. Unification
+    /// is tried on the snapshot of the inference context, so it isn't committed,
+    /// and unresolved type variables of the arguments match any parameter.
+    /// Generic parameters are instantiated with fresh type variables.
+    /// No implicit coercions are performed.
+    ///
+    /// Returns the index of the selected overload
+    /// in the declaration order and its id.
+    ///
+    /// # Errors
+    /// - [`TypeckError::NoMatchingOverload`]: no overload matches arguments.
+    /// - [`TypeckError::AmbiguousOverload`]: more than one overload matches arguments.
+    ///
+    fn select_overload(
+        &mut self,
+        location: &Address,
+        fns: Vec<(usize, Id<Function>)>,
+        args: &[(Address, Typ)],
+    ) -> (usize, Id<Function>) {
+        let args: Vec<Typ> = args.iter().map(|a| self.icx.apply(a.1.clone())).collect();
+        let matching: Vec<(usize, Id<Function>)> = fns
+            .iter()
+            .copied()
+            .filter(|(_, id)| {
+                let function = self.icx.tcx.function(*id);
+                if function.params.len() != args.len() {
+                    return false;
+                }
+                let generics = function.generics.clone();
+                // Trying unification on the snapshot
+                let snapshot = self.icx.snapshot();
+                let instantiated = Typ::Function(*id, self.icx.mk_fresh_generics(&generics));
+                let matches = instantiated
+                    .params(&mut self.icx)
+                    .into_iter()
+                    .zip(&args)
+                    .all(|(p, a)| coercion::unify(&mut self.icx, p.typ, a.clone()).is_ok());
+                self.icx.rollback(snapshot);
+                matches
+            })
+            .collect();
+
+        match matching.as_slice() {
+            [selected] => *selected,
+            [] => bail!(TypeckError::NoMatchingOverload {
+                src: self.module.source.clone(),
+                span: location.span.clone().into(),
+                name: self.icx.tcx.function(fns[0].1).name.clone(),
+                args: args
+                    .iter()
+                    .map(|a| a.pretty(&mut self.icx))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            }),
+            _ => bail!(TypeckError::AmbiguousOverload {
+                src: self.module.source.clone(),
+                span: location.span.clone().into(),
+                name: self.icx.tcx.function(matching[0].1).name.clone(),
+                count: matching.len()
+            }),
+        }
+    }
+
     /// Infers the type of function or constructor call.
     ///
     /// This routine performs three major tasks:
@@ -678,6 +977,10 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// is instantiated via [`Hydrator::mk_function`] and each parameter is unified with
     /// the corresponding argument expression.
     ///
+    /// ### Overloaded extern function call
+    /// If the callee resolves to an overload set (`Res::Overloaded`), the overload
+    /// is selected by [`select_overload`] and called like a regular function.
+    ///
     /// ### Enum variant construction
     /// If the callee is an enum variant (`Res::Variant`), each variant field is unified
     /// with its corresponding argument expression. We don't instantiate the enum,
@@ -696,6 +999,15 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         args: Vec<Expression>,
     ) -> Res {
         let function = self.infer_resolution(what);
+        self.infer_call_of(location, function, args)
+    }
+
+    /// Infers the call of the already resolved callee.
+    ///
+    /// See [`infer_call`] for the supported callees.
+    ///
+    fn infer_call_of(&mut self, location: Address, function: Res, args: Vec<Expression>) -> Res {
+        let awaited = self.awaited.contains(&location.span);
         let args = args
             .into_iter()
             .map(|a| (a.location(), self.infer_expr(a)))
@@ -705,6 +1017,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             // Custom type
             Res::Custom(TypeDef::Struct(id)) => {
                 let struct_ = self.icx.tcx.struct_(id);
+                self.warn_deprecated(&location, &struct_.name, &struct_.deprecated);
                 let generics = struct_.generics.clone();
                 self.ensure_arity(location, struct_.fields.len(), args.len());
 
@@ -718,7 +1031,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                         coercion::coerce(
                             &mut self.icx,
                             Cause::StructArgument(&a.0),
-                            Coercion::Eq(p.typ, a.1),
+                            Coercion::Assign(a.1, p.typ),
                         );
                     });
 
@@ -726,37 +1039,33 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             }
             // Value
             Res::Value(Typ::Function(id, generic_args)) => {
-                let function = self.icx.tcx.function(id);
-                let generics = function.generics.clone();
-                self.ensure_arity(location, function.params.len(), args.len());
-
-                let instantiated = Typ::Function(
-                    id,
-                    self.icx
-                        .mk_fresh_generics_m(&generics, generic_args.subtitutions),
-                );
-
-                instantiated
-                    .params(&mut self.icx)
-                    .into_iter()
-                    .zip(args)
-                    .for_each(|(p, a)| {
-                        coercion::coerce(
-                            &mut self.icx,
-                            Cause::FunctionArgument(&a.0),
-                            Coercion::Eq(p.typ, a.1),
-                        );
-                    });
-
-                Res::Value(instantiated.ret(&mut self.icx))
+                self.warn_deprecated_fn(&location, id);
+                self.ensure_const_call(&location, id);
+                if !awaited {
+                    self.unawaited.push((location.clone(), id));
+                }
+                self.infer_function_call(location, id, generic_args, args)
+            }
+            // Overloaded extern function
+            Res::Overloaded(fns) => {
+                // Selected overload is called directly by its index
+                let (idx, id) = self.select_overload(&location, fns, &args);
+                self.hints.overloads.insert(location.span.clone(), idx);
+                self.warn_deprecated_fn(&location, id);
+                self.ensure_const_call(&location, id);
+                self.infer_function_call(location, id, GenericArgs::default(), args)
             }
             // Variant
             Res::Variant(en, variant) => {
+                if let Typ::Enum(id, _) = en {
+                    let enum_ = self.icx.tcx.enum_(id);
+                    self.warn_deprecated(&location, &enum_.name, &enum_.deprecated);
+                }
                 variant.fields.iter().cloned().zip(args).for_each(|(p, a)| {
                     coercion::coerce(
                         &mut self.icx,
                         Cause::VariantArgument(&a.0),
-                        Coercion::Eq(p.typ, a.1),
+                        Coercion::Assign(a.1, p.typ),
                     );
                 });
 
@@ -770,6 +1079,95 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         }
     }
 
+    /// Ensures that function could be called
+    /// from the const function, if it's being inferred.
+    ///
+    fn ensure_const_call(&self, location: &Address, id: Id<Function>) {
+        let function = self.icx.tcx.function(id);
+        if self.in_const_fn && !function.is_const {
+            bail!(TypeckError::NonConstCall {
+                src: self.module.source.clone(),
+                span: location.span.clone().into(),
+                name: function.name.clone(),
+            })
+        }
+    }
+
+    /// Warns about the call of the deprecated function
+    fn warn_deprecated_fn(&self, location: &Address, id: Id<Function>) {
+        let function = self.icx.tcx.function(id);
+        self.warn_deprecated(location, &function.name, &function.deprecated);
+    }
+
+    /// Warns about the call of the deprecated function or type
+    fn warn_deprecated(
+        &self,
+        location: &Address,
+        name: &EcoString,
+        deprecated: &Option<Deprecation>,
+    ) {
+        if let Some(deprecated) = deprecated {
+            warn!(
+                self.package,
+                TypeckWarning::DeprecatedCall {
+                    src: self.module.source.clone(),
+                    span: location.span.clone().into(),
+                    name: name.clone(),
+                    message: deprecated.message.clone(),
+                }
+            );
+        }
+    }
+
+    /// Infers optional chain expression.
+    ///
+    /// If the base is `Optional[T]`, access is resolved in `T`,
+    /// and the result becomes optional, because the whole chain
+    /// short-circuits to `null`. Otherwise, the optional access
+    /// resolves exactly like the regular field access or call.
+    ///
+    /// # Parameters
+    /// - `location`: Location of the optional chain.
+    /// - `base`: Expression on the left-hand side of `?.`.
+    /// - `access`: Requested field or method call.
+    ///
+    /// # Returns
+    /// - `Res` representing the resolved field or call result.
+    ///
+    fn infer_optional_chain(
+        &mut self,
+        location: Address,
+        base: Expression,
+        access: ChainAccess,
+    ) -> Res {
+        match self.infer_resolution(base) {
+            Res::Value(typ) => match self.icx.apply(typ) {
+                Typ::Optional(inner) => {
+                    match self.infer_chain_access(location, Res::Value(*inner), access) {
+                        Res::Value(typ) => match self.icx.apply(typ) {
+                            it @ Typ::Optional(_) => Res::Value(it),
+                            it => Res::Value(Typ::Optional(Box::new(it))),
+                        },
+                        res => res,
+                    }
+                }
+                typ => self.infer_chain_access(location, Res::Value(typ), access),
+            },
+            res => self.infer_chain_access(location, res, access),
+        }
+    }
+
+    /// Infers optional chain access in the already resolved base.
+    fn infer_chain_access(&mut self, location: Address, base: Res, access: ChainAccess) -> Res {
+        match access {
+            ChainAccess::Field(name) => self.infer_field_access_in(location, base, name),
+            ChainAccess::Call(name, args) => {
+                let function = self.infer_field_access_in(location.clone(), base, name);
+                self.infer_call_of(location, function, args)
+            }
+        }
+    }
+
     /// Performs name/field resolution on an expression that appears in a "call position".
     ///
     /// This function is responsible only for *resolving what the expression refers to*.
@@ -778,6 +1176,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// Supported resolution forms:
     /// - `PrefixVar`: simple variable access,
     /// - `SuffixVar`: field access (`a.b`),
+    /// - `OptionalChain`: optional field access or call (`a?.b`, `a?.b()`),
     /// - nested calls (`f(x)(y)`), which recursively call [`infer_call`].
     ///
     /// Any other expression that cannot denote a callable value or a namespace entry
@@ -799,6 +1198,11 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 what,
                 args,
             } => self.infer_call(location.clone(), *what, args),
+            Expression::OptionalChain {
+                location,
+                base,
+                access,
+            } => self.infer_optional_chain(location, *base, access),
             expr => bail!(TypeckError::UnexpectedExprInResolution {
                 expr: format!("{expr:?}").into()
             }),
@@ -859,26 +1263,38 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             generics: Vec::new(),
             params: params.clone().into_values().collect(),
             ret: ret.clone(),
+            is_const: false,
+            is_async: false,
+            deprecated: None,
         };
         let id = self.icx.tcx.insert_function(function);
 
         // pushing new scope
-        self.resolver.push_rib();
+        self.resolver.push_fn_rib();
 
         // defining params in new scope
         params
             .into_iter()
-            .for_each(|p| self.resolver.define_local(&location, &p.0, p.1.typ));
+            .for_each(|p| self.define_local(&location, &p.0, p.1.typ));
 
-        // inferring body
+        // inferring body, `await` is allowed in the anonymous
+        // function inside guard, and outer labels are unavailable
+        self.returns.push((location.clone(), ret.clone()));
+        let in_guard = std::mem::replace(&mut self.in_guard, false);
+        let labels = std::mem::take(&mut self.labels);
+        let awaits = std::mem::replace(&mut self.awaits, false);
         let (block_location, inferred_block) = match body {
             Either::Left(block) => (block.location.clone(), self.infer_block(block)),
             Either::Right(expr) => (expr.location(), self.infer_expr(*expr)),
         };
+        self.icx.tcx.function_mut(id).is_async = std::mem::replace(&mut self.awaits, awaits);
+        self.labels = labels;
+        self.in_guard = in_guard;
+        self.returns.pop();
         coercion::coerce(
             &mut self.icx,
-            Cause::Return(&block_location, &location),
-            Coercion::Eq(inferred_block, ret),
+            Cause::Return(&location, &block_location),
+            Coercion::Assign(inferred_block, ret),
         );
         self.resolver.pop_rib();
 
@@ -897,12 +1313,14 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// - Ensures literals (`Int`, `Float`, etc.) match the expected type,
     /// - Handles wildcards (`_`) and variable binding patterns,
     /// - Recursively validates `pat1 | pat2`
+    /// - Ensures guard condition is `bool`, with pattern bindings in scope
     ///
     /// ### Errors:
     /// - [`TypeckError::TypesMissmatch`] — literal or variant does not match the scrutinee type.
     /// - [`TypeckError::WrongUnwrapPattern`] — using `.field` pattern on non-variant.
     /// - [`TypeckError::EnumVariantFieldIsNotDefined`] — non-existent field in variant.
     /// - [`TypeckError::WrongVariantPattern`] — non-variant used where variant pattern expected.
+    /// - [`TypeckError::ExpectedLogicalInGuard`] — guard condition is not `bool`.
     ///
     /// This function may introduce new local bindings (for `BindTo`) into the current rib.
     ///
@@ -940,11 +1358,9 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                                 // it was already instantiated by instantiating the enum
                                 // itself and getting fresh enum variant
                                 // during variant resolution.
-                                Some(it) => self.resolver.define_local(
-                                    &case.address,
-                                    &it.name,
-                                    it.typ.clone(),
-                                ),
+                                Some(it) => {
+                                    self.define_local(&case.address, &it.name, it.typ.clone())
+                                }
                                 None => bail!(TypeckError::EnumVariantFieldIsNotDefined {
                                     src: self.module.source.clone(),
                                     span: field.0.span.into(),
@@ -1019,13 +1435,31 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 }
             }
             Pattern::BindTo(address, name) => {
-                self.resolver
-                    .define_local(&address, &name, inferred_what.clone());
+                self.define_local(&address, &name, inferred_what.clone());
             }
             Pattern::Or(pat1, pat2) => {
                 self.analyze_pattern(what_address.clone(), inferred_what.clone(), case, &pat1);
                 self.analyze_pattern(what_address, inferred_what, case, &pat2);
             }
+            Pattern::Guard {
+                pattern, condition, ..
+            } => {
+                // analyzing pattern, its bindings
+                // are visible in the condition
+                self.analyze_pattern(what_address, inferred_what, case, &pattern);
+                // inferring condition
+                let condition_location = condition.location();
+                let in_guard = std::mem::replace(&mut self.in_guard, true);
+                let inferred_condition = self.infer_expr(condition);
+                self.in_guard = in_guard;
+                match self.icx.apply(inferred_condition) {
+                    Typ::Prelude(PreludeType::Bool) => {}
+                    _ => bail!(TypeckError::ExpectedLogicalInGuard {
+                        src: self.module.source.clone(),
+                        span: condition_location.span.into()
+                    }),
+                }
+            }
         }
     }
 
@@ -1054,6 +1488,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         location: Address,
         what: Expression,
         cases: Vec<Case>,
+        return_path: Option<(Address, Typ)>,
     ) -> Typ {
         // inferring matchable
         let what_location = what.location();
@@ -1072,27 +1507,24 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 &case.pattern,
             );
             // analyzing body
+            self.return_path = return_path.clone();
             let (case_location, inferred_case) = match case.body {
                 Either::Left(block) => (block.location.clone(), self.infer_block(block)),
-                Either::Right(expr) => (expr.location(), self.infer_expr(expr)),
+                Either::Right(expr) => (expr.location(), self.infer_diverging(expr)),
             };
             to_unify.push((case_location, inferred_case));
             // pattern scope end
             self.resolver.pop_rib();
         }
-        // solving types
-        let fresh = Typ::Var(self.icx.fresh());
-        for branch in to_unify {
-            coercion::coerce(
-                &mut self.icx,
-                Cause::Branch(&location, &branch.0),
-                Coercion::Eq(fresh.clone(), branch.1),
-            );
-        }
+        // solving types, match, which cases all diverge, diverges too
+        let diverges = !to_unify.is_empty() && to_unify.iter().all(|branch| branch.1 == Typ::Never);
+        let unified = self.unify_branches(&location, to_unify, return_path);
         let checked = ExMatchCx::check(self, inferred_what, cases);
         // checking all cases covered
-        if checked {
-            self.icx.apply(fresh)
+        if checked && diverges {
+            Typ::Never
+        } else if checked {
+            unified
         } else {
             warn!(
                 self.package,
@@ -1105,6 +1537,44 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         }
     }
 
+    /// Unifies types of the branches of the branching expression.
+    ///
+    /// Branches of the returned expression aren't unified together,
+    /// instead each branch is checked against the return type, so
+    /// mismatch is reported at the offending branch, and every
+    /// branch could be coerced into the return type separately.
+    ///
+    fn unify_branches(
+        &mut self,
+        location: &Address,
+        branches: Vec<(Address, Typ)>,
+        return_path: Option<(Address, Typ)>,
+    ) -> Typ {
+        match return_path {
+            Some((fn_location, ret)) => {
+                for branch in branches {
+                    coercion::coerce(
+                        &mut self.icx,
+                        Cause::Return(&fn_location, &branch.0),
+                        Coercion::Assign(branch.1, ret.clone()),
+                    );
+                }
+                ret
+            }
+            None => {
+                let fresh = Typ::Var(self.icx.fresh());
+                for branch in branches {
+                    coercion::coerce(
+                        &mut self.icx,
+                        Cause::Branch(location, &branch.0),
+                        Coercion::Eq(fresh.clone(), branch.1),
+                    );
+                }
+                self.icx.apply(fresh)
+            }
+        }
+    }
+
     /// Infers the type of `if`/`elif`/`else` chain.
     ///
     /// ### Logical expression
@@ -1128,7 +1598,15 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         logical: Expression,
         body: Either<Block, Box<Expression>>,
         else_branches: Vec<ElseBranch>,
+        return_path: Option<(Address, Typ)>,
     ) -> Typ {
+        // `if` without `else` evaluates to `Unit`,
+        // so its branches are not return paths
+        let return_path = return_path.filter(|_| {
+            else_branches
+                .iter()
+                .any(|branch| matches!(branch, ElseBranch::Else { .. }))
+        });
         // pushing rib
         self.resolver.push_rib();
         // inferring logical
@@ -1143,9 +1621,10 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             }
         }
         // inferring block
+        self.return_path = return_path.clone();
         let (if_location, inferred_if) = match body {
             Either::Left(block) => (block.location.clone(), self.infer_block(block)),
-            Either::Right(expr) => (expr.location(), self.infer_expr(*expr)),
+            Either::Right(expr) => (expr.location(), self.infer_diverging(*expr)),
         };
         let mut to_unify = vec![(if_location, inferred_if)];
         // popping rib
@@ -1158,7 +1637,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 ElseBranch::Elif { logical, body, .. } => {
                     // inferring logical
                     let logical_location = logical.location();
-                    let inferred_logical = self.infer_expr(logical);
+                    let inferred_logical = self.infer_expr(*logical);
                     match inferred_logical {
                         Typ::Prelude(PreludeType::Bool) => {}
                         _ => {
@@ -1169,17 +1648,19 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                         }
                     }
                     // inferring block
+                    self.return_path = return_path.clone();
                     let (branch_location, inferred_branch) = match body {
                         Either::Left(block) => (block.location.clone(), self.infer_block(block)),
-                        Either::Right(expr) => (expr.location(), self.infer_expr(expr)),
+                        Either::Right(expr) => (expr.location(), self.infer_diverging(expr)),
                     };
                     to_unify.push((branch_location, inferred_branch));
                 }
                 ElseBranch::Else { body, .. } => {
                     // inferring block
+                    self.return_path = return_path.clone();
                     let (branch_location, inferred_branch) = match body {
                         Either::Left(block) => (block.location.clone(), self.infer_block(block)),
-                        Either::Right(expr) => (expr.location(), self.infer_expr(expr)),
+                        Either::Right(expr) => (expr.location(), self.infer_diverging(expr)),
                     };
                     to_unify.push((branch_location, inferred_branch));
                     else_reached = true;
@@ -1188,21 +1669,233 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         }
         // checking else reached
         if else_reached {
-            let fresh = Typ::Var(self.icx.fresh());
-            for branch in to_unify {
-                coercion::coerce(
-                    &mut self.icx,
-                    Cause::Branch(&location, &branch.0),
-                    Coercion::Eq(fresh.clone(), branch.1),
-                );
-            }
-            self.icx.apply(fresh)
+            // `if`, which branches all diverge, diverges too
+            let diverges = to_unify.iter().all(|branch| branch.1 == Typ::Never);
+            let unified = self.unify_branches(&location, to_unify, return_path);
+            if diverges { Typ::Never } else { unified }
         } else {
             // todo: error
             Typ::Unit
         }
     }
 
+    /// Infers the type of `try`/`catch` expression.
+    ///
+    /// ### Scoping
+    /// Both `try` body and `catch` handler introduce a new rib.
+    /// Caught value is bound in the handler rib, its type is the
+    /// annotated one or `string` by default. Only values of this
+    /// type are caught, the others are rethrown at runtime,
+    /// so the type is recorded to the [`CatchKind`] hint.
+    ///
+    /// Returns:
+    /// - The unified type of the body and the handler.
+    ///
+    /// Errors:
+    /// - [`TypeckError::InvalidCatchType`] if the annotated type
+    ///   is neither `string`, nor a non-generic struct.
+    ///
+    fn infer_try_catch(
+        &mut self,
+        location: Address,
+        body: Block,
+        bind: EcoString,
+        typ: Option<TypePath>,
+        handler: Block,
+    ) -> Typ {
+        // inferring body
+        self.resolver.push_rib();
+        let body_location = body.location.clone();
+        let inferred_body = self.infer_block(body);
+        self.resolver.pop_rib();
+        // inferring handler
+        self.resolver.push_rib();
+        let (caught, kind) = match typ {
+            Some(typ) => {
+                let typ_location = typ.location();
+                match self.infer_type_annotation(typ) {
+                    Typ::Prelude(PreludeType::String) => {
+                        (Typ::Prelude(PreludeType::String), CatchKind::String)
+                    }
+                    Typ::Struct(id, args) if args.subtitutions.is_empty() => {
+                        let name = self.icx.tcx.struct_(id).name.clone();
+                        (Typ::Struct(id, args), CatchKind::Struct { name })
+                    }
+                    other => bail!(TypeckError::InvalidCatchType {
+                        src: typ_location.source,
+                        span: typ_location.span.into(),
+                        t: other.pretty(&mut self.icx)
+                    }),
+                }
+            }
+            None => (Typ::Prelude(PreludeType::String), CatchKind::String),
+        };
+        self.hints.catches.insert(location.span.clone(), kind);
+        self.define_local(&location, &bind, caught);
+        let handler_location = handler.location.clone();
+        let inferred_handler = self.infer_block(handler);
+        self.resolver.pop_rib();
+        // unifying
+        let fresh = Typ::Var(self.icx.fresh());
+        for branch in [
+            (body_location, inferred_body),
+            (handler_location, inferred_handler),
+        ] {
+            coercion::coerce(
+                &mut self.icx,
+                Cause::Branch(&location, &branch.0),
+                Coercion::Eq(fresh.clone(), branch.1),
+            );
+        }
+        self.icx.apply(fresh)
+    }
+
+    /// Infers the type of `?` error propagation expression.
+    ///
+    /// Propagated value should be an enum with the `Ok` variant,
+    /// that has exactly one field. The result type is the type
+    /// of this field. Any other variant exits the enclosing function,
+    /// so the enum and its generics, that aren't used by the `Ok`
+    /// field are unified with the function return type. The `Ok`
+    /// payload is left free, since it's never propagated.
+    ///
+    /// Value could also be `Optional[T]`, then the result type is `T`.
+    /// `null` exits the enclosing function, so function should return
+    /// `Optional` value too.
+    ///
+    /// ## Errors:
+    /// - [`TypeckError::InvalidTryValue`] if the value is not an enum,
+    ///   or enum has no single-field `Ok` variant.
+    /// - [`TypeckError::AmbiguousTryValue`] if the value is `Optional`
+    ///   of the enum with the single-field `Ok` variant.
+    ///
+    fn infer_try(&mut self, location: Address, value: Expression) -> Typ {
+        // inferring value
+        let inferred_value = self.infer_expr(value);
+        // unifying with the enclosing function return type
+        let (fn_location, ret) = match self.returns.last() {
+            Some(it) => it.clone(),
+            None => unreachable!(),
+        };
+        // `Optional[T]` propagates `null`
+        if let Typ::Optional(inner) = self.icx.apply(inferred_value.clone()) {
+            if self.find_ok_variant(&inner).is_some() {
+                bail!(TypeckError::AmbiguousTryValue {
+                    src: location.source.clone(),
+                    span: location.span.into(),
+                    t: inferred_value.pretty(&mut self.icx)
+                })
+            }
+            let fresh = Typ::Optional(Box::new(Typ::Var(self.icx.fresh())));
+            coercion::coerce(
+                &mut self.icx,
+                Cause::Return(&fn_location, &location),
+                Coercion::Eq(ret, fresh),
+            );
+            self.hints.tries.insert(location.span, TryKind::Optional);
+            return *inner;
+        }
+        // finding `Ok` variant field
+        let field = match self.find_ok_variant(&inferred_value) {
+            Some(field) => field,
+            None => bail!(TypeckError::InvalidTryValue {
+                src: location.source.clone(),
+                span: location.span.into(),
+                t: inferred_value.pretty(&mut self.icx)
+            }),
+        };
+        let propagated = self.propagated_type(inferred_value);
+        coercion::coerce(
+            &mut self.icx,
+            Cause::Return(&fn_location, &location),
+            Coercion::Eq(ret, propagated),
+        );
+        self.hints
+            .tries
+            .insert(location.span, TryKind::Result { field: field.name });
+        field.typ
+    }
+
+    /// Finds the single field of the `Ok` variant of the enum
+    fn find_ok_variant(&mut self, typ: &Typ) -> Option<Field> {
+        typ.variants(&mut self.icx)
+            .into_iter()
+            .find(|variant| variant.name == "Ok" && variant.fields.len() == 1)
+            .map(|mut variant| variant.fields.remove(0))
+    }
+
+    /// Retrieves type of the value, propagated by `?` from the enum.
+    ///
+    /// Generics, used by the declared `Ok` variant field are
+    /// replaced with fresh type variables, so the propagated value
+    /// keeps the enum and its error generics only.
+    ///
+    fn propagated_type(&mut self, typ: Typ) -> Typ {
+        match self.icx.apply(typ) {
+            Typ::Enum(id, args) => {
+                let ok = self
+                    .icx
+                    .tcx
+                    .enum_(id)
+                    .variants
+                    .iter()
+                    .find(|variant| variant.name == "Ok")
+                    .map(|variant| variant.fields[0].typ.clone());
+                let subtitutions = args
+                    .subtitutions
+                    .into_iter()
+                    .map(|(generic, typ)| match &ok {
+                        Some(ok) if ok.mentions_generic(&self.icx, generic) => {
+                            (generic, Typ::Var(self.icx.fresh()))
+                        }
+                        _ => (generic, typ),
+                    })
+                    .collect();
+                Typ::Enum(id, GenericArgs { subtitutions })
+            }
+            other => other,
+        }
+    }
+
+    /// Infers the type of `await` expression.
+    ///
+    /// Promises are transparent to the type system, so
+    /// `extern fn` returning promise is annotated with the
+    /// resolved value type, and `await` keeps the value type.
+    /// Function, that contains `await`, is marked as async,
+    /// and awaited call is recorded, see [`check_unawaited_calls`].
+    ///
+    /// ## Errors:
+    /// - [`TypeckError::AwaitInGuard`] if `await` is used in the pattern guard.
+    ///
+    fn infer_await(&mut self, location: Address, value: Expression) -> Typ {
+        if self.in_guard {
+            bail!(TypeckError::AwaitInGuard {
+                src: location.source.clone(),
+                span: location.span.into(),
+            })
+        }
+        self.awaits = true;
+        if let Expression::Call { .. } | Expression::OptionalChain { .. } = value {
+            self.awaited.insert(value.location().span);
+        }
+        self.infer_expr(value)
+    }
+
+    /// Infers the type of the expression, which value is the result
+    /// of the statement or the branch body.
+    ///
+    /// `todo` and `panic` are placeholders for values, so their type is a
+    /// fresh variable. But the expression, which value is the result of the
+    /// statement or the branch, never produces it, if [`Expression::diverges`],
+    /// so its type is `Never`.
+    ///
+    pub(crate) fn infer_diverging(&mut self, expr: Expression) -> Typ {
+        let diverges = expr.diverges();
+        let inferred = self.infer_expr(expr);
+        if diverges { Typ::Never } else { inferred }
+    }
+
     /// The central entry point for expression type inference.
     ///
     /// Dispatches to specialized inference routines depending on expression kind:
@@ -1211,7 +1904,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// - calls (`infer_call`),
     /// - anonymous functions (`infer_anonymous_fn`),
     /// - binary and unary ops,
-    /// - match/if constructs.
+    /// - match/if/try constructs, `?` propagation and `await`.
     ///
     /// After the initial inference, the result is passed through the hydrator
     /// (`Hydrator::apply`) to resolve any pending substitutions of unbounds.
@@ -1219,6 +1912,16 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// This guarantees that the final type is always normalized.
     ///
     pub(crate) fn infer_expr(&mut self, expr: Expression) -> Typ {
+        // Branching expressions check their branches
+        // against the return type, other expressions are
+        // checked as a whole
+        let return_path = self.return_path.take();
+        let returned = match &expr {
+            Expression::If { .. } | Expression::Match { .. } | Expression::Paren { .. } => None,
+            _ => return_path
+                .clone()
+                .map(|(fn_location, ret)| (fn_location, expr.location(), ret)),
+        };
         // Inferencing expression
         let result = match expr {
             Expression::Float { .. } => Typ::Prelude(PreludeType::Float),
@@ -1236,6 +1939,17 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 Typ::Var(self.icx.fresh())
             }
             Expression::Panic { .. } => Typ::Var(self.icx.fresh()),
+            Expression::Break { location, label } => {
+                if !self.labels.contains(&label) {
+                    bail!(TypeckError::UnknownLabel {
+                        src: location.source.clone(),
+                        span: location.span.into(),
+                        label
+                    })
+                }
+                self.breaks += 1;
+                Typ::Never
+            }
             Expression::Bin {
                 location,
                 left,
@@ -1269,6 +1983,13 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             } => self
                 .infer_call(location.clone(), *what, args)
                 .unwrap_typ(&mut self.icx, &location),
+            Expression::OptionalChain {
+                location,
+                base,
+                access,
+            } => self
+                .infer_optional_chain(location.clone(), *base, access)
+                .unwrap_typ(&mut self.icx, &location),
             Expression::Function {
                 location,
                 params,
@@ -1280,15 +2001,35 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 value,
                 cases,
                 ..
-            } => self.infer_pattern_matching(location, *value, cases),
+            } => self.infer_pattern_matching(location, *value, cases, return_path),
             Expression::If {
                 location,
                 logical,
                 body,
                 else_branches,
-            } => self.infer_if(location, *logical, body, else_branches),
-            Expression::Paren { expr, .. } => self.infer_expr(*expr),
+            } => self.infer_if(location, *logical, body, else_branches, return_path),
+            Expression::Paren { expr, .. } => {
+                self.return_path = return_path;
+                self.infer_expr(*expr)
+            }
+            Expression::TryCatch {
+                location,
+                body,
+                bind,
+                typ,
+                handler,
+            } => self.infer_try_catch(location, body, bind, typ, handler),
+            Expression::Try { location, value } => self.infer_try(location, *value),
+            Expression::Await { location, value } => self.infer_await(location, *value),
         };
+        // Checking returned value
+        if let Some((fn_location, value_location, ret)) = returned {
+            coercion::coerce(
+                &mut self.icx,
+                Cause::Return(&fn_location, &value_location),
+                Coercion::Assign(result.clone(), ret),
+            );
+        }
         // Applying substs
         self.icx.apply(result)
     }
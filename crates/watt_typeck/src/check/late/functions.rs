@@ -1,18 +1,25 @@
 /// Imports
 use crate::{
     cx::module::ModuleCx,
+    errors::TypeckError,
     inference::{
         cause::Cause,
         coercion::{self, Coercion},
     },
+    pretty::Pretty,
+    resolve::rib::RibsStack,
     typ::{
         res::Res,
-        typ::{Parameter, Typ},
+        typ::{Function, Parameter, Typ},
     },
 };
 use ecow::EcoString;
-use watt_ast::ast::{Block, Either, Expression, FnDeclaration};
-use watt_common::address::Address;
+use id_arena::Id;
+use watt_ast::{
+    ast::{Block, Declaration, Either, Expression, FnDeclaration, Statement},
+    hints::Signature,
+};
+use watt_common::{address::Address, bail};
 
 /// Late declaration analysis pass for the module.
 ///
@@ -34,15 +41,22 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// ## Steps:
     /// - Look up the function shell previously registered by the early pass.
     /// - Re-push generic parameters into the hydrator.
-    /// - Resolve the return type if annotated; otherwise assume `Unit`.
+    /// - Resolve the return type if annotated; otherwise it's a type variable,
+    ///   solved from the body and finalized by `finalize_fn_returns`. Function
+    ///   without annotation, that was already inferred on its first use, is skipped.
     /// - Resolve the types of all parameters, constructing a typed signature.
     /// - Publish the function signature into the module (so it is visible to
     ///   recursive calls within its own body).
     /// - Create a new scope (rib) for local variables.
     /// - Insert parameters as locals into that scope.
-    /// - Infer the function body (block or expression).
+    /// - Infer the function body (block or expression), with the return
+    ///   type pushed as the target of `?` error propagation. Annotated return
+    ///   type is checked against each return path of the body.
+    /// - Mark function as async, if its body contains `await`.
+    /// - Report missing return, if annotated function body ends with a statement.
     /// - Emit a unification equation requiring: `inferred_body_type == return_type`.
     /// - Pop the local scope.
+    /// - Write solved return type back, if it wasn't annotated.
     /// - Pop the generic parameter scope.
     ///
     /// At the end of this method the function is fully type-checked.
@@ -62,34 +76,225 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         let ret = function.ret.clone();
         let generics = function.generics.clone();
 
+        // Function without annotation could
+        // be already inferred on its first use
+        let annotated = !matches!(ret, Typ::Var(_));
+        if !annotated && self.unsolved.remove(&id).is_none() {
+            return;
+        }
+
         // Pushing generics
         self.icx.generics.re_push_scope(generics.clone());
 
         // pushing new scope
-        self.resolver.push_rib();
+        self.resolver.push_fn_rib();
 
         // defining params in new scope
-        params.iter().for_each(|p| {
-            self.resolver
-                .define_local(&location, &p.name, p.typ.clone())
-        });
+        params
+            .iter()
+            .for_each(|p| self.define_local(&location, &p.name, p.typ.clone()));
+
+        // checking for missing return
+        let returns_value = match &body {
+            Either::Left(block) => match block.body.last() {
+                Some(stmt) => matches!(stmt, Statement::Expr(_)) || stmt.diverges(),
+                None => false,
+            },
+            Either::Right(_) => true,
+        };
 
-        // inferring body
+        // inferring body, annotated return type
+        // is checked against each return path
+        self.returns.push((location.clone(), ret.clone()));
+        if annotated {
+            self.return_path = Some((location.clone(), ret.clone()));
+        }
+        let awaits = std::mem::replace(&mut self.awaits, false);
         let (block_location, inferred_block) = match body {
             Either::Left(block) => (block.location.clone(), self.infer_block(block)),
             Either::Right(expr) => (expr.location(), self.infer_expr(expr)),
         };
+        self.icx.tcx.function_mut(id).is_async = std::mem::replace(&mut self.awaits, awaits);
+        self.returns.pop();
+        if annotated && !returns_value && ret != Typ::Unit && inferred_block != Typ::Never {
+            bail!(TypeckError::MissingReturn {
+                src: self.module.source.clone(),
+                span: location.span.into(),
+                expected: ret.pretty(&mut self.icx)
+            })
+        }
         coercion::coerce(
             &mut self.icx,
-            Cause::Return(&block_location, &location),
-            Coercion::Eq(inferred_block, ret),
+            Cause::Return(&location, &block_location),
+            Coercion::Assign(inferred_block, ret.clone()),
         );
         self.resolver.pop_rib();
 
+        // Writing solved return type back, so generics
+        // it mentions are instantiated on each call
+        if !annotated {
+            self.icx.tcx.function_mut(id).ret = self.solve(ret);
+        }
+
         // Popping generics
         self.icx.generics.pop_scope();
     }
 
+    /// Infers the body of the function without return type
+    /// annotation on its first use, so the use sees the solved
+    /// return type, instead of the placeholder.
+    ///
+    /// Body is inferred in the fresh scope, state of
+    /// the currently inferred body is restored after.
+    /// Non-const function body is inferred outside
+    /// of the const context of the use. Recursive uses
+    /// of the function, that is being inferred, see the placeholder.
+    ///
+    pub(crate) fn infer_fn_on_use(&mut self, id: Id<Function>) {
+        let decl = match self.unsolved.get(&id) {
+            Some(decl) => decl.clone(),
+            None => return,
+        };
+        // Saving state
+        let ribs = self.resolver.replace_ribs(RibsStack::default());
+        let return_path = self.return_path.take();
+        let in_guard = std::mem::replace(&mut self.in_guard, false);
+        let in_const_fn = std::mem::replace(&mut self.in_const_fn, false);
+        let returns = std::mem::take(&mut self.returns);
+        let labels = std::mem::take(&mut self.labels);
+        let breaks = self.breaks;
+        // Inferring function
+        self.late_analyze_fn_decl(decl);
+        // Restoring state
+        self.resolver.replace_ribs(ribs);
+        self.return_path = return_path;
+        self.in_guard = in_guard;
+        self.in_const_fn = in_const_fn;
+        self.returns = returns;
+        self.labels = labels;
+        self.breaks = breaks;
+    }
+
+    /// Applies substitutions to the type, until it's solved
+    fn solve(&self, mut typ: Typ) -> Typ {
+        loop {
+            let applied = self.icx.apply(typ.clone());
+            if applied == typ {
+                return typ;
+            }
+            typ = applied;
+        }
+    }
+
+    /// Ensures that calls of the async functions are awaited.
+    ///
+    /// Function, that contains `await`, is generated as `async`
+    /// and returns a promise, while its type is the resolved
+    /// value type, so the call, that isn't awaited, would
+    /// silently give a promise instead of the value.
+    ///
+    /// ## Errors:
+    /// - [`TypeckError::UnawaitedAsyncCall`] if call of the async function isn't awaited.
+    ///
+    pub(crate) fn check_unawaited_calls(&mut self) {
+        for (location, id) in std::mem::take(&mut self.unawaited) {
+            let function = self.icx.tcx.function(id);
+            if function.is_async {
+                bail!(TypeckError::UnawaitedAsyncCall {
+                    src: location.source.clone(),
+                    span: location.span.into(),
+                    name: function.name.clone(),
+                })
+            }
+        }
+    }
+
+    /// Finalizes inferred return types of the functions without annotation.
+    ///
+    /// Return type, that is still unknown after the late analysis,
+    /// e.g. of the function, that only calls itself, is `Unit`.
+    /// Solved return type is written back into the function,
+    /// so it's visible to the other modules.
+    ///
+    pub(crate) fn finalize_fn_returns(&mut self) {
+        for declaration in &self.module.declarations {
+            let (location, name) = match declaration {
+                Declaration::Fn(FnDeclaration::Function {
+                    location,
+                    name,
+                    typ: None,
+                    ..
+                })
+                | Declaration::Fn(FnDeclaration::ConstFunction {
+                    location,
+                    name,
+                    typ: None,
+                    ..
+                }) => (location, name),
+                _ => continue,
+            };
+            let id = match self.resolver.resolve(location, name) {
+                Res::Value(Typ::Function(f, _)) => f,
+                _ => unreachable!(),
+            };
+            // Applying substitutions until the type is solved
+            let mut ret = self.solve(self.icx.tcx.function(id).ret.clone());
+            if let Typ::Var(_) = ret {
+                coercion::coerce(
+                    &mut self.icx,
+                    Cause::Return(location, location),
+                    Coercion::Eq(Typ::Unit, ret),
+                );
+                ret = Typ::Unit;
+            }
+            self.icx.tcx.function_mut(id).ret = ret;
+        }
+    }
+
+    /// Records JSDoc signatures of the module functions into
+    /// the code generation hints, so documentation of the generated
+    /// code uses inferred types, instead of the type annotations.
+    ///
+    /// # Notes
+    /// Should be called after return types finalization,
+    /// so unannotated return types are already solved.
+    ///
+    pub(crate) fn record_signatures(&mut self) {
+        for declaration in &self.module.declarations {
+            let (location, name) = match declaration {
+                Declaration::Fn(FnDeclaration::Function { location, name, .. })
+                | Declaration::Fn(FnDeclaration::ConstFunction { location, name, .. })
+                | Declaration::Fn(FnDeclaration::ExternFunction { location, name, .. }) => {
+                    (location, name)
+                }
+                _ => continue,
+            };
+            let id = match self.resolver.resolve(location, name) {
+                Res::Value(Typ::Function(id, _)) => id,
+                Res::Overloaded(fns) => match fns
+                    .into_iter()
+                    .find(|(_, id)| self.icx.tcx.function(*id).location.span == location.span)
+                {
+                    Some((_, id)) => id,
+                    None => continue,
+                },
+                _ => continue,
+            };
+            let function = self.icx.tcx.function(id).clone();
+            let signature = Signature {
+                params: function
+                    .params
+                    .iter()
+                    .map(|param| param.typ.jsdoc(&mut self.icx, &function.generics).into())
+                    .collect(),
+                ret: function.ret.jsdoc(&mut self.icx, &function.generics).into(),
+            };
+            self.hints
+                .signatures
+                .insert(location.span.clone(), signature);
+        }
+    }
+
     /// Dispatches a function declaration to the corresponding late analysis routine.
     ///
     /// Each type declaration variant is fully processed here:
@@ -103,16 +308,27 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     ///
     /// # Notes
     /// Externals does not need any additional analyze after early.
+    /// Const functions bodies could call only const functions.
     ///
     pub fn late_analyze_fn_decl(&mut self, decl: FnDeclaration) {
-        if let FnDeclaration::Function {
-            location,
-            name,
-            body,
-            ..
-        } = decl
-        {
-            self.late_analyze_fn(location, name, body)
+        match decl {
+            FnDeclaration::Function {
+                location,
+                name,
+                body,
+                ..
+            } => self.late_analyze_fn(location, name, body),
+            FnDeclaration::ConstFunction {
+                location,
+                name,
+                body,
+                ..
+            } => {
+                self.in_const_fn = true;
+                self.late_analyze_fn(location, name, body);
+                self.in_const_fn = false;
+            }
+            FnDeclaration::ExternFunction { .. } => {}
         }
     }
 }
@@ -10,7 +10,11 @@ use crate::{
         cause::Cause,
         coercion::{self, Coercion},
     },
-    typ::{def::ModuleDef, typ::WithPublicity},
+    typ::{
+        def::{ModuleDef, TypeDef},
+        res::Res,
+        typ::{Typ, WithPublicity},
+    },
 };
 use ecow::EcoString;
 use watt_ast::ast::{Declaration, Dependency, Expression, Publicity, TypePath, UseKind};
@@ -59,11 +63,15 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     ) {
         // Const inference
         let annotated = self.infer_type_annotation(typ);
+        self.ensure_const_value(&value);
+        if let Some(int) = self.eval_const_int(&value) {
+            self.const_ints.insert(name.clone(), int);
+        }
         let inferred = self.infer_expr(value);
         coercion::coerce(
             &mut self.icx,
             Cause::Assignment(&location),
-            Coercion::Eq(annotated.clone(), inferred),
+            Coercion::Assign(inferred, annotated.clone()),
         );
 
         // Defining constant
@@ -77,6 +85,55 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         );
     }
 
+    /// Ensures that variables, used in the constant value, are constants,
+    /// and called functions are const functions.
+    ///
+    /// # Notes
+    /// Other non-const expressions are rejected by the parser.
+    ///
+    /// ## Errors
+    /// - [`TypeckError::NonConstExpression`]: if variable isn't a constant.
+    /// - [`TypeckError::NonConstCall`]: if called function isn't const.
+    ///
+    fn ensure_const_value(&mut self, value: &Expression) {
+        match value {
+            Expression::PrefixVar { location, name }
+                if !matches!(self.resolver.resolve(location, name), Res::Const(_)) =>
+            {
+                bail!(TypeckError::NonConstExpression {
+                    src: self.module.source.clone(),
+                    span: location.span.clone().into(),
+                    name: name.clone(),
+                })
+            }
+            Expression::Bin { left, right, .. } => {
+                self.ensure_const_value(left);
+                self.ensure_const_value(right);
+            }
+            Expression::As { value, .. } | Expression::Unary { value, .. } => {
+                self.ensure_const_value(value)
+            }
+            Expression::Paren { expr, .. } => self.ensure_const_value(expr),
+            Expression::Call { what, args, .. } => {
+                if let Expression::PrefixVar { location, name } = what.as_ref() {
+                    let is_const = match self.resolver.resolve(location, name) {
+                        Res::Value(Typ::Function(id, _)) => self.icx.tcx.function(id).is_const,
+                        _ => false,
+                    };
+                    if !is_const {
+                        bail!(TypeckError::NonConstCall {
+                            src: self.module.source.clone(),
+                            span: location.span.clone().into(),
+                            name: name.clone(),
+                        })
+                    }
+                }
+                args.iter().for_each(|arg| self.ensure_const_value(arg));
+            }
+            _ => {}
+        }
+    }
+
     /// Each declaration variant is fully processed here:
     /// - Const → `late_analyze_const`
     /// - Type → `late_analyze_type`
@@ -106,7 +163,9 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// - `use foo for a,b`  → import selected names.
     ///
     /// On success, the referenced module is integrated into the current scope
-    /// according to the chosen `UseKind`.
+    /// according to the chosen `UseKind`. Type aliases, imported by names,
+    /// are recorded into the hints, since they're erased from the generated code,
+    /// as well as overloaded extern functions, since their overloads are imported separately.
     ///
     /// ## Errors
     /// - [`TypeckError::ImportOfUnknownModule`]: if module doesn't exist.
@@ -118,13 +177,37 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                     self.resolver
                         .import_as(self.package.root, &import.location, name, module)
                 }
-                UseKind::ForNames(names) => self.resolver.import_for(
-                    self.package.root,
-                    &mut self.icx,
-                    &import.location,
-                    names,
-                    module,
-                ),
+                UseKind::ForNames(names) => {
+                    self.resolver.import_for(
+                        self.package.root,
+                        &mut self.icx,
+                        &import.location,
+                        names.clone(),
+                        module,
+                    );
+                    // Type aliases are erased from the generated code,
+                    // and overloads of the extern functions are imported separately
+                    for name in names {
+                        match self.resolver.imported_defs.get(&name) {
+                            Some(ModuleDef::Type(WithPublicity {
+                                value: TypeDef::Alias(_),
+                                ..
+                            })) => {
+                                self.hints.erased_imports.insert(name);
+                            }
+                            Some(ModuleDef::OverloadedFn(fns)) => {
+                                let public = fns
+                                    .iter()
+                                    .enumerate()
+                                    .filter(|(_, f)| f.publicity == Publicity::Public)
+                                    .map(|(idx, _)| idx)
+                                    .collect();
+                                self.hints.overloaded_imports.insert(name, public);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
             },
             None => bail!(TypeckError::ImportOfUnknownModule {
                 src: self.module.source.clone(),
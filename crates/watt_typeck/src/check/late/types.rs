@@ -43,11 +43,12 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             _ => unreachable!(),
         };
         let struct_ = self.icx.tcx.struct_(id);
-        let (location, uid, name, generics) = (
+        let (location, uid, name, generics, deprecated) = (
             struct_.location.clone(),
             struct_.uid,
             struct_.name.clone(),
             struct_.generics.clone(),
+            struct_.deprecated.clone(),
         );
 
         // Re pushing generics
@@ -67,6 +68,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                     typ: self.infer_type_annotation(f.typ),
                 })
                 .collect(),
+            deprecated,
         };
         let struct_mut = self.icx.tcx.struct_mut(id);
         *struct_mut = new_struct;
@@ -98,11 +100,12 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             _ => unreachable!(),
         };
         let enum_ = self.icx.tcx.enum_(id);
-        let (location, uid, name, generics) = (
+        let (location, uid, name, generics, deprecated) = (
             enum_.location.clone(),
             enum_.uid,
             enum_.name.clone(),
             enum_.generics.clone(),
+            enum_.deprecated.clone(),
         );
 
         // Repushing generics
@@ -130,6 +133,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                         .collect(),
                 })
                 .collect(),
+            deprecated,
         };
         let enum_mut = self.icx.tcx.enum_mut(id);
         *enum_mut = new_enum;
@@ -143,6 +147,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// Each type declaration variant is fully processed here:
     /// - Struct → `late_analyze_struct`
     /// - Enum → `late_analyze_enum`
+    /// - Alias → `resolve_alias`
     ///
     /// After this call, each type declaration is fully type-analyzed and integrated
     /// into the module’s type environment.
@@ -161,6 +166,14 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 variants,
                 ..
             } => self.late_analyze_enum(location, name, variants),
+            TypeDeclaration::Alias { location, name, .. } => {
+                match self.resolver.resolve_type(&location, &name) {
+                    TypeDef::Alias(id) => {
+                        self.resolve_alias(id);
+                    }
+                    _ => unreachable!(),
+                }
+            }
         }
     }
 }
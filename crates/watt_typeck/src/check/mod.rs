@@ -1,3 +1,4 @@
+pub mod dead;
 pub mod early;
 pub mod expr;
 pub mod late;
@@ -2,7 +2,7 @@
 use crate::cx::module::ModuleCx;
 use crate::typ::typ::Module;
 use tracing::info;
-use watt_ast::ast::Declaration;
+use watt_ast::ast::{Declaration, TypeDeclaration};
 
 /// Implementation
 impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
@@ -10,9 +10,15 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     ///
     /// Pipeline stages:
     /// 1. Perform imports.
-    /// 2. Early define types by name.
-    /// 3. Early define and analyze functions.
-    /// 4. Late analyze declarations.
+    /// 2. Check declarations, that shadow imports.
+    /// 3. Early define types by name.
+    /// 4. Resolve type aliases.
+    /// 5. Early define and analyze functions.
+    /// 6. Late analyze declarations.
+    /// 7. Finalize inferred return types of functions.
+    /// 8. Record signatures of functions.
+    /// 9. Check calls of async functions are awaited.
+    /// 10. Check unused imports.
     ///
     /// After this call, the module is fully type-checked.
     ///
@@ -23,7 +29,11 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             self.perform_import(import)
         }
 
-        // 2. Early definitions of types
+        // 2. Shadowed imports check
+        info!("Performing shadowed imports check.");
+        self.check_shadowed_imports();
+
+        // 3. Early definitions of types
         info!("Performing early type definitions.");
         for definition in &self.module.declarations {
             if let Declaration::Type(t) = definition {
@@ -31,7 +41,15 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             }
         }
 
-        // 3. Early functions analysis
+        // 4. Type aliases resolution
+        info!("Performing type aliases resolution.");
+        for definition in self.module.declarations.clone() {
+            if let Declaration::Type(t @ TypeDeclaration::Alias { .. }) = definition {
+                self.late_analyze_type_decl(t)
+            }
+        }
+
+        // 5. Early functions analysis
         info!("Performing early functions analyse.");
         for definition in &self.module.declarations {
             if let Declaration::Fn(f) = definition {
@@ -39,17 +57,34 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             }
         }
 
-        // 4. Late analysis
+        // 6. Late analysis
         info!("Performing late analysis...");
         for definition in self.module.declarations.clone() {
             self.late_analyze_decl(definition);
         }
 
+        // 7. Return types finalization
+        info!("Performing return types finalization.");
+        self.finalize_fn_returns();
+
+        // 8. Signatures recording
+        info!("Performing signatures recording.");
+        self.record_signatures();
+
+        // 9. Unawaited calls check
+        info!("Performing unawaited calls check.");
+        self.check_unawaited_calls();
+
+        // 10. Unused imports check
+        info!("Performing unused imports check.");
+        self.check_unused_imports();
+
         // Pipeline result
         Module {
             source: self.module.source.clone(),
             name: self.module_name.clone(),
             fields: self.resolver.collect(),
+            hints: std::mem::take(&mut self.hints),
         }
     }
 }
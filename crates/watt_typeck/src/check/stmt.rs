@@ -11,11 +11,12 @@ use crate::{
         res::Res,
         typ::{PreludeType, Typ},
     },
+    warnings::TypeckWarning,
 };
 use ecow::EcoString;
 use watt_ast::ast::*;
 use watt_ast::ast::{Block, Expression, TypePath};
-use watt_common::{address::Address, bail, skip};
+use watt_common::{address::Address, bail, skip, warn};
 
 /// Statements inferencing
 impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
@@ -42,14 +43,18 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         let inferred_logical = self.infer_expr(logical);
         match inferred_logical {
             Typ::Prelude(PreludeType::Bool) => skip!(),
-            other => bail!(TypeckError::TypesMissmatch {
-                related: vec![TypeckRelated::Here {
-                    src: location.source,
-                    span: location.span.into()
-                }],
-                expected: Typ::Prelude(PreludeType::Bool).pretty(&mut self.icx),
-                got: other.pretty(&mut self.icx),
-            }),
+            other => {
+                let got = other.pretty(&mut self.icx);
+                bail!(TypeckError::TypesMissmatch {
+                    related: vec![TypeckRelated::ThisType {
+                        src: location.source,
+                        span: location.span.into(),
+                        t: got.clone()
+                    }],
+                    expected: Typ::Prelude(PreludeType::Bool).pretty(&mut self.icx),
+                    got,
+                })
+            }
         }
         // inferring block
         let _ = match body {
@@ -72,57 +77,67 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     ///
     fn analyze_range(&mut self, range: Range) {
         match range {
-            Range::ExcludeLast { location, from, to } => {
+            Range::ExcludeLast { from, to, .. } => {
                 // Inferring from and to expression
+                let (from_location, to_location) = (from.location(), to.location());
                 let inferred_from = self.infer_expr(from);
                 let inferred_to = self.infer_expr(to);
                 // Checking both are ints
                 let typ = Typ::Prelude(PreludeType::Int);
                 if inferred_from != typ {
+                    let got = inferred_from.pretty(&mut self.icx);
                     bail!(TypeckError::TypesMissmatch {
-                        related: vec![TypeckRelated::Here {
-                            src: location.source,
-                            span: location.span.into()
+                        related: vec![TypeckRelated::ThisType {
+                            src: from_location.source,
+                            span: from_location.span.into(),
+                            t: got.clone()
                         }],
                         expected: typ.pretty(&mut self.icx),
-                        got: inferred_from.pretty(&mut self.icx)
+                        got
                     })
                 }
                 if inferred_to != typ {
+                    let got = inferred_to.pretty(&mut self.icx);
                     bail!(TypeckError::TypesMissmatch {
-                        related: vec![TypeckRelated::Here {
-                            src: location.source,
-                            span: location.span.into()
+                        related: vec![TypeckRelated::ThisType {
+                            src: to_location.source,
+                            span: to_location.span.into(),
+                            t: got.clone()
                         }],
                         expected: typ.pretty(&mut self.icx),
-                        got: inferred_from.pretty(&mut self.icx)
+                        got
                     })
                 }
             }
-            Range::IncludeLast { location, from, to } => {
+            Range::IncludeLast { from, to, .. } => {
                 // Inferring from and to expression
+                let (from_location, to_location) = (from.location(), to.location());
                 let inferred_from = self.infer_expr(from);
                 let inferred_to = self.infer_expr(to);
                 // Checking both are ints
                 let typ = Typ::Prelude(PreludeType::Int);
                 if inferred_from != typ {
+                    let got = inferred_from.pretty(&mut self.icx);
                     bail!(TypeckError::TypesMissmatch {
-                        related: vec![TypeckRelated::Here {
-                            src: location.source,
-                            span: location.span.into()
+                        related: vec![TypeckRelated::ThisType {
+                            src: from_location.source,
+                            span: from_location.span.into(),
+                            t: got.clone()
                         }],
                         expected: typ.pretty(&mut self.icx),
-                        got: inferred_from.pretty(&mut self.icx)
+                        got
                     })
                 }
                 if inferred_to != typ {
+                    let got = inferred_to.pretty(&mut self.icx);
                     bail!(TypeckError::TypesMissmatch {
-                        related: vec![TypeckRelated::Here {
-                            src: location.source,
-                            span: location.span.into()
+                        related: vec![TypeckRelated::ThisType {
+                            src: to_location.source,
+                            span: to_location.span.into(),
+                            t: got.clone()
                         }],
                         expected: typ.pretty(&mut self.icx),
-                        got: inferred_to.pretty(&mut self.icx)
+                        got
                     })
                 }
             }
@@ -151,8 +166,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         // pushing rib
         self.resolver.push_rib();
         // defining variable for iterations
-        self.resolver
-            .define_local(&location, &name, Typ::Prelude(PreludeType::Int));
+        self.define_local(&location, &name, Typ::Prelude(PreludeType::Int));
         // analyzing range
         self.analyze_range(range);
         // inferring block
@@ -187,19 +201,22 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         match typ {
             Some(annotated_path) => {
                 let annotated = self.infer_type_annotation(annotated_path);
-                let coercion = Coercion::Eq(annotated.clone(), self.icx.mk_fresh(inferred_value));
+                let coercion =
+                    Coercion::Assign(self.icx.mk_fresh(inferred_value), annotated.clone());
                 coercion::coerce(&mut self.icx, Cause::Assignment(&location), coercion);
-                self.resolver.define_local(&location, &name, annotated)
+                self.define_local(&location, &name, annotated)
+            }
+            None => {
+                let inferred = self.icx.mk_fresh(inferred_value);
+                self.define_local(&location, &name, inferred)
             }
-            None => self
-                .resolver
-                .define_local(&location, &name, self.icx.mk_fresh(inferred_value)),
         }
     }
 
     /// Analyzes an assignment (`x = value`).
     ///
     /// ## Steps:
+    /// - Record the reassigned local variable into the hints, if it's assigned.
     /// - Resolve the left-hand side (`what`) and check that it is not a constant.
     /// - Infer the type of the assign value and instantiate its type.
     /// - Emit an coercion unifying the variable's type and the value's type.
@@ -208,6 +225,11 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// - [`TypeckError::CouldNotAssignConstant`] if the left-hand side refers to a constant.
     ///
     fn analyze_assignment(&mut self, location: Address, what: Expression, value: Expression) {
+        if let Expression::PrefixVar { name, .. } = &what
+            && let Some(defined) = self.resolver.lookup_local_address(name)
+        {
+            self.hints.reassigned.insert(defined.span.clone());
+        }
         let inferred_what = self.infer_resolution(what);
         if let Res::Const(_) = inferred_what {
             bail!(TypeckError::CouldNotAssignConstant {
@@ -216,34 +238,71 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             })
         }
         let inferred_value = self.infer_expr(value);
-        let coercion = Coercion::Eq(
-            inferred_what.unwrap_typ(&mut self.icx, &location),
+        let coercion = Coercion::Assign(
             self.icx.mk_fresh(inferred_value),
+            inferred_what.unwrap_typ(&mut self.icx, &location),
         );
         coercion::coerce(&mut self.icx, Cause::Assignment(&location), coercion);
     }
 
+    /// Analyzes a `throw` statement.
+    ///
+    /// Values of unknown type, like the `catch` binding,
+    /// could be rethrown, as well as the diverging values.
+    ///
+    /// ## Errors:
+    /// - [`TypeckError::InvalidThrowValue`] if the thrown value
+    ///   is neither a `String`, nor a struct instance.
+    ///
+    fn analyze_throw(&mut self, location: Address, value: Expression) {
+        let inferred_value = self.infer_expr(value);
+        match self.icx.apply(inferred_value) {
+            Typ::Prelude(PreludeType::String) | Typ::Struct(_, _) => skip!(),
+            Typ::Var(_) | Typ::Never => skip!(),
+            other => bail!(TypeckError::InvalidThrowValue {
+                src: location.source,
+                span: location.span.into(),
+                t: other.pretty(&mut self.icx)
+            }),
+        }
+    }
+
     /// Infers the type of statement.
     ///
     /// ## Behavior by statement kind:
-    /// - `Expr` — evaluates to the expression’s type.
+    /// - `Expr` — evaluates to the expression’s type, see [`infer_diverging`].
     /// - `VarDef` — delegates to [`analyze_let_define`] and returns `Unit`.
     /// - `VarAssign` — delegates to [`analyze_assignment`] and returns `Unit`.
-    /// - `Loop` — delegates to [`analyze_loop`] and returns `Unit`.
+    /// - `ParallelAssign` — delegates each target and value pair
+    ///   to [`analyze_assignment`] and returns `Unit`.
+    /// - `Loop` — delegates to [`analyze_loop`] and returns `Unit`,
+    ///   or `Never`, if the loop is infinite and has no `break` inside.
     /// - `For` — delegates to [`analyze_for`] and returns `Unit`.
     /// - `Semi(expr)` — infers the expression, discards its value, returns `Unit`.
     ///
+    /// `VarDef` and `Semi` return `Never`, if the expression diverges,
+    /// since [`Statement::diverges`] treats them as diverging.
+    /// - `Throw` — delegates to [`analyze_throw`] and returns `Never`,
+    ///   because control never reaches the statement end.
+    /// - `Labeled` — infers the block with the label in scope and returns `Unit`.
+    ///
     fn infer_stmt(&mut self, stmt: Statement) -> Typ {
+        // Only expression statement could be a return path
+        let return_path = self.return_path.take();
         match stmt {
-            Statement::Expr(expression) => self.infer_expr(expression),
+            Statement::Expr(expression) => {
+                self.return_path = return_path;
+                self.infer_diverging(expression)
+            }
             Statement::VarDef {
                 location,
                 name,
                 value,
                 typ,
             } => {
+                let diverges = value.diverges();
                 self.analyze_let_definition(location, name, value, typ);
-                Typ::Unit
+                if diverges { Typ::Never } else { Typ::Unit }
             }
             Statement::VarAssign {
                 location,
@@ -253,13 +312,31 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 self.analyze_assignment(location, what, value);
                 Typ::Unit
             }
+            Statement::ParallelAssign {
+                location,
+                what,
+                value,
+            } => {
+                for (what, value) in what.into_iter().zip(value) {
+                    self.analyze_assignment(location.clone(), what, value);
+                }
+                Typ::Unit
+            }
             Statement::Loop {
                 location,
                 logical,
                 body,
             } => {
+                // Loop with `true` condition and without `break` never ends
+                let infinite =
+                    matches!(&logical, Expression::Bool { value, .. } if value == "true");
+                let breaks = self.breaks;
                 self.analyze_loop(location, logical, body);
-                Typ::Unit
+                if infinite && self.breaks == breaks {
+                    Typ::Never
+                } else {
+                    Typ::Unit
+                }
             }
             Statement::For {
                 location,
@@ -271,7 +348,20 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 Typ::Unit
             }
             Statement::Semi(expr) => {
+                let diverges = expr.diverges();
                 self.infer_expr(expr);
+                if diverges { Typ::Never } else { Typ::Unit }
+            }
+            Statement::Throw { location, value } => {
+                self.analyze_throw(location, value);
+                Typ::Never
+            }
+            Statement::Labeled { label, body, .. } => {
+                self.labels.push(label);
+                self.resolver.push_rib();
+                self.infer_block(body);
+                self.resolver.pop_rib();
+                self.labels.pop();
                 Typ::Unit
             }
         }
@@ -283,6 +373,9 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// - All statements except the last are treated as having type `Unit`.
     /// - The type of the block is the type of the last statement.
     /// - Empty blocks evaluate to `Unit`.
+    /// - Statements after a diverging statement are
+    ///   still analyzed, but reported as unreachable,
+    ///   and the block evaluates to `Never`.
     ///
     /// ## Implementation:
     /// - Pop the last statement.
@@ -290,6 +383,20 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     /// - Infer and return the type of the last statement.
     ///
     pub(crate) fn infer_block(&mut self, mut block: Block) -> Typ {
+        // Only last statement could be a return path
+        let return_path = self.return_path.take();
+        // Reporting unreachable code
+        if let Some(pair) = block.body.windows(2).find(|pair| pair[0].diverges()) {
+            warn!(
+                self.package,
+                TypeckWarning::UnreachableCode {
+                    src: self.module.source.clone(),
+                    diverging_span: pair[0].location().span.into(),
+                    unreachable_span: pair[1].location().span.into()
+                }
+            );
+        }
+        let diverges = block.diverges();
         // Last stmt
         let last = match block.body.pop() {
             Some(last) => last,
@@ -300,6 +407,8 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             self.infer_stmt(stmt);
         }
         // Inferring last
-        self.infer_stmt(last)
+        self.return_path = return_path;
+        let inferred = self.infer_stmt(last);
+        if diverges { Typ::Never } else { inferred }
     }
 }
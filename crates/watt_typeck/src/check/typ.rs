@@ -5,7 +5,9 @@ use crate::{
     pretty::Pretty,
     typ::{
         def::{ModuleDef, TypeDef},
-        typ::{Enum, Function, GenericArgs, Parameter, PreludeType, Struct, Typ},
+        typ::{
+            Alias, AliasState, Enum, Function, GenericArgs, Parameter, PreludeType, Struct, Typ,
+        },
     },
 };
 use ecow::EcoString;
@@ -48,6 +50,35 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         }
     }
 
+    /// Resolves a type alias into its target type.
+    ///
+    /// Aliases are transparent, so the resolved target `Typ`
+    /// is returned as is and unifies with the target type.
+    ///
+    /// # Errors
+    /// - [`RecursiveType`]: alias target refers to the alias itself.
+    ///
+    pub(crate) fn resolve_alias(&mut self, id: Id<Alias>) -> Typ {
+        let alias = self.icx.tcx.alias(id);
+        match &alias.state {
+            AliasState::Resolved(typ) => typ.clone(),
+            AliasState::Resolving => bail!(TypeckError::RecursiveType {
+                related: vec![TypeckRelated::Here {
+                    src: alias.location.source.clone(),
+                    span: alias.location.span.clone().into()
+                }],
+                t: alias.name.to_string()
+            }),
+            AliasState::Unresolved => {
+                let target = alias.target.clone();
+                self.icx.tcx.alias_mut(id).state = AliasState::Resolving;
+                let typ = self.infer_type_annotation(target);
+                self.icx.tcx.alias_mut(id).state = AliasState::Resolved(typ.clone());
+                typ
+            }
+        }
+    }
+
     /// Infers a local type (built-in or user-defined).
     fn infer_local_type_path(
         &mut self,
@@ -69,6 +100,11 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 Typ::Prelude(PreludeType::String)
             }),
             "unit" => self.ensure_no_generics(&location, generics.len(), || Typ::Unit),
+            "Optional" => {
+                self.check_generic_params_arity(&location, 1, generics.len());
+                let inner = self.infer_type_annotation(generics[0].clone());
+                Typ::Optional(Box::new(inner))
+            }
 
             // User-defined types
             _ => match self.icx.generics.get(&name) {
@@ -76,6 +112,10 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 None => match self.resolver.resolve_type(&location, &name) {
                     TypeDef::Enum(en) => self.instantiate_enum_type(&location, en, generics),
                     TypeDef::Struct(st) => self.instantiate_struct_type(&location, st, generics),
+                    TypeDef::Alias(al) => {
+                        self.ensure_no_generics(&location, generics.len(), || Typ::Unit);
+                        self.resolve_alias(al)
+                    }
                 },
             },
         }
@@ -100,7 +140,9 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 span: location.span.into(),
                 def: def.value.pretty(&mut self.icx)
             }),
-            Some(ModuleDef::Const(_)) | Some(ModuleDef::Function(_)) => {
+            Some(ModuleDef::Const(_))
+            | Some(ModuleDef::Function(_))
+            | Some(ModuleDef::OverloadedFn(_)) => {
                 bail!(TypeckError::CouldNotUseValueAsType {
                     src: self.module.source.clone(),
                     span: location.span.into(),
@@ -117,6 +159,10 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         match &def.value {
             TypeDef::Enum(en) => self.instantiate_enum_type(&location, *en, generics),
             TypeDef::Struct(st) => self.instantiate_struct_type(&location, *st, generics),
+            TypeDef::Alias(al) => {
+                self.ensure_no_generics(&location, generics.len(), || Typ::Unit);
+                self.resolve_alias(*al)
+            }
         }
     }
 
@@ -141,6 +187,9 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
                 })
                 .collect(),
             ret: ret.map_or(Typ::Unit, |t| self.infer_type_annotation(*t)),
+            is_const: false,
+            is_async: false,
+            deprecated: None,
         };
         Typ::Function(
             self.icx.tcx.insert_function(function),
@@ -200,6 +249,7 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
     ///
     /// ## This function handles:
     /// - Prelude (built-in) types: `int`, `float`, `bool`, `string`, `()`
+    /// - Optional types (e.g. `Optional[int]`)
     /// - User-defined types (enums and structs)
     /// - Module-qualified types (e.g. `math.Vector`)
     /// - Function type expressions (e.g. `(int, float) -> bool`)
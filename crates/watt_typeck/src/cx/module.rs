@@ -4,11 +4,27 @@ use crate::{
     resolve::resolve::ModuleResolver,
     typ::{
         cx::{InferCx, TyCx},
-        typ::Module,
+        typ::{Function, Module, Typ},
     },
+    warnings::TypeckWarning,
 };
 use ecow::EcoString;
-use watt_ast::ast::{self};
+use id_arena::Id;
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+use watt_ast::{
+    ast::{self, ConstDeclaration, Declaration, FnDeclaration, TypeDeclaration, UseKind},
+    hints::Hints,
+};
+use watt_common::{address::Address, warn};
+
+/// Name of the opt-in lint, that enables unused imports check
+pub const UNUSED_IMPORTS_LINT: &str = "unused-imports";
+
+/// Name of the lint, that disables shadowed bindings check
+pub const SHADOWING_LINT: &str = "shadowing";
 
 /// Module ctx
 pub struct ModuleCx<'pkg, 'cx> {
@@ -21,6 +37,41 @@ pub struct ModuleCx<'pkg, 'cx> {
     pub(crate) icx: InferCx<'cx>,
     /// Root package context
     pub(crate) package: &'cx PackageCx<'cx>,
+    /// Locations and return types of the enclosing
+    /// functions, used by `?` error propagation
+    pub(crate) returns: Vec<(Address, Typ)>,
+    /// Location and declared return type of the function,
+    /// set while the function return path is being inferred
+    pub(crate) return_path: Option<(Address, Typ)>,
+    /// Is pattern guard condition being inferred,
+    /// used to prohibit `await` in guards
+    pub(crate) in_guard: bool,
+    /// Is const function body being inferred,
+    /// used to prohibit non-const calls
+    pub(crate) in_const_fn: bool,
+    /// Labels of the enclosing labeled
+    /// blocks, used by `break`
+    pub(crate) labels: Vec<EcoString>,
+    /// Is `await` inferred in the current function body,
+    /// used to mark function as async
+    pub(crate) awaits: bool,
+    /// Spans of the awaited calls
+    pub(crate) awaited: HashSet<Range<usize>>,
+    /// Calls of the functions, that aren't awaited. Checked after
+    /// all bodies are inferred, because function is known to be
+    /// async only after its body is inferred
+    pub(crate) unawaited: Vec<(Address, Id<Function>)>,
+    /// Amount of the inferred `break`
+    /// expressions, used to find infinite loops
+    pub(crate) breaks: usize,
+    /// Functions without return type annotation,
+    /// which bodies aren't inferred yet
+    pub(crate) unsolved: HashMap<Id<Function>, FnDeclaration>,
+    /// Values of the integer module constants, that are
+    /// known without evaluation, used to check shift amounts
+    pub(crate) const_ints: HashMap<EcoString, i64>,
+    /// Code generation hints
+    pub(crate) hints: Hints,
     /// Last uid
     last_uid: usize,
 }
@@ -40,6 +91,18 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
             resolver: ModuleResolver::default(),
             icx: InferCx::new(tcx),
             package,
+            returns: Vec::new(),
+            return_path: None,
+            in_guard: false,
+            in_const_fn: false,
+            labels: Vec::new(),
+            awaits: false,
+            awaited: HashSet::new(),
+            unawaited: Vec::new(),
+            breaks: 0,
+            unsolved: HashMap::new(),
+            const_ints: HashMap::new(),
+            hints: Hints::default(),
             last_uid: 0,
         }
     }
@@ -49,6 +112,124 @@ impl<'pkg, 'cx> ModuleCx<'pkg, 'cx> {
         self.pipeline()
     }
 
+    /// Defines a local variable in the current rib.
+    ///
+    /// If the local variable shadows an imported
+    /// definition, reports `TypeckWarning::ShadowedImport`.
+    /// If it shadows a binding from the outer scope of the same
+    /// function, reports `TypeckWarning::ShadowedBinding`,
+    /// unless `shadowing` lint is disabled.
+    ///
+    pub(crate) fn define_local(&mut self, address: &Address, name: &EcoString, typ: Typ) {
+        if let Some(imported) = self.resolver.imported_locations.get(name) {
+            warn!(
+                self.package,
+                TypeckWarning::ShadowedImport {
+                    src: address.source.clone(),
+                    original_span: imported.span.clone().into(),
+                    shadow_span: address.span.clone().into(),
+                    name: name.clone()
+                }
+            );
+        }
+        let shadowing = !self
+            .package
+            .draft
+            .lints
+            .disabled
+            .iter()
+            .any(|lint| lint == SHADOWING_LINT);
+        if let Some(outer) = self.resolver.lookup_shadowed(name)
+            && shadowing
+        {
+            warn!(
+                self.package,
+                TypeckWarning::ShadowedBinding {
+                    src: address.source.clone(),
+                    original_span: outer.span.clone().into(),
+                    shadow_span: address.span.clone().into(),
+                    name: name.clone()
+                }
+            );
+        }
+        self.resolver.define_local(address, name, typ);
+    }
+
+    /// Reports module declarations, that shadow names imported
+    /// with `use ... for`, with `TypeckWarning::ShadowedImport`.
+    ///
+    /// Overloaded extern functions are reported once.
+    ///
+    pub(crate) fn check_shadowed_imports(&self) {
+        let mut reported: HashSet<&EcoString> = HashSet::new();
+        for declaration in &self.module.declarations {
+            let (location, name) = match declaration {
+                Declaration::Fn(FnDeclaration::Function { location, name, .. })
+                | Declaration::Fn(FnDeclaration::ConstFunction { location, name, .. })
+                | Declaration::Fn(FnDeclaration::ExternFunction { location, name, .. })
+                | Declaration::Const(ConstDeclaration { location, name, .. })
+                | Declaration::Type(TypeDeclaration::Struct { location, name, .. })
+                | Declaration::Type(TypeDeclaration::Enum { location, name, .. })
+                | Declaration::Type(TypeDeclaration::Alias { location, name, .. }) => {
+                    (location, name)
+                }
+            };
+            if let Some(imported) = self.resolver.imported_locations.get(name)
+                && reported.insert(name)
+            {
+                warn!(
+                    self.package,
+                    TypeckWarning::ShadowedImport {
+                        src: location.source.clone(),
+                        original_span: imported.span.clone().into(),
+                        shadow_span: location.span.clone().into(),
+                        name: name.clone()
+                    }
+                );
+            }
+        }
+    }
+
+    /// Reports names imported with `use ... for`,
+    /// that were never resolved in the module.
+    ///
+    /// Names starting with `_` aren't reported.
+    ///
+    /// # Notes
+    /// Check is opt-in and does nothing,
+    /// unless `unused-imports` lint is enabled.
+    ///
+    pub(crate) fn check_unused_imports(&self) {
+        if !self
+            .package
+            .draft
+            .lints
+            .enabled
+            .iter()
+            .any(|lint| lint == UNUSED_IMPORTS_LINT)
+        {
+            return;
+        }
+
+        for dependency in &self.module.dependencies {
+            if let UseKind::ForNames(names) = &dependency.kind {
+                for name in names {
+                    if name.starts_with('_') || self.resolver.used_imports.contains(name) {
+                        continue;
+                    }
+                    warn!(
+                        self.package,
+                        TypeckWarning::UnusedImport {
+                            src: dependency.location.source.clone(),
+                            span: dependency.location.span.clone().into(),
+                            name: name.clone()
+                        }
+                    );
+                }
+            }
+        }
+    }
+
     /// Generates fresh uid
     pub fn fresh_id(&mut self) -> usize {
         self.last_uid += 1;
@@ -31,16 +31,15 @@ pub(crate) enum TypeckRelated {
 #[derive(Debug, Error, Diagnostic)]
 pub(crate) enum TypeckError {
     #[error("could not resolve `{name}`.")]
-    #[diagnostic(
-        code(typeck::could_not_resolve),
-        help("check symbol/variable existence.")
-    )]
+    #[diagnostic(code(typeck::could_not_resolve))]
     CouldNotResolve {
         #[source_code]
         src: Arc<NamedSource<String>>,
         #[label("this is not defined in the current scope.")]
         span: SourceSpan,
         name: EcoString,
+        #[help]
+        help: String,
     },
     #[error("could not assign value to a constant.")]
     #[diagnostic(code(typeck::could_not_assign_constant))]
@@ -82,6 +81,18 @@ pub(crate) enum TypeckError {
         b: String,
         op: BinaryOp,
     },
+    #[error("could not shift by the negative amount `{amount}`.")]
+    #[diagnostic(
+        code(typeck::negative_shift),
+        help("shift amount should be non-negative.")
+    )]
+    NegativeShift {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this shift is incorrect.")]
+        span: SourceSpan,
+        amount: i64,
+    },
     #[error("could not use `as` operator with `{a:?}` & `{b:?}`.")]
     #[diagnostic(
         code(typeck::as_with_non_primitives),
@@ -235,7 +246,7 @@ pub(crate) enum TypeckError {
         name: EcoString,
         def: String,
     },
-    #[error("expected a logical epxression in if.")]
+    #[error("expected a logical expression in if.")]
     #[diagnostic(code(typeck::expected_logical_in_if))]
     ExpectedLogicalInIf {
         #[source_code]
@@ -243,6 +254,61 @@ pub(crate) enum TypeckError {
         #[label("expected logical expression in if.")]
         span: SourceSpan,
     },
+    #[error("expected a logical expression in pattern guard.")]
+    #[diagnostic(code(typeck::expected_logical_in_guard))]
+    ExpectedLogicalInGuard {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("expected logical expression in guard.")]
+        span: SourceSpan,
+    },
+    #[error("could not use `await` in pattern guard.")]
+    #[diagnostic(
+        code(typeck::await_in_guard),
+        help("guards are evaluated synchronously, consider awaiting value before `match`.")
+    )]
+    AwaitInGuard {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this value could not be awaited here.")]
+        span: SourceSpan,
+    },
+    #[error("call of the async function `{name}` is not awaited.")]
+    #[diagnostic(
+        code(typeck::unawaited_async_call),
+        help("function contains `await`, so it returns a promise, consider awaiting the call.")
+    )]
+    UnawaitedAsyncCall {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this call should be awaited.")]
+        span: SourceSpan,
+        name: EcoString,
+    },
+    #[error("could not call non-const function `{name}` in const context.")]
+    #[diagnostic(
+        code(typeck::non_const_call),
+        help("const functions and constants could only call const functions.")
+    )]
+    NonConstCall {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this function is not const.")]
+        span: SourceSpan,
+        name: EcoString,
+    },
+    #[error("could not use non-const value `{name}` in constant.")]
+    #[diagnostic(
+        code(typeck::non_const_expression),
+        help("constants could only use literals and other constants.")
+    )]
+    NonConstExpression {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this value is not a constant.")]
+        span: SourceSpan,
+        name: EcoString,
+    },
     #[error("types missmatch. expected `{expected}`, got `{got}`.")]
     #[diagnostic(code(typeck::types_missmatch))]
     TypesMissmatch {
@@ -251,6 +317,26 @@ pub(crate) enum TypeckError {
         expected: String,
         got: String,
     },
+    #[error("return type missmatch. expected `{expected}`, got `{got}`.")]
+    #[diagnostic(code(typeck::return_type_missmatch))]
+    ReturnTypeMismatch {
+        #[related]
+        related: Vec<TypeckRelated>,
+        expected: String,
+        got: String,
+    },
+    #[error("missing return value. expected `{expected}`.")]
+    #[diagnostic(
+        code(typeck::missing_return),
+        help("last statement of the function body should be an expression.")
+    )]
+    MissingReturn {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this function doesn't return a value.")]
+        span: SourceSpan,
+        expected: String,
+    },
     #[error("wrong unwrap pattern. expected variant of enum, got `{got}`")]
     #[diagnostic(code(typeck::wrong_unwrap_pattern))]
     WrongUnwrapPattern {
@@ -303,6 +389,117 @@ pub(crate) enum TypeckError {
         related: Vec<TypeckRelated>,
         t: String,
     },
+    #[error("found infinite type `{var} = {t}`.")]
+    #[diagnostic(
+        code(typeck::infinite_type),
+        help("type `{var}` could not contain itself.")
+    )]
+    InfiniteType {
+        #[related]
+        related: Vec<TypeckRelated>,
+        var: String,
+        t: String,
+    },
+    #[error("`{got}` is not a subtype of `{expected}`.")]
+    #[diagnostic(
+        code(typeck::subtype_missmatch),
+        help("field `{field}` of `{expected}` is missing in `{got}`.")
+    )]
+    SubtypeMismatch {
+        #[related]
+        related: Vec<TypeckRelated>,
+        expected: String,
+        got: String,
+        field: EcoString,
+    },
+    #[error("no overload of `{name}` matches arguments ({args}).")]
+    #[diagnostic(
+        code(typeck::no_matching_overload),
+        help("argument types should exactly match one of the overloads.")
+    )]
+    NoMatchingOverload {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("no matching overload for this call.")]
+        span: SourceSpan,
+        name: EcoString,
+        args: String,
+    },
+    #[error("call to `{name}` is ambiguous, {count} overloads match.")]
+    #[diagnostic(
+        code(typeck::ambiguous_overload),
+        help("overloads parameters types should not overlap.")
+    )]
+    AmbiguousOverload {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("ambiguous call.")]
+        span: SourceSpan,
+        name: EcoString,
+        count: usize,
+    },
+    #[error("could not throw value of type `{t}`.")]
+    #[diagnostic(
+        code(typeck::invalid_throw_value),
+        help("only strings and struct instances could be thrown.")
+    )]
+    InvalidThrowValue {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this value could not be thrown.")]
+        span: SourceSpan,
+        t: String,
+    },
+    #[error("could not catch value of type `{t}`.")]
+    #[diagnostic(
+        code(typeck::invalid_catch_type),
+        help("only strings and non-generic struct instances could be caught.")
+    )]
+    InvalidCatchType {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this type could not be caught.")]
+        span: SourceSpan,
+        t: String,
+    },
+    #[error("could not propagate value of type `{t}`.")]
+    #[diagnostic(
+        code(typeck::invalid_try_value),
+        help(
+            "only `Optional` values and enums with the single-field `Ok` variant could be propagated with `?`."
+        )
+    )]
+    InvalidTryValue {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this value could not be propagated.")]
+        span: SourceSpan,
+        t: String,
+    },
+    #[error("propagation of value of type `{t}` is ambiguous.")]
+    #[diagnostic(
+        code(typeck::ambiguous_try_value),
+        help("`null` and not `Ok` variant could not be told apart, match the value explicitly.")
+    )]
+    AmbiguousTryValue {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this value could not be propagated.")]
+        span: SourceSpan,
+        t: String,
+    },
+    #[error("label `'{label}` is not defined.")]
+    #[diagnostic(
+        code(typeck::unknown_label),
+        help("`break` could only leave enclosing labeled blocks.")
+    )]
+    UnknownLabel {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("this label is unknown.")]
+        span: SourceSpan,
+        label: EcoString,
+    },
 }
 
 /// Exhaustiveness error
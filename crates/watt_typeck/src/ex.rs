@@ -73,13 +73,20 @@ impl<'module_cx, 'pkg, 'cx> ExMatchCx<'module_cx, 'pkg, 'cx> {
             // So, checking for default patterns
             // `BindTo` and `Wildcard`
             Typ::Function(_, _) => ex.has_default_pattern(&ex.cases),
+            // Optional values could not
+            // be matched by patterns,
+            // because `null` has no pattern.
+            //
+            // So, checking for default patterns
+            // `BindTo` and `Wildcard`
+            Typ::Optional(_) => ex.has_default_pattern(&ex.cases),
             // Could not cover unit
             // values, becuase...
             // it's nothing =)
             //
             // So, checking for default patterns
             // `BindTo` and `Wildcard`
-            Typ::Unit => ex.has_default_pattern(&ex.cases),
+            Typ::Unit | Typ::Never => ex.has_default_pattern(&ex.cases),
             // All type variable values
             // could not be covered,
             // because it's a unknown type
@@ -100,6 +107,11 @@ impl<'module_cx, 'pkg, 'cx> ExMatchCx<'module_cx, 'pkg, 'cx> {
     }
 
     /// Checks that `BindTo` or `Wildcard` pattern exists in cases vec
+    ///
+    /// # Notes
+    /// Guarded patterns are never treated as covering,
+    /// because the guard condition could be false.
+    ///
     fn has_default_pattern(&self, cases: &Vec<Case>) -> bool {
         // Checking for patterns
         for case in cases {
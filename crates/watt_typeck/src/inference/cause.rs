@@ -60,6 +60,7 @@ pub enum Cause<'a> {
     Assignment(&'a Address),
 
     /// A type constraint originating from an return type and block type match check.
+    /// First address is the function, second is the returned value.
     ///
     /// Example:
     /// ```watt
@@ -109,16 +110,19 @@ impl<'a> Cause<'a> {
     /// # Parameters
     /// - `self` — the cause of the type error, e.g., function argument, assignment, pattern, etc.
     /// - `error` — the specific coercion error that occurred (e.g., recursive types or mismatch).
-    /// - `p1` — the first type involved (expected type or one side of recursion).
-    /// - `p2` — the second type involved (actual type or other side of recursion).
+    /// - `p1` — the first type involved (expected type).
+    /// - `p2` — the second type involved (actual type).
     ///
     /// # Returns
     /// A `TypeckError` containing detailed information about the error, including
     /// related source spans and types, suitable for reporting to the user.
     ///
     /// # Behavior
-    /// - If the `CoercionError` is `RecursiveType`, the method generates
-    ///   a `TypeckError::RecursiveType` with type and source spab.
+    /// - If the `CoercionError` is `InfiniteType`, the method generates
+    ///   a `TypeckError::InfiniteType` with the variable, the type it
+    ///   occurs in and source span.
+    /// - If the `CoercionError` is `MissingField`, the method generates
+    ///   a `TypeckError::SubtypeMismatch` with both types and missing field name.
     /// - If the `CoercionError` is `TypesMissmatch`, the method generates
     ///   a `TypeckError::TypesMissmatch`, adjusting the related spans depending
     ///   on the specific `Cause` variant (e.g., assignment, function return, pattern, branch).
@@ -130,19 +134,39 @@ impl<'a> Cause<'a> {
         p2: String,
     ) -> TypeckError {
         match error {
-            CoercionError::RecursiveType => match self {
+            CoercionError::InfiniteType { var, t } => match self {
                 Cause::StructArgument(address)
                 | Cause::VariantArgument(address)
                 | Cause::FunctionArgument(address)
                 | Cause::Assignment(address)
-                | Cause::Return(address, _)
+                | Cause::Return(_, address)
                 | Cause::Pattern(address, _)
-                | Cause::Branch(address, _) => bail!(TypeckError::RecursiveType {
-                    related: vec![TypeckRelated::Here {
+                | Cause::Branch(address, _) => bail!(TypeckError::InfiniteType {
+                    related: vec![TypeckRelated::ThisType {
                         src: address.source.clone(),
-                        span: address.span.clone().into()
+                        span: address.span.clone().into(),
+                        t: t.clone()
                     }],
-                    t: p1
+                    var,
+                    t
+                }),
+            },
+            CoercionError::MissingField(field) => match self {
+                Cause::StructArgument(address)
+                | Cause::VariantArgument(address)
+                | Cause::FunctionArgument(address)
+                | Cause::Assignment(address)
+                | Cause::Return(_, address)
+                | Cause::Pattern(address, _)
+                | Cause::Branch(address, _) => bail!(TypeckError::SubtypeMismatch {
+                    related: vec![TypeckRelated::ThisType {
+                        src: address.source.clone(),
+                        span: address.span.clone().into(),
+                        t: p2.clone()
+                    }],
+                    expected: p1,
+                    got: p2,
+                    field
                 }),
             },
             CoercionError::TypesMissmatch => match self {
@@ -150,15 +174,16 @@ impl<'a> Cause<'a> {
                 | Cause::VariantArgument(address)
                 | Cause::FunctionArgument(address)
                 | Cause::Assignment(address) => bail!(TypeckError::TypesMissmatch {
-                    related: vec![TypeckRelated::Here {
+                    related: vec![TypeckRelated::ThisType {
                         src: address.source.clone(),
-                        span: address.span.clone().into()
+                        span: address.span.clone().into(),
+                        t: p2.clone()
                     }],
                     expected: p1,
                     got: p2
                 }),
                 Cause::Return(a1, a2) => {
-                    bail!(TypeckError::TypesMissmatch {
+                    bail!(TypeckError::ReturnTypeMismatch {
                         related: vec![
                             TypeckRelated::ThisType {
                                 src: a1.source.clone(),
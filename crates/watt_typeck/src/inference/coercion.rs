@@ -7,6 +7,7 @@ use crate::{
         typ::{TyVar, Typ},
     },
 };
+use ecow::EcoString;
 use id_arena::Id;
 use tracing::instrument;
 use watt_common::{bail, skip};
@@ -22,10 +23,13 @@ use watt_common::{bail, skip};
 ///
 #[derive(Debug, Clone)]
 pub enum CoercionError {
-    /// Represents types recursion.
-    RecursiveType,
+    /// Represents infinite type, produced by
+    /// the type variable `var`, that occurs in type `t`.
+    InfiniteType { var: String, t: String },
     /// Represents types missmatch.
     TypesMissmatch,
+    /// Represents missing field of the supertype.
+    MissingField(EcoString),
 }
 
 /// A constraint in the type inference system.
@@ -38,6 +42,8 @@ pub enum CoercionError {
 ///
 /// - [`Eq`] — unifies two types or type variables.
 /// - [`Same`] — unifies all types within a group are same.
+/// - [`Subtype`] — checks first type is a subtype of the second.
+/// - [`Assign`] — checks first type could be assigned to the second.
 ///
 #[derive(Debug, Clone)]
 pub enum Coercion {
@@ -45,11 +51,18 @@ pub enum Coercion {
     Eq(Typ, Typ),
     /// Equality of many types
     Same(Vec<Typ>),
+    /// First type has at least the fields
+    /// of the second type with same types
+    Subtype(Typ, Typ),
+    /// First type is equal to the second,
+    /// or to its inner type, if second is `Optional`
+    Assign(Typ, Typ),
 }
 
 /// Solve a type coercion constraint.
 ///
-/// Dispatches to `eq` for `Eq` constraints or `same` for `Same` constraints.
+/// Dispatches to `eq` for `Eq` constraints, `same` for `Same` constraints,
+/// `subtype` for `Subtype` constraints or `assign` for `Assign` constraints.
 ///
 /// # Arguments
 /// - `icx` — the inference context for substitutions and type state.
@@ -61,6 +74,8 @@ pub fn coerce(icx: &mut InferCx, cause: Cause, coercion: Coercion) {
     match coercion.clone() {
         Coercion::Eq(expected, got) => eq(icx, &cause, expected, got),
         Coercion::Same(items) => same(icx, &cause, items),
+        Coercion::Subtype(sub, sup) => subtype(icx, &cause, sub, sup),
+        Coercion::Assign(value, target) => assign(icx, &cause, value, target),
     }
 }
 
@@ -94,16 +109,88 @@ fn same(icx: &mut InferCx, cause: &Cause, mut items: Vec<Typ>) {
     }
 }
 
+/// Solve a `Subtype(sub, sup)` coercion, checking `sub` is a subtype of `sup`.
+///
+/// Structs of different declarations are compared by width: `sub`
+/// should have every field of `sup`, and field types are unified.
+/// Non-optional `sub` is also a subtype of `Optional` of its supertype,
+/// so `T` could be coerced into `Optional[T]`. Other types are unified,
+/// like with `Eq`.
+///
+/// # Arguments
+/// - `icx` — the inference context.
+/// - `cause` — the cause for error reporting.
+/// - `sub` — the type, that should be a subtype.
+/// - `sup` — the expected supertype.
+///
+#[instrument(skip(icx), level = "trace")]
+fn subtype(icx: &mut InferCx, cause: &Cause, sub: Typ, sup: Typ) {
+    // Applying substs
+    let t1 = icx.apply(sub);
+    let t2 = icx.apply(sup);
+    // Checking subtyping
+    match (&t1, &t2) {
+        (Typ::Struct(id1, _), Typ::Struct(id2, _)) if id1 != id2 => {
+            let fields = t1.fields(icx);
+            for expected in t2.fields(icx) {
+                match fields.iter().find(|field| field.name == expected.name) {
+                    Some(field) => eq(icx, cause, expected.typ, field.typ.clone()),
+                    None => {
+                        let (p1, p2) = (t2.pretty(icx), t1.pretty(icx));
+                        bail!(cause.clone().into_typeck_error(
+                            CoercionError::MissingField(expected.name),
+                            p1,
+                            p2
+                        ))
+                    }
+                }
+            }
+        }
+        (Typ::Optional(_) | Typ::Var(_) | Typ::Never, Typ::Optional(_)) => eq(icx, cause, t2, t1),
+        (_, Typ::Optional(inner)) => subtype(icx, cause, t1.clone(), *inner.clone()),
+        _ => eq(icx, cause, t2, t1),
+    }
+}
+
+/// Solve an `Assign(value, target)` coercion, checking `value`
+/// could be assigned to the `target`.
+///
+/// Non-optional `value` could be assigned to `Optional` target,
+/// so `T` is coerced into `Optional[T]`. Other types are unified,
+/// like with `Eq`.
+///
+/// # Arguments
+/// - `icx` — the inference context.
+/// - `cause` — the cause for error reporting.
+/// - `value` — the type of the assigned value.
+/// - `target` — the type of the assignment target.
+///
+#[instrument(skip(icx), level = "trace")]
+fn assign(icx: &mut InferCx, cause: &Cause, value: Typ, target: Typ) {
+    // Applying substs
+    let t1 = icx.apply(value);
+    let t2 = icx.apply(target);
+    // Checking optional coercion
+    match (&t1, &t2) {
+        (Typ::Optional(_) | Typ::Var(_) | Typ::Never, Typ::Optional(_)) => eq(icx, cause, t2, t1),
+        (_, Typ::Optional(inner)) => eq(icx, cause, *inner.clone(), t1),
+        _ => eq(icx, cause, t2, t1),
+    }
+}
+
 /// Core method to unify two types.
 /// Returns `Ok(())` if unification succeeds, otherwise a `CoercionError`.
 ///
-fn unify(icx: &mut InferCx, expected: Typ, got: Typ) -> Result<(), CoercionError> {
+pub(crate) fn unify(icx: &mut InferCx, expected: Typ, got: Typ) -> Result<(), CoercionError> {
     // Applying substs
     let t1 = icx.apply(expected);
     let t2 = icx.apply(got);
     // Unifying
     if t1 != t2 {
         match (&t1, &t2) {
+            // `Never` is a subtype of all types, so it's
+            // not substituted into the type variables
+            (Typ::Never, _) | (_, Typ::Never) => Ok(()),
             (Typ::Var(a), Typ::Var(b)) => {
                 if a != b {
                     icx.substitute(*a, t2.clone());
@@ -112,7 +199,10 @@ fn unify(icx: &mut InferCx, expected: Typ, got: Typ) -> Result<(), CoercionError
             }
             (Typ::Var(a), b) | (b, Typ::Var(a)) => {
                 if occurs(icx, *a, b) {
-                    Err(CoercionError::RecursiveType)
+                    Err(CoercionError::InfiniteType {
+                        var: Typ::Var(*a).pretty(icx),
+                        t: b.pretty(icx),
+                    })
                 } else {
                     icx.substitute(*a, b.clone());
                     Ok(())
@@ -152,6 +242,7 @@ fn unify(icx: &mut InferCx, expected: Typ, got: Typ) -> Result<(), CoercionError
                 let r2 = t2.ret(icx);
                 unify(icx, r1, r2)
             }
+            (Typ::Optional(a), Typ::Optional(b)) => unify(icx, *a.clone(), *b.clone()),
             _ => Err(CoercionError::TypesMissmatch),
         }
     } else {
@@ -189,6 +280,7 @@ fn occurs(icx: &mut InferCx, own: Id<TyVar>, t: &Typ) -> bool {
             .variants(icx)
             .iter()
             .any(|v| v.fields.iter().any(|f| occurs(icx, own, &f.typ))),
-        Typ::Generic(_) | Typ::Prelude(_) | Typ::Unit => false,
+        Typ::Optional(inner) => occurs(icx, own, &inner),
+        Typ::Generic(_) | Typ::Prelude(_) | Typ::Unit | Typ::Never => false,
     }
 }
@@ -8,12 +8,12 @@ use crate::{
         cx::InferCx,
         def::{ModuleDef, TypeDef},
         res::Res,
-        typ::{GenericArgs, Module, Typ},
+        typ::{Function, GenericArgs, Module, Typ, WithPublicity},
     },
 };
 use ecow::EcoString;
 use id_arena::Id;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tracing::instrument;
 use watt_common::{address::Address, bail};
 
@@ -42,6 +42,14 @@ use watt_common::{address::Address, bail};
 ///   Definitions imported from other modules, keyed by their local names.
 ///   Enables access to external types and constants without fully qualifying them.
 ///
+/// - `imported_locations: HashMap<EcoString, Address>`
+///   Locations of the `use` declarations, that imported definitions.
+///   Used to report shadowed imports.
+///
+/// - `used_imports: HashSet<EcoString>`
+///   Names of the imported definitions, that were resolved at least once.
+///   Used to report unused imports.
+///
 #[derive(Default, Debug)]
 pub struct ModuleResolver {
     /// Ribs stack of module
@@ -52,6 +60,10 @@ pub struct ModuleResolver {
     pub imported_modules: HashMap<EcoString, Id<Module>>,
     /// Imported definitions
     pub imported_defs: HashMap<EcoString, ModuleDef>,
+    /// Imported definitions locations
+    pub imported_locations: HashMap<EcoString, Address>,
+    /// Used imported definitions
+    pub used_imports: HashSet<EcoString>,
 }
 
 /// Implementation
@@ -86,7 +98,7 @@ impl ModuleResolver {
                         t: name.clone()
                     })
                 }
-                ModuleDef::Const(_) | ModuleDef::Function(_) => {
+                ModuleDef::Const(_) | ModuleDef::Function(_) | ModuleDef::OverloadedFn(_) => {
                     bail!(TypeckError::VariableIsAlreadyDefined {
                         src: address.source.clone(),
                         span: address.span.clone().into(),
@@ -100,6 +112,43 @@ impl ModuleResolver {
         }
     }
 
+    /// Defines an overloaded extern function in the module.
+    ///
+    /// If the overload set with the given name already exists,
+    /// the function is appended to it, otherwise new overload set is created.
+    ///
+    /// # Parameters
+    ///
+    /// - `address: &Address`
+    ///   The source location of the definition, used for error reporting.
+    ///
+    /// - `name: &EcoString`
+    ///   The identifier name for the overload set.
+    ///
+    /// - `def: WithPublicity<Id<Function>>`
+    ///   The extern function to append.
+    ///
+    /// # Errors
+    ///
+    /// - Raises `TypeckError::TypeIsAlreadyDefined` or `TypeckError::VariableIsAlreadyDefined`
+    ///   if the name is taken by a non-overloaded definition.
+    ///
+    pub fn define_overload(
+        &mut self,
+        address: &Address,
+        name: &EcoString,
+        def: WithPublicity<Id<Function>>,
+    ) {
+        match self.module_defs.get_mut(name) {
+            Some(ModuleDef::OverloadedFn(fns)) => fns.push(def),
+            Some(_) => self.define_module(address, name, ModuleDef::OverloadedFn(vec![def])),
+            None => {
+                self.module_defs
+                    .insert(name.clone(), ModuleDef::OverloadedFn(vec![def]));
+            }
+        }
+    }
+
     /// Defines a local-level item (local variable) if it is not already defined.
     ///
     /// This method inserts a new definition into the last rib's scope. It performs
@@ -125,6 +174,40 @@ impl ModuleResolver {
         self.ribs_stack.define(address, name, typ);
     }
 
+    /// Suggests names similar to the unresolved `name`.
+    ///
+    /// Candidates are local variables, module definitions, imported
+    /// definitions and imported modules, that are within the edit distance
+    /// of the third of the name length. At most three closest names are listed.
+    ///
+    fn suggest(&self, name: &EcoString) -> String {
+        // Max allowed edit distance
+        let max_distance = (name.chars().count() / 3).max(1);
+        // Collecting candidates
+        let mut candidates: Vec<(usize, &EcoString)> = self
+            .ribs_stack
+            .names()
+            .chain(self.module_defs.keys())
+            .chain(self.imported_defs.keys())
+            .chain(self.imported_modules.keys())
+            .map(|candidate| (edit_distance(name, candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect();
+        candidates.sort();
+        candidates.dedup();
+        // Rendering suggestion
+        if candidates.is_empty() {
+            String::from("check symbol/variable existence.")
+        } else {
+            let names: Vec<String> = candidates
+                .iter()
+                .take(3)
+                .map(|(_, candidate)| format!("`{candidate}`"))
+                .collect();
+            format!("did you mean {}?", names.join(", "))
+        }
+    }
+
     /// Resolves an identifier to its corresponding value, type, or module.
     ///
     /// This method looks up the given `name` in the current module's namespace
@@ -162,6 +245,8 @@ impl ModuleResolver {
     ///    - `ModuleDef::Type` -> `Res::Custom(TypeDef)`
     ///    - `ModuleDef::Const` -> `Res::Value(Typ)`
     ///
+    ///    Resolved name is marked as used in `used_imports`.
+    ///
     /// 4. **Imported modules lookup**
     ///    If the identifier is not found in definitions, the resolver checks
     ///    `imported_modules`. If found, the identifier resolves to a module:
@@ -170,7 +255,7 @@ impl ModuleResolver {
     /// 5. **Error if not found**
     ///    If the identifier cannot be found in any of the above cases, the
     ///    resolver raises a `TypeckError::CouldNotResolve` with the given source
-    ///    location and the unresolved name.
+    ///    location, the unresolved name and similar names suggestion.
     ///
     /// # Returns
     ///
@@ -182,7 +267,7 @@ impl ModuleResolver {
     ///
     /// `Res::Variant(Rc<Enum>, EnumVariant)` will be never returned
     ///
-    pub fn resolve(&self, address: &Address, name: &EcoString) -> Res {
+    pub fn resolve(&mut self, address: &Address, name: &EcoString) -> Res {
         // Checking existence in ribs
         match self.ribs_stack.lookup(name) {
             Some(typ) => Res::Value(typ),
@@ -194,23 +279,33 @@ impl ModuleResolver {
                     ModuleDef::Function(ty) => {
                         Res::Value(Typ::Function(ty.value, GenericArgs::default()))
                     }
+                    ModuleDef::OverloadedFn(fns) => {
+                        Res::Overloaded(fns.iter().map(|f| f.value).enumerate().collect())
+                    }
                 },
                 None => match self.imported_defs.get(name) {
                     // Checking existence in imported defs
-                    Some(typ) => match typ {
-                        ModuleDef::Type(ty) => Res::Custom(ty.value.clone()),
-                        ModuleDef::Const(ty) => Res::Const(ty.value.clone()),
-                        ModuleDef::Function(ty) => {
-                            Res::Value(Typ::Function(ty.value, GenericArgs::default()))
+                    Some(typ) => {
+                        self.used_imports.insert(name.clone());
+                        match typ {
+                            ModuleDef::Type(ty) => Res::Custom(ty.value.clone()),
+                            ModuleDef::Const(ty) => Res::Const(ty.value.clone()),
+                            ModuleDef::Function(ty) => {
+                                Res::Value(Typ::Function(ty.value, GenericArgs::default()))
+                            }
+                            ModuleDef::OverloadedFn(fns) => {
+                                Res::Overloaded(fns.iter().map(|f| f.value).enumerate().collect())
+                            }
                         }
-                    },
+                    }
                     None => match self.imported_modules.get(name) {
                         // Checking existence in modules
                         Some(_) => Res::Module(name.clone()),
                         None => bail!(TypeckError::CouldNotResolve {
                             src: address.source.clone(),
                             span: address.clone().span.into(),
-                            name: name.clone()
+                            name: name.clone(),
+                            help: self.suggest(name)
                         }),
                     },
                 },
@@ -242,19 +337,19 @@ impl ModuleResolver {
     /// 2. **Imported definitions lookup**
     ///    If the identifier is not present in module definitions, the resolver
     ///    checks `imported_defs` for the type existence (`TypeDef`), which contains
-    ///    definitions imported from other modules.
+    ///    definitions imported from other modules. Resolved name is marked as used.
     ///
     /// # Errors
     ///
     /// - Raises `TypeckError::TypeIsNotDefined` if the type cannot be resolved.
     /// - Raises `TypeckError::CouldNotUseValueAsType` if the const shadows the type name.
     ///
-    pub fn resolve_type(&self, address: &Address, name: &EcoString) -> TypeDef {
+    pub fn resolve_type(&mut self, address: &Address, name: &EcoString) -> TypeDef {
         // Checking existence in module definitions
         match self.module_defs.get(name) {
             Some(typ) => match typ {
                 ModuleDef::Type(ty) => ty.value.clone(),
-                ModuleDef::Const(_) | ModuleDef::Function(_) => {
+                ModuleDef::Const(_) | ModuleDef::Function(_) | ModuleDef::OverloadedFn(_) => {
                     bail!(TypeckError::CouldNotUseValueAsType {
                         src: address.source.clone(),
                         span: address.clone().span.into(),
@@ -264,16 +359,21 @@ impl ModuleResolver {
             },
             None => match self.imported_defs.get(name) {
                 // Checking existence in imported defs
-                Some(typ) => match typ {
-                    ModuleDef::Type(ty) => ty.value.clone(),
-                    ModuleDef::Const(_) | ModuleDef::Function(_) => {
-                        bail!(TypeckError::CouldNotUseValueAsType {
-                            src: address.source.clone(),
-                            span: address.clone().span.into(),
-                            v: name.clone()
-                        })
+                Some(typ) => {
+                    self.used_imports.insert(name.clone());
+                    match typ {
+                        ModuleDef::Type(ty) => ty.value.clone(),
+                        ModuleDef::Const(_)
+                        | ModuleDef::Function(_)
+                        | ModuleDef::OverloadedFn(_) => {
+                            bail!(TypeckError::CouldNotUseValueAsType {
+                                src: address.source.clone(),
+                                span: address.clone().span.into(),
+                                v: name.clone()
+                            })
+                        }
                     }
-                },
+                }
                 None => bail!(TypeckError::TypeIsNotDefined {
                     src: address.source.clone(),
                     span: address.clone().span.into(),
@@ -310,6 +410,37 @@ impl ModuleResolver {
         self.ribs_stack.push();
     }
 
+    /// Pushes a new function rib onto the ribs stack.
+    ///
+    /// Function ribs start a new function scope,
+    /// bindings of the enclosing function are not shadowed by it.
+    ///
+    pub fn push_fn_rib(&mut self) {
+        self.ribs_stack.push_fn();
+    }
+
+    /// Replaces the ribs stack with the given one,
+    /// returning the previous ribs stack.
+    ///
+    /// Used to infer a function body in the fresh scope,
+    /// while other function body is being inferred.
+    ///
+    pub fn replace_ribs(&mut self, ribs: RibsStack) -> RibsStack {
+        std::mem::replace(&mut self.ribs_stack, ribs)
+    }
+
+    /// Looks up a definition address of the local variable.
+    pub fn lookup_local_address(&self, name: &EcoString) -> Option<&Address> {
+        self.ribs_stack.lookup_address(name)
+    }
+
+    /// Looks up an outer binding of the current function,
+    /// that would be shadowed by defining `name` in the current scope.
+    ///
+    pub fn lookup_shadowed(&self, name: &EcoString) -> Option<&Address> {
+        self.ribs_stack.lookup_shadowed(name)
+    }
+
     /// Pops the top rib from the ribs stack.
     ///
     /// Returns the popped `Rib` if it exists. Popping a rib exits
@@ -399,6 +530,8 @@ impl ModuleResolver {
                         def: already.pretty(icx),
                     }),
                     None => {
+                        self.imported_locations
+                            .insert(name.clone(), address.clone());
                         self.imported_defs.insert(name, def.clone());
                     }
                 },
@@ -414,3 +547,19 @@ impl ModuleResolver {
         }
     }
 }
+
+/// Computes Levenshtein edit distance between two names
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // Distances from the previous `a` prefix to every `b` prefix
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
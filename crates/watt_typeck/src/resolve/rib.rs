@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use watt_common::{address::Address, bail};
 
 /// A single lexical scope that mappings
-/// variable names to their types.
+/// variable names to their definition addresses and types.
 ///
 /// `Rib` represents a single environment scope, storing variables
 /// declared within that scope. Each `Rib` is typically pushed onto the
@@ -14,7 +14,7 @@ use watt_common::{address::Address, bail};
 /// # Important
 /// - New rib isn't created during `Enum` or `Struct` analysys.
 ///
-pub type Rib = HashMap<EcoString, Typ>;
+pub type Rib = HashMap<EcoString, (Address, Typ)>;
 
 /// Stack of lexical scopes (ribs).
 ///
@@ -29,6 +29,8 @@ pub type Rib = HashMap<EcoString, Typ>;
 #[derive(Default, Debug)]
 pub struct RibsStack {
     stack: Vec<Rib>,
+    /// Indices of the ribs, that are starting function scopes
+    functions: Vec<usize>,
 }
 
 /// Implementation
@@ -45,6 +47,17 @@ impl RibsStack {
         self.stack.push(HashMap::new())
     }
 
+    /// Pushes a new, empty rib onto the stack,
+    /// marking it as the start of a function scope.
+    ///
+    /// Use this when entering a function body,
+    /// so outer bindings are not reported as shadowed.
+    ///
+    pub fn push_fn(&mut self) {
+        self.functions.push(self.stack.len());
+        self.stack.push(HashMap::new())
+    }
+
     /// Pops the top rib out the stack.
     ///
     /// Returns the popped `Rib` if the stack was not empty.
@@ -53,7 +66,11 @@ impl RibsStack {
     /// Used to exit the scope.
     ///
     pub fn pop(&mut self) -> Option<Rib> {
-        self.stack.pop()
+        let rib = self.stack.pop();
+        if self.functions.last() == Some(&self.stack.len()) {
+            self.functions.pop();
+        }
+        rib
     }
 
     /// Defines a variable in the current scope.
@@ -70,7 +87,7 @@ impl RibsStack {
         match self.stack.last_mut() {
             Some(env) => {
                 if !env.contains_key(name) {
-                    env.insert(name.clone(), typ);
+                    env.insert(name.clone(), (address.clone(), typ));
                 } else {
                     bail!(TypeckError::VariableIsAlreadyDefined {
                         src: address.source.clone(),
@@ -99,9 +116,57 @@ impl RibsStack {
     pub fn lookup(&self, name: &EcoString) -> Option<Typ> {
         for env in self.stack.iter().rev() {
             if env.contains_key(name) {
-                return Some(env.get(name).unwrap().clone());
+                return Some(env.get(name).unwrap().1.clone());
             }
         }
         None
     }
+
+    /// Looks up a definition address of the variable by name,
+    /// searching from innermost to outermost scope.
+    ///
+    /// # Parameters
+    /// - `name`: The variable name to lookup.
+    ///
+    /// # Returns
+    /// - `Some(Address)` if the variable is found in any scope.
+    /// - `None` if the variable does not exist in any active scope.
+    ///
+    pub fn lookup_address(&self, name: &EcoString) -> Option<&Address> {
+        self.stack
+            .iter()
+            .rev()
+            .find_map(|env| env.get(name).map(|(address, _)| address))
+    }
+
+    /// Returns names of the variables in all active scopes.
+    pub fn names(&self) -> impl Iterator<Item = &EcoString> {
+        self.stack.iter().flat_map(|env| env.keys())
+    }
+
+    /// Looks up a binding, that would be shadowed by
+    /// defining `name` in the current scope.
+    ///
+    /// # Parameters
+    /// - `name`: The variable name to lookup.
+    ///
+    /// # Returns
+    /// - `Some(Address)` of the outer binding, if it exists.
+    /// - `None` if there is no outer binding with this name.
+    ///
+    /// # Behavior
+    /// - Only outer scopes of the current function are checked,
+    ///   bindings captured by closures are not considered shadowed.
+    ///
+    pub fn lookup_shadowed(&self, name: &EcoString) -> Option<&Address> {
+        // Function scope start
+        let start = self.functions.last().copied().unwrap_or(0);
+        let end = self.stack.len().saturating_sub(1);
+        // Checking outer scopes
+        self.stack
+            .get(start..end)?
+            .iter()
+            .rev()
+            .find_map(|env| env.get(name).map(|(address, _)| address))
+    }
 }
@@ -1,7 +1,7 @@
 /// Imports
 use crate::{
     inference::generics::Generics,
-    typ::typ::{Enum, Function, GenericArgs, GenericParameter, Struct, TyVar, Typ},
+    typ::typ::{Alias, Enum, Function, GenericArgs, GenericParameter, Struct, TyVar, Typ},
 };
 use id_arena::{Arena, Id};
 use indexmap::IndexMap;
@@ -32,6 +32,9 @@ pub struct TyCx {
 
     /// Arena storing all enum definitions.
     pub enums: Arena<Enum>,
+
+    /// Arena storing all type alias definitions.
+    pub aliases: Arena<Alias>,
 }
 
 impl TyCx {
@@ -56,6 +59,13 @@ impl TyCx {
         self.enums.alloc(enum_)
     }
 
+    /// Allocates a new type alias definition in the type context
+    /// and returns its unique ID.
+    #[inline]
+    pub fn insert_alias(&mut self, alias: Alias) -> Id<Alias> {
+        self.aliases.alloc(alias)
+    }
+
     /// Returns an immutable reference to a function definition.
     ///
     /// # Panics
@@ -86,6 +96,16 @@ impl TyCx {
         self.enums.get(id).expect("invalid Enum id")
     }
 
+    /// Returns an immutable reference to a type alias definition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given `id` does not belong to this `TyCx`.
+    #[inline]
+    pub fn alias(&self, id: Id<Alias>) -> &Alias {
+        self.aliases.get(id).expect("invalid Alias id")
+    }
+
     /// Returns a mutable reference to a function definition.
     ///
     /// # Panics
@@ -116,6 +136,16 @@ impl TyCx {
         self.enums.get_mut(id).expect("invalid Enum id")
     }
 
+    /// Returns a mutable reference to a type alias definition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given `id` does not belong to this `TyCx`.
+    #[inline]
+    pub fn alias_mut(&mut self, id: Id<Alias>) -> &mut Alias {
+        self.aliases.get_mut(id).expect("invalid Alias id")
+    }
+
     /// Executes `f` with an immutable reference to the function definition
     /// if the given `id` exists in this context.
     ///
@@ -274,6 +304,20 @@ impl<'tcx> InferCx<'tcx> {
         self.type_variables.alloc(TyVar::Bound(to))
     }
 
+    /// Takes snapshot of the type variables, used to
+    /// try unification without committing its substitutions
+    ///
+    pub fn snapshot(&self) -> Arena<TyVar> {
+        self.type_variables.clone()
+    }
+
+    /// Rolls type variables back to the snapshot,
+    /// taken by [`InferCx::snapshot`]
+    ///
+    pub fn rollback(&mut self, snapshot: Arena<TyVar>) {
+        self.type_variables = snapshot;
+    }
+
     /// Return immutable reference to the type variable by id
     ///
     pub fn get(&self, id: Id<TyVar>) -> &TyVar {
@@ -328,6 +372,7 @@ impl<'tcx> InferCx<'tcx> {
                         .collect(),
                 },
             ),
+            Typ::Optional(inner) => Typ::Optional(Box::new(self.apply(*inner))),
             other => other,
         }
     }
@@ -404,7 +449,7 @@ impl<'icx, 'tcx> FresheningCx<'icx, 'tcx> {
     /// Generic(id) -> Unbound($id)
     pub fn mk_ty(&mut self, t: Typ) -> Typ {
         match t {
-            Typ::Prelude(_) | Typ::Unit | Typ::Var(_) => t,
+            Typ::Prelude(_) | Typ::Unit | Typ::Never | Typ::Var(_) => t,
             Typ::Generic(id) => {
                 // If typ is already specified
                 if let Some(typ) = self.mapping.get(&id) {
@@ -447,6 +492,7 @@ impl<'icx, 'tcx> FresheningCx<'icx, 'tcx> {
 
                 Typ::Enum(id, generics)
             }
+            Typ::Optional(inner) => Typ::Optional(Box::new(self.mk_ty(*inner))),
         }
     }
 
@@ -3,7 +3,7 @@ use crate::{
     pretty::Pretty,
     typ::{
         cx::InferCx,
-        typ::{Enum, Function, Struct, Typ, WithPublicity},
+        typ::{Alias, Enum, Function, Struct, Typ, WithPublicity},
     },
 };
 use id_arena::Id;
@@ -28,12 +28,18 @@ use std::fmt::Debug;
 /// - `Function(WithPublicity<Rc<Function>>)`
 ///   Represents a function.
 ///
+/// - `OverloadedFn(Vec<WithPublicity<Id<Function>>>)`
+///   Represents a set of extern functions sharing the same name.
+///   Overload is selected at the call site by the arguments types.
+///
 #[derive(Clone, Debug)]
 pub enum ModuleDef {
     /// User-defined type
     Type(WithPublicity<TypeDef>),
     /// Function
     Function(WithPublicity<Id<Function>>),
+    /// Overloaded extern functions
+    OverloadedFn(Vec<WithPublicity<Id<Function>>>),
     /// Constant with fully inferred type
     Const(WithPublicity<Typ>),
 }
@@ -51,6 +57,10 @@ impl Pretty for ModuleDef {
         match self {
             ModuleDef::Type(ty) => ty.value.pretty(icx),
             ModuleDef::Function(f) => format!("Function({})", icx.tcx.function(f.value).name),
+            ModuleDef::OverloadedFn(fns) => match fns.first() {
+                Some(f) => format!("OverloadedFn({})", icx.tcx.function(f.value).name),
+                None => "OverloadedFn".to_string(),
+            },
             ModuleDef::Const(ty) => format!("Const({})", ty.value.pretty(icx)),
         }
     }
@@ -66,10 +76,14 @@ impl Pretty for ModuleDef {
 /// - `Struct(Id<Struct>)`
 ///   Represents struct type definition
 ///
+/// - `Alias(Id<Alias>)`
+///   Represents type alias definition
+///
 #[derive(Clone, PartialEq)]
 pub enum TypeDef {
     Enum(Id<Enum>),
     Struct(Id<Struct>),
+    Alias(Id<Alias>),
 }
 
 /// Pretty implementation for `TypeDef`
@@ -85,6 +99,7 @@ impl Pretty for TypeDef {
         match self {
             TypeDef::Enum(id) => format!("Enum({})", icx.tcx.enum_(*id).name),
             TypeDef::Struct(id) => format!("Struct({})", icx.tcx.struct_(*id).name),
+            TypeDef::Alias(id) => format!("Alias({})", icx.tcx.alias(*id).name),
         }
     }
 }
@@ -95,6 +110,7 @@ impl Debug for TypeDef {
         match self {
             TypeDef::Enum(en) => write!(f, "Enum({en:?})"),
             TypeDef::Struct(ty) => write!(f, "Struct({ty:?})"),
+            TypeDef::Alias(al) => write!(f, "Alias({al:?})"),
         }
     }
 }
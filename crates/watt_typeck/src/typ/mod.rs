@@ -1,5 +1,5 @@
 pub mod cx;
 pub mod def;
 pub mod res;
-#[allow(clippy::self_named_module_files)]
+#[allow(clippy::self_named_module_files, clippy::module_inception)]
 pub mod typ;
@@ -5,10 +5,11 @@ use crate::{
     typ::{
         cx::InferCx,
         def::TypeDef,
-        typ::{EnumVariant, Typ},
+        typ::{EnumVariant, Function, Typ},
     },
 };
 use ecow::EcoString;
+use id_arena::Id;
 use watt_common::{address::Address, bail};
 
 /// Represents the result of a name/type resolution.
@@ -32,6 +33,11 @@ use watt_common::{address::Address, bail};
 /// - `Value(Typ)`
 ///   The identifier resolves directly to a type/value
 ///
+/// - `Overloaded(Vec<(usize, Id<Function>)>)`
+///   The identifier resolves to a set of overloaded extern functions,
+///   paired with their indices in the declaration order of the module.
+///   Could be used only as a callee.
+///
 /// - `Const(Typ)`
 ///   The identifier resolves directly to a const type/value
 ///
@@ -41,6 +47,7 @@ pub enum Res {
     Custom(TypeDef),
     Variant(Typ, EnumVariant),
     Value(Typ),
+    Overloaded(Vec<(usize, Id<Function>)>),
     Const(Typ),
 }
 
@@ -91,6 +98,10 @@ impl Pretty for Res {
             Res::Custom(def) => def.pretty(icx),
             Res::Variant(typ, variant) => format!("Variant({}.{})", typ.pretty(icx), variant.name),
             Res::Value(typ) => format!("Value({})", typ.pretty(icx)),
+            Res::Overloaded(fns) => match fns.first() {
+                Some((_, f)) => format!("Overloaded({})", icx.tcx.function(*f).name),
+                None => "Overloaded".to_string(),
+            },
             Res::Const(typ) => format!("Const({})", typ.pretty(icx)),
         }
     }
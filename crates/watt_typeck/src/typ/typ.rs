@@ -8,7 +8,10 @@ use id_arena::Id;
 use indexmap::IndexMap;
 use miette::NamedSource;
 use std::{collections::HashMap, fmt::Debug, sync::Arc};
-use watt_ast::ast::Publicity;
+use watt_ast::{
+    ast::{Publicity, TypePath},
+    hints::Hints,
+};
 use watt_common::address::Address;
 
 /// Represents built-in or prelude types in the language.
@@ -74,6 +77,19 @@ pub struct Parameter {
     pub typ: Typ,
 }
 
+/// Represents a deprecation of the function or type,
+/// declared by the `#[deprecated]` attribute.
+///
+/// # Fields
+///
+/// - `message: Option<EcoString>`
+///   The deprecation message, if it's given.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deprecation {
+    pub message: Option<EcoString>,
+}
+
 /// Represents a generic parameter in a type or function.
 ///
 /// A `GenericParameter` stores the name and source location of a
@@ -155,6 +171,9 @@ impl Debug for Field {
 /// - `fields: Vec<Field>`
 ///   A list of fields in the struct, each with its name, type, and location.
 ///
+/// - `deprecated: Option<Deprecation>`
+///   Deprecation of the struct, if it's deprecated.
+///
 #[derive(Clone)]
 pub struct Struct {
     pub location: Address,
@@ -162,6 +181,7 @@ pub struct Struct {
     pub name: EcoString,
     pub generics: Vec<GenericParameter>,
     pub fields: Vec<Field>,
+    pub deprecated: Option<Deprecation>,
 }
 
 /// Debug implementation
@@ -240,6 +260,9 @@ impl Debug for EnumVariant {
 ///   A list of variants for this enum, each with its own name, location,
 ///   and optional parameters.
 ///
+/// - `deprecated: Option<Deprecation>`
+///   Deprecation of the enum, if it's deprecated.
+///
 #[derive(Clone)]
 #[allow(dead_code)]
 pub struct Enum {
@@ -248,6 +271,7 @@ pub struct Enum {
     pub name: EcoString,
     pub generics: Vec<GenericParameter>,
     pub variants: Vec<EnumVariant>,
+    pub deprecated: Option<Deprecation>,
 }
 
 /// Debug implementation for `Enum`
@@ -268,6 +292,63 @@ impl PartialEq for Enum {
     }
 }
 
+/// Represents a resolution state of the type alias.
+///
+/// # Variants
+///
+/// - `Unresolved`
+///   Alias target is not resolved yet.
+///
+/// - `Resolving`
+///   Alias target is being resolved right now,
+///   used to detect recursive aliases like `type A = A`.
+///
+/// - `Resolved(Typ)`
+///   Alias target is resolved into the `Typ`.
+///
+#[derive(Clone, Debug)]
+pub enum AliasState {
+    Unresolved,
+    Resolving,
+    Resolved(Typ),
+}
+
+/// Represents a user-defined type alias.
+///
+/// Aliases are transparent: every usage of the alias
+/// is replaced with the resolved target type.
+///
+/// # Fields
+///
+/// - `location: Address`
+///   The location in the source code where the alias is declared.
+///
+/// - `name: EcoString`
+///   The name of the alias.
+///
+/// - `target: TypePath`
+///   The aliased type annotation.
+///
+/// - `state: AliasState`
+///   Resolution state of the aliased type.
+///
+#[derive(Clone)]
+pub struct Alias {
+    pub location: Address,
+    pub name: EcoString,
+    pub target: TypePath,
+    pub state: AliasState,
+}
+
+/// Debug implementation for `Alias`
+///
+/// Displays the alias name in the format `Alias(name)`.
+impl Debug for Alias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Alias({})", self.name)
+    }
+}
+
 /// Represents a user-defined function in the language.
 ///
 /// A `Function` stores all necessary information about a function,
@@ -291,6 +372,15 @@ impl PartialEq for Enum {
 /// - `ret: Typ`
 ///   The inferred return type of the function.
 ///
+/// - `is_const: bool`
+///   Is function declared as `const fn`.
+///
+/// - `is_async: bool`
+///   Does function body contain `await`, so function returns a promise.
+///
+/// - `deprecated: Option<Deprecation>`
+///   Deprecation of the function, if it's deprecated.
+///
 #[derive(Clone)]
 pub struct Function {
     pub location: Address,
@@ -298,6 +388,9 @@ pub struct Function {
     pub generics: Vec<GenericParameter>,
     pub params: Vec<Parameter>,
     pub ret: Typ,
+    pub is_const: bool,
+    pub is_async: bool,
+    pub deprecated: Option<Deprecation>,
 }
 
 /// Debug implementation for `Function`
@@ -336,12 +429,16 @@ impl PartialEq for Function {
 /// - `fields: HashMap<EcoString, ModDuleef>`
 ///   The definitions contained in the module, keyed by their names.
 ///
+/// - `hints: Hints`
+///   Code generation hints, recorded during the module analysis.
+///
 #[derive(Clone)]
 #[allow(dead_code)]
 pub struct Module {
     pub source: Arc<NamedSource<String>>,
     pub name: EcoString,
     pub fields: HashMap<EcoString, ModuleDef>,
+    pub hints: Hints,
 }
 
 /// Debug implementation for `Module`
@@ -373,6 +470,7 @@ impl Debug for GenericArgs {
 /// An `TyVar` can either be unbound (not yet determined) or bound
 /// (linked to a concrete type `Typ`).
 ///
+#[derive(Clone)]
 pub enum TyVar {
     /// A type variable that has not yet been resolved.
     /// During inference, this may later be linked to a concrete type.
@@ -389,7 +487,9 @@ pub enum TyVar {
 /// - prelude primitive types (`Int`, `Float`, etc.)
 /// - user-defined structs and enums
 /// - function types
+/// - optional types, that could be `null`
 /// - unit type `()`
+/// - never type of the diverging expressions
 /// - unbound types for type inference
 /// - generic type variables
 ///
@@ -403,6 +503,8 @@ pub enum Typ {
     Enum(Id<Enum>, GenericArgs),
     /// Function type
     Function(Id<Function>, GenericArgs),
+    /// Optional type, which value could be `null`
+    Optional(Box<Typ>),
     /// Inference type with unique id used during type inference.
     /// (id is used to link unbound `Typ` with substitution)
     Var(Id<TyVar>),
@@ -411,6 +513,12 @@ pub enum Typ {
     Generic(usize),
     /// Unit type, representing `()`
     Unit,
+    /// Bottom type of the statements, that always diverge:
+    /// `throw`, `break` and infinite loops. `todo` and `panic`
+    /// are placeholders for values, so they're fresh variables,
+    /// but statements and branches of them diverge too.
+    /// (unifies with any type)
+    Never,
 }
 
 /// `Typ` methods implementation
@@ -500,6 +608,30 @@ impl Typ {
         }
     }
 
+    /// Checks that the type mentions the generic
+    /// with given id, directly or in the generic
+    /// arguments and function signatures.
+    ///
+    pub fn mentions_generic(&self, icx: &InferCx, id: usize) -> bool {
+        match self {
+            Typ::Generic(generic) => *generic == id,
+            Typ::Struct(_, args) | Typ::Enum(_, args) => args
+                .subtitutions
+                .values()
+                .any(|typ| typ.mentions_generic(icx, id)),
+            Typ::Function(function, args) => {
+                let function = icx.tcx.function(*function);
+                args.subtitutions
+                    .values()
+                    .chain(function.params.iter().map(|param| &param.typ))
+                    .chain(std::iter::once(&function.ret))
+                    .any(|typ| typ.mentions_generic(icx, id))
+            }
+            Typ::Optional(inner) => inner.mentions_generic(icx, id),
+            Typ::Prelude(_) | Typ::Var(_) | Typ::Unit | Typ::Never => false,
+        }
+    }
+
     /// Retrieves return type and applies
     /// substitution by `InferCx`.
     ///
@@ -519,6 +651,73 @@ impl Typ {
     }
 }
 
+/// JSDoc rendering implementation
+impl Typ {
+    /// Renders type as JSDoc type expression.
+    ///
+    /// Generics are rendered by their names from `generics`,
+    /// unsolved type variables are rendered as `*`.
+    ///
+    pub fn jsdoc(&self, icx: &mut InferCx, generics: &[GenericParameter]) -> String {
+        match icx.apply(self.clone()) {
+            Typ::Prelude(PreludeType::Int | PreludeType::Float) => "number".to_string(),
+            Typ::Prelude(PreludeType::String) => "string".to_string(),
+            Typ::Prelude(PreludeType::Bool) => "boolean".to_string(),
+            Typ::Struct(id, args) => {
+                let name = icx.tcx.struct_(id).name.clone();
+                Self::jsdoc_generic(&name, args, icx, generics)
+            }
+            Typ::Enum(id, args) => {
+                let name = icx.tcx.enum_(id).name.clone();
+                Self::jsdoc_generic(&name, args, icx, generics)
+            }
+            Typ::Function(id, _) => {
+                let function = icx.tcx.function(id).clone();
+                format!(
+                    "function({}): {}",
+                    function
+                        .params
+                        .iter()
+                        .map(|p| p.typ.jsdoc(icx, generics))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    function.ret.jsdoc(icx, generics)
+                )
+            }
+            Typ::Optional(inner) => format!("?{}", inner.jsdoc(icx, generics)),
+            Typ::Generic(id) => generics
+                .iter()
+                .find(|generic| generic.id == id)
+                .map_or("*".to_string(), |generic| generic.name.to_string()),
+            Typ::Var(_) => "*".to_string(),
+            Typ::Unit => "void".to_string(),
+            Typ::Never => "never".to_string(),
+        }
+    }
+
+    /// Renders JSDoc type expression of
+    /// the struct or enum with generic arguments
+    fn jsdoc_generic(
+        name: &EcoString,
+        args: GenericArgs,
+        icx: &mut InferCx,
+        generics: &[GenericParameter],
+    ) -> String {
+        if args.subtitutions.is_empty() {
+            name.to_string()
+        } else {
+            format!(
+                "{name}<{}>",
+                args.subtitutions
+                    .values()
+                    .map(|t| t.jsdoc(icx, generics))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        }
+    }
+}
+
 /// Pretty printing implementation
 impl Pretty for Typ {
     /// Pretty prints type
@@ -539,7 +738,6 @@ impl Pretty for Typ {
                     generic_args
                         .subtitutions
                         .values()
-                        .cloned()
                         .map(|t| t.pretty(icx))
                         .collect::<Vec<String>>()
                         .join(", ")
@@ -555,7 +753,6 @@ impl Pretty for Typ {
                     generic_args
                         .subtitutions
                         .values()
-                        .cloned()
                         .map(|t| t.pretty(icx))
                         .collect::<Vec<String>>()
                         .join(", ")
@@ -575,9 +772,11 @@ impl Pretty for Typ {
                     it.ret(icx).pretty(icx)
                 )
             }
+            Typ::Optional(inner) => format!("Optional[{}]", inner.pretty(icx)),
             Typ::Var(id) => format!("?{}", id.index()),
             Typ::Generic(id) => format!("^{id}"),
             Typ::Unit => "Unit".to_string(),
+            Typ::Never => "Never".to_string(),
         }
     }
 }
@@ -1,5 +1,6 @@
 
 /// Imports
+use ecow::EcoString;
 use miette::{Diagnostic, NamedSource, SourceSpan};
 use std::sync::Arc;
 use thiserror::Error;
@@ -31,4 +32,85 @@ pub(crate) enum TypeckWarning {
         #[label("found todo.")]
         span: SourceSpan,
     },
+    #[error("`{name}` shadows imported name.")]
+    #[diagnostic(
+        code(typeck::warn::shadowed_import),
+        help("imported `{name}` is unavailable in this scope, consider renaming it."),
+        severity(warning)
+    )]
+    ShadowedImport {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("imported here.")]
+        original_span: SourceSpan,
+        #[label("shadowed here.")]
+        shadow_span: SourceSpan,
+        name: EcoString,
+    },
+    #[error("`{name}` shadows outer binding.")]
+    #[diagnostic(
+        code(typeck::warn::shadowing),
+        help("outer `{name}` is unavailable in this scope, consider renaming it."),
+        severity(warning)
+    )]
+    ShadowedBinding {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("defined here.")]
+        original_span: SourceSpan,
+        #[label("shadowed here.")]
+        shadow_span: SourceSpan,
+        name: EcoString,
+    },
+    #[error("`{name}` is never reached from `main`.")]
+    #[diagnostic(
+        code(typeck::warn::dead_function),
+        help("consider removing this function, or making it private."),
+        severity(warning)
+    )]
+    DeadFunction {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("defined here.")]
+        span: SourceSpan,
+        name: EcoString,
+    },
+    #[error("`{name}` is imported, but never used.")]
+    #[diagnostic(
+        code(typeck::warn::unused_import),
+        help("consider removing it from `use`."),
+        severity(warning)
+    )]
+    UnusedImport {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("imported here.")]
+        span: SourceSpan,
+        name: EcoString,
+    },
+    #[error("`{name}` is deprecated.")]
+    #[diagnostic(code(typeck::warn::deprecated_call), severity(warning))]
+    DeprecatedCall {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("deprecated item is called here.")]
+        span: SourceSpan,
+        name: EcoString,
+        #[help]
+        message: Option<EcoString>,
+    },
+    #[error("unreachable code.")]
+    #[diagnostic(
+        code(typeck::warn::unreachable_code),
+        help("consider removing it, since it will never be executed."),
+        severity(warning)
+    )]
+    UnreachableCode {
+        #[source_code]
+        src: Arc<NamedSource<String>>,
+        #[label("control never leaves this statement.")]
+        diverging_span: SourceSpan,
+        #[label("so this is unreachable.")]
+        unreachable_span: SourceSpan,
+    },
 }